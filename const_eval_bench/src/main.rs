@@ -0,0 +1,233 @@
+//! Measures the largest haystack that konst's search functions can search
+//! in a constant, without hitting the `long_running_const_eval` lint,
+//! comparing them with a naive search that checks every haystack position.
+//!
+//! For each search and haystack length this generates a crate that
+//! evaluates the search in a constant, then runs `cargo check` on it,
+//! binary searching for the largest length that compiles.
+//! The lint fires after a fixed amount of const evaluation steps,
+//! so that length is also used to estimate the steps taken per haystack byte.
+//!
+//! Run it with `cargo run --release` from this directory,
+//! optionally passing a crates.io version of konst (eg: `0.4.3`),
+//! or the path to the `konst` crate directory of another checkout,
+//! to also measure that version of konst.
+//! See the README for the results.
+
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+struct Case {
+    // the search, as printed in the results table
+    name: &'static str,
+    // the expression that uses konst
+    konst: &'static str,
+    // the expression that uses the naive search
+    naive: &'static str,
+}
+
+// The haystack is `LEN` bytes of `b'a'`,
+// with a `b'c'` near the start and a `b'b'` near the end.
+const CASES: &[Case] = &[
+    Case {
+        name: "bytes_find(_, &'b')",
+        konst: "slice::bytes_find(H, &'b')",
+        naive: "naive_find(H, b\"b\")",
+    },
+    Case {
+        name: "bytes_rfind(_, &'c')",
+        konst: "slice::bytes_rfind(H, &'c')",
+        naive: "naive_rfind(H, b\"c\")",
+    },
+    Case {
+        name: "string::find(_, \"aaaaaaaaaaaaaaab\")",
+        konst: "string::find(S, \"aaaaaaaaaaaaaaab\")",
+        naive: "naive_find(H, b\"aaaaaaaaaaaaaaab\")",
+    },
+    Case {
+        name: "bytes_rfind(_, b\"baaaaaaaaaaaa\")",
+        konst: "slice::bytes_rfind(H, b\"baaaaaaaaaaaa\")",
+        naive: "naive_rfind(H, b\"baaaaaaaaaaaa\")",
+    },
+    Case {
+        name: "string::contains(_, \"not found\")",
+        konst: "string::contains(S, \"not found\")",
+        naive: "naive_find(H, b\"not found\").is_some()",
+    },
+];
+
+const MIN_LEN: u64 = 64;
+const MAX_LEN: u64 = 1 << 25;
+
+// The amount of loop iterations and function calls
+// that rustc evaluates in a constant before the `long_running_const_eval` lint fires.
+const LINT_STEP_LIMIT: f64 = 2_000_000.0;
+
+// A search that compares the needle at every haystack position,
+// like konst did before it used two-way search.
+const NAIVE_SEARCH: &str = r#"
+const fn naive_matches_at(haystack: &[u8], needle: &[u8], pos: usize) -> bool {
+    let mut i = 0;
+    while i < needle.len() {
+        if haystack[pos + i] != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn naive_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    let mut pos = 0;
+    while pos <= haystack.len() - needle.len() {
+        if naive_matches_at(haystack, needle, pos) {
+            return Some(pos);
+        }
+        pos += 1;
+    }
+    None
+}
+
+const fn naive_rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    let mut pos = haystack.len() - needle.len() + 1;
+    while pos != 0 {
+        pos -= 1;
+        if naive_matches_at(haystack, needle, pos) {
+            return Some(pos);
+        }
+    }
+    None
+}
+"#;
+
+fn main() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let konst_dir = manifest_dir.join("../konst");
+    let scratch_dir = manifest_dir.join("target/scratch/konst");
+    write_scratch_crate(&scratch_dir, &format!("path = {konst_dir:?}"));
+
+    let baseline = std::env::args().nth(1).map(|baseline| {
+        let scratch_dir = manifest_dir.join("target/scratch/baseline");
+        let (source, name) = if Path::new(&baseline).is_dir() {
+            (format!("path = {baseline:?}"), "baseline konst".to_string())
+        } else {
+            (format!("version = \"={baseline}\""), format!("konst {baseline}"))
+        };
+        write_scratch_crate(&scratch_dir, &source);
+        (scratch_dir, name)
+    });
+
+    println!(
+        "Largest haystack length, with the estimated const evaluation steps per byte in parentheses."
+    );
+    println!();
+
+    if let Some((_, name)) = &baseline {
+        println!("| search | naive | {name} | konst |");
+        println!("|---|---|---|---|");
+    } else {
+        println!("| search | naive | konst |");
+        println!("|---|---|---|");
+    }
+
+    for case in CASES {
+        let naive = measure(&scratch_dir, case.naive);
+        let baseline = match &baseline {
+            Some((dir, _)) => format!(" {} |", measure(dir, case.konst)),
+            None => String::new(),
+        };
+        let konst = measure(&scratch_dir, case.konst);
+        println!("| `{}` | {naive} |{baseline} {konst} |", case.name);
+    }
+}
+
+// `konst_source` is the part of the dependency that says where konst comes from,
+// eg: `path = "../konst"` or `version = "=0.4.3"`
+fn write_scratch_crate(scratch_dir: &Path, konst_source: &str) {
+    fs::create_dir_all(scratch_dir.join("src")).unwrap();
+
+    let manifest = format!(
+        r#"
+[package]
+name = "scratch"
+version = "0.0.0"
+edition = "2024"
+
+[dependencies]
+konst = {{ {konst_source}, default-features = false, features = ["iter"] }}
+
+[workspace]
+"#,
+    );
+
+    fs::write(scratch_dir.join("Cargo.toml"), manifest).unwrap();
+}
+
+// Formats the largest haystack length for `search`,
+// and the const evaluation steps per haystack byte that it implies.
+fn measure(scratch_dir: &Path, search: &str) -> String {
+    let len = largest_len(scratch_dir, search);
+    format!("{len} ({:.1})", LINT_STEP_LIMIT / len as f64)
+}
+
+// Binary searches for the largest haystack length (within about 6%)
+// for which `search` compiles.
+fn largest_len(scratch_dir: &Path, search: &str) -> u64 {
+    let mut lo = MIN_LEN;
+    let mut hi = MAX_LEN;
+
+    while hi * 16 > lo * 17 {
+        let mid = (lo as f64 * hi as f64).sqrt() as u64;
+        if compiles(scratch_dir, search, mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+fn compiles(scratch_dir: &Path, search: &str, len: u64) -> bool {
+    let source = format!(
+        r#"
+#![allow(unused)]
+use konst::{{slice, string}};
+
+const LEN: usize = {len};
+const H: &[u8; LEN] = &{{
+    let mut arr = [b'a'; LEN];
+    arr[LEN - 4] = b'b';
+    arr[3] = b'c';
+    arr
+}};
+const S: &str = unsafe {{ core::str::from_utf8_unchecked(H) }};
+
+pub const X: usize = {{
+    let _ = {search};
+    0
+}};
+
+{NAIVE_SEARCH}
+"#,
+    );
+
+    fs::write(scratch_dir.join("src/lib.rs"), source).unwrap();
+
+    Command::new(env!("CARGO"))
+        .args(["check", "--quiet"])
+        .current_dir(scratch_dir)
+        .stderr(Stdio::null())
+        .status()
+        .unwrap()
+        .success()
+}
@@ -0,0 +1,515 @@
+//! Const fns and macros for encoding and decoding [base64].
+//!
+//! Both the standard and URL-safe alphabets are supported,
+//! with or without padding, as configured by the [`Config`] type.
+//!
+//! # Example
+//!
+//! ```rust
+//! use konst::base64::{self, Config};
+//!
+//! const TOKEN: [u8; 11] = base64::decode!("aGVsbG8gd29ybGQ=", Config::STANDARD);
+//! assert_eq!(&TOKEN, b"hello world");
+//!
+//! const ENCODED: &str = base64::encode!(&[0xFB, 0xFF, 0xBF], Config::URL_SAFE);
+//! assert_eq!(ENCODED, "-_-_");
+//!
+//! ```
+//!
+//! [base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+
+use crate::{string::ArrayStr, utils::wrong_array_length_panic};
+
+use core::fmt::{self, Display};
+
+/// Configuration for base64 encoding and decoding.
+///
+/// # Decoding
+///
+/// Decoding always accepts both padded and unpadded input,
+/// padding only needs to be correct if present.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config};
+///
+/// const BYTES: &[u8] = &[0xFF, 0xFE];
+///
+/// assert_eq!(base64::encode!(BYTES, Config::STANDARD), "//4=");
+/// assert_eq!(base64::encode!(BYTES, Config::STANDARD_NO_PAD), "//4");
+/// assert_eq!(base64::encode!(BYTES, Config::URL_SAFE), "__4=");
+/// assert_eq!(base64::encode!(BYTES, Config::URL_SAFE_NO_PAD), "__4");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Config {
+    url_safe: bool,
+    padding: bool,
+}
+
+impl Config {
+    /// The standard alphabet (using `+` and `/`), with `=` padding.
+    pub const STANDARD: Self = Self {
+        url_safe: false,
+        padding: true,
+    };
+
+    /// The standard alphabet (using `+` and `/`), without padding.
+    pub const STANDARD_NO_PAD: Self = Self {
+        url_safe: false,
+        padding: false,
+    };
+
+    /// The URL-safe alphabet (using `-` and `_`), with `=` padding.
+    pub const URL_SAFE: Self = Self {
+        url_safe: true,
+        padding: true,
+    };
+
+    /// The URL-safe alphabet (using `-` and `_`), without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self {
+        url_safe: true,
+        padding: false,
+    };
+
+    /// Whether this uses the URL-safe alphabet.
+    pub const fn is_url_safe(self) -> bool {
+        self.url_safe
+    }
+
+    /// Whether encoding pads the output with `=`.
+    pub const fn is_padded(self) -> bool {
+        self.padding
+    }
+
+    const fn alphabet(self) -> &'static [u8; 64] {
+        if self.url_safe {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+        } else {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+        }
+    }
+
+    const fn decode_symbol(self, byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' if !self.url_safe => Some(62),
+            b'/' if !self.url_safe => Some(63),
+            b'-' if self.url_safe => Some(62),
+            b'_' if self.url_safe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the length of the base64 encoding of `bytes_len` bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{Config, encoded_len};
+///
+/// assert_eq!(encoded_len(0, Config::STANDARD), 0);
+/// assert_eq!(encoded_len(1, Config::STANDARD), 4);
+/// assert_eq!(encoded_len(1, Config::STANDARD_NO_PAD), 2);
+/// assert_eq!(encoded_len(5, Config::URL_SAFE), 8);
+/// assert_eq!(encoded_len(5, Config::URL_SAFE_NO_PAD), 7);
+///
+/// ```
+pub const fn encoded_len(bytes_len: usize, config: Config) -> usize {
+    if config.padding {
+        bytes_len.div_ceil(3) * 4
+    } else {
+        bytes_len / 3 * 4
+            + match bytes_len % 3 {
+                0 => 0,
+                1 => 2,
+                _ => 3,
+            }
+    }
+}
+
+/// Encodes `bytes` as base64.
+///
+/// To get a `&'static str` without having to compute the length explicitly,
+/// you can use the [`encode`](macro@encode) macro.
+///
+/// # Panics
+///
+/// Panics if `N` isn't
+/// [`encoded_len(bytes.len(), config)`](encoded_len).
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config};
+/// use konst::string::ArrayStr;
+///
+/// const BYTES: &[u8] = b"konst";
+///
+/// const LEN: usize = base64::encoded_len(BYTES.len(), Config::STANDARD_NO_PAD);
+/// const ENCODED: ArrayStr<LEN> = base64::encode(BYTES, Config::STANDARD_NO_PAD);
+///
+/// assert_eq!(ENCODED.as_str(), "a29uc3Q");
+///
+/// ```
+#[track_caller]
+pub const fn encode<const N: usize>(bytes: &[u8], config: Config) -> ArrayStr<N> {
+    let expected_len = encoded_len(bytes.len(), config);
+    if N != expected_len {
+        wrong_array_length_panic(N, expected_len)
+    }
+
+    let alphabet = config.alphabet();
+    let mut out = [b'='; N];
+    let mut out_i = 0;
+
+    let (chunks, rem) = bytes.as_chunks::<3>();
+
+    crate::for_range! {i in 0..chunks.len() =>
+        let [a, b, c] = chunks[i];
+        out[out_i] = alphabet[(a >> 2) as usize];
+        out[out_i + 1] = alphabet[((a & 0b11) << 4 | b >> 4) as usize];
+        out[out_i + 2] = alphabet[((b & 0b1111) << 2 | c >> 6) as usize];
+        out[out_i + 3] = alphabet[(c & 0b11_1111) as usize];
+        out_i += 4;
+    }
+
+    match *rem {
+        [a] => {
+            out[out_i] = alphabet[(a >> 2) as usize];
+            out[out_i + 1] = alphabet[((a & 0b11) << 4) as usize];
+        }
+        [a, b] => {
+            out[out_i] = alphabet[(a >> 2) as usize];
+            out[out_i + 1] = alphabet[((a & 0b11) << 4 | b >> 4) as usize];
+            out[out_i + 2] = alphabet[((b & 0b1111) << 2) as usize];
+        }
+        _ => {}
+    }
+
+    ArrayStr(out)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the length of the bytes decoded from the `encoded` base64 string.
+///
+/// This assumes that `encoded` is valid base64,
+/// [`decode`](fn@decode) returns an error if it isn't.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::decoded_len;
+///
+/// assert_eq!(decoded_len(""), 0);
+/// assert_eq!(decoded_len("AA=="), 1);
+/// assert_eq!(decoded_len("AA"), 1);
+/// assert_eq!(decoded_len("AAA="), 2);
+/// assert_eq!(decoded_len("AAAAAA"), 4);
+///
+/// ```
+pub const fn decoded_len(encoded: &str) -> usize {
+    let len = unpadded_len(encoded.as_bytes());
+    len / 4 * 3 + (len % 4).saturating_sub(1)
+}
+
+const fn unpadded_len(bytes: &[u8]) -> usize {
+    match bytes {
+        [.., b'=', b'='] => bytes.len() - 2,
+        [.., b'='] => bytes.len() - 1,
+        _ => bytes.len(),
+    }
+}
+
+/// Decodes the `encoded` base64 string into an array.
+///
+/// This accepts both padded and unpadded input, regardless of `config`.
+///
+/// To get an array without having to compute its length explicitly,
+/// you can use the [`decode`](macro@decode) macro.
+///
+/// # Errors
+///
+/// This returns an error if `encoded` isn't valid base64 in the
+/// alphabet of `config`, or if it doesn't decode into exactly `N` bytes.
+/// The [`DecodeError::offset`] method returns the byte offset in `encoded`
+/// where the error was found.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config, DecodeError, DecodeErrorKind};
+///
+/// const OK: Result<[u8; 4], DecodeError> = base64::decode("3q2+7w==", Config::STANDARD);
+/// assert_eq!(OK, Ok([0xDE, 0xAD, 0xBE, 0xEF]));
+///
+/// const ERR: Result<[u8; 4], DecodeError> = base64::decode("3q2+7w==", Config::URL_SAFE);
+/// let err = ERR.unwrap_err();
+/// assert_eq!(err.kind(), DecodeErrorKind::InvalidByte);
+/// assert_eq!(err.offset(), 3);
+///
+/// ```
+pub const fn decode<const N: usize>(encoded: &str, config: Config) -> Result<[u8; N], DecodeError> {
+    let bytes = encoded.as_bytes();
+    let len = unpadded_len(bytes);
+
+    if len != bytes.len() && !bytes.len().is_multiple_of(4) {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, len));
+    } else if len % 4 == 1 {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidLength, len));
+    } else if N != decoded_len(encoded) {
+        return Err(DecodeError::new(DecodeErrorKind::ArrayLength, bytes.len()));
+    }
+
+    let mut out = [0u8; N];
+    let mut out_i = 0;
+
+    // the bits that haven't been written to `out` yet
+    let mut acc = 0u32;
+    let mut acc_bits = 0u32;
+
+    crate::for_range! {i in 0..len =>
+        let Some(sextet) = config.decode_symbol(bytes[i]) else {
+            return Err(DecodeError::new(DecodeErrorKind::InvalidByte, i));
+        };
+
+        acc = (acc << 6) | sextet as u32;
+        acc_bits += 6;
+
+        if acc_bits >= 8 {
+            acc_bits -= 8;
+            out[out_i] = (acc >> acc_bits) as u8;
+            out_i += 1;
+            acc &= (1 << acc_bits) - 1;
+        }
+    }
+
+    if acc != 0 {
+        return Err(DecodeError::new(DecodeErrorKind::TrailingBits, len - 1));
+    }
+
+    Ok(out)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned by [`decode`](fn@decode).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+    offset: usize,
+}
+
+/// What kind of base64 decoding error a [`DecodeError`] is.
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DecodeErrorKind {
+    /// A byte that isn't in the alphabet of the [`Config`] was found.
+    InvalidByte,
+    /// The amount of non-padding bytes is one more than a multiple of 4,
+    /// which no amount of bytes encodes into.
+    InvalidLength,
+    /// The `=` padding doesn't make the string length a multiple of 4.
+    InvalidPadding,
+    /// The last symbol has bits set that aren't part of the decoded bytes.
+    TrailingBits,
+    /// The decoded bytes don't fit exactly in the returned array.
+    ArrayLength,
+}
+
+impl DecodeError {
+    const fn new(kind: DecodeErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// The kind of decoding error that this is.
+    pub const fn kind(&self) -> DecodeErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the encoded string where the error was found.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    const fn description(&self) -> &'static str {
+        match self.kind {
+            DecodeErrorKind::InvalidByte => "invalid base64 byte at offset ",
+            DecodeErrorKind::InvalidLength => "invalid base64 length: ",
+            DecodeErrorKind::InvalidPadding => "invalid base64 padding at offset ",
+            DecodeErrorKind::TrailingBits => "invalid trailing bits in base64 at offset ",
+            DecodeErrorKind::ArrayLength => {
+                "decoded base64 doesn't fit in the array, input length: "
+            }
+        }
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())?;
+        Display::fmt(&self.offset, f)
+    }
+}
+
+const _: () = {
+    use const_panic::{
+        PanicFmt, PanicVal, flatten_panicvals,
+        fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind},
+    };
+
+    impl PanicFmt for DecodeError {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 2,
+            summed_pv_count: <DecodeErrorKind>::PV_COUNT + <usize>::PV_COUNT,
+            delimiter: cfmt::TypeDelim::Braced,
+        }
+        .call();
+    }
+
+    impl DecodeError {
+        /// Formats a DecodeError
+        pub const fn to_panicvals(
+            &self,
+            fmtarg: FmtArg,
+        ) -> [PanicVal<'static>; DecodeError::PV_COUNT] {
+            match fmtarg.fmt_kind {
+                FmtKind::Debug => {
+                    flatten_panicvals! {fmtarg;
+                        "DecodeError",
+                        open: cfmt::OpenBrace,
+                            "kind: ", DecodeErrorKind => self.kind, cfmt::COMMA_SEP,
+                            "offset: ", usize => self.offset, cfmt::COMMA_TERM,
+                        close: cfmt::CloseBrace,
+                    }
+                }
+                _ => const_panic::utils::flatten_panicvals(&[&[
+                    PanicVal::write_str(self.description()),
+                    PanicVal::from_usize(self.offset, FmtArg::DEBUG),
+                ]]),
+            }
+        }
+    }
+
+    impl PanicFmt for DecodeErrorKind {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = 1;
+    }
+
+    impl DecodeErrorKind {
+        /// Formats a DecodeErrorKind
+        pub const fn to_panicvals(
+            &self,
+            _fmtarg: FmtArg,
+        ) -> [PanicVal<'static>; DecodeErrorKind::PV_COUNT] {
+            [PanicVal::write_str(match self {
+                Self::InvalidByte => "InvalidByte",
+                Self::InvalidLength => "InvalidLength",
+                Self::InvalidPadding => "InvalidPadding",
+                Self::TrailingBits => "TrailingBits",
+                Self::ArrayLength => "ArrayLength",
+            })]
+        }
+    }
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Decodes a base64 string constant into a `[u8; N]`,
+/// where `N` is computed from the string.
+///
+/// This takes a `&'static str` constant and a [`Config`],
+/// decoding the string with [`decode`](fn@decode).
+///
+/// # Panics
+///
+/// This causes a compile-time error if the string isn't valid base64.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config};
+///
+/// const KEY: &str = "AAECAwQFBgcICQ";
+///
+/// const DECODED: [u8; 10] = base64::decode!(KEY, Config::URL_SAFE_NO_PAD);
+///
+/// assert_eq!(DECODED, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// ```
+///
+/// ### Invalid base64
+///
+/// ```compile_fail
+/// use konst::base64::{self, Config};
+///
+/// const DECODED: [u8; 2] = base64::decode!("A*A", Config::STANDARD);
+/// ```
+#[doc(inline)]
+pub use crate::__base64_decode as decode;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __base64_decode {
+    ($encoded:expr, $config:expr $(,)?) => {{
+        const __ENCODED_81608BFNA5: &$crate::__::str = $encoded;
+        {
+            const LEN: $crate::__::usize = $crate::base64::decoded_len(__ENCODED_81608BFNA5);
+
+            const ARR: [$crate::__::u8; LEN] =
+                $crate::result::unwrap!($crate::base64::decode(__ENCODED_81608BFNA5, $config));
+
+            ARR
+        }
+    }};
+}
+
+/// Encodes a byte slice constant as a base64 `&'static str`.
+///
+/// This takes a `&'static [u8]` constant and a [`Config`],
+/// encoding the bytes with [`encode`](fn@encode).
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config};
+///
+/// assert_eq!(base64::encode!(b"hello", Config::STANDARD), "aGVsbG8=");
+/// assert_eq!(base64::encode!(b"hello", Config::STANDARD_NO_PAD), "aGVsbG8");
+/// assert_eq!(base64::encode!(&[], Config::STANDARD), "");
+///
+/// ```
+#[doc(inline)]
+pub use crate::__base64_encode as encode;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __base64_encode {
+    ($bytes:expr, $config:expr $(,)?) => {{
+        const __BYTES_81608BFNA5: &[$crate::__::u8] = $bytes;
+        const __CONFIG_81608BFNA5: $crate::base64::Config = $config;
+        {
+            const LEN: $crate::__::usize =
+                $crate::base64::encoded_len(__BYTES_81608BFNA5.len(), __CONFIG_81608BFNA5);
+
+            const CONC: &$crate::string::ArrayStr<LEN> =
+                &$crate::base64::encode(__BYTES_81608BFNA5, __CONFIG_81608BFNA5);
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
@@ -0,0 +1,477 @@
+//! Const iteration over [RFC 4180] CSV.
+//!
+//! [`records`] iterates over the records (lines) of a CSV string,
+//! and each [`Record`] iterates over its [`Field`]s.
+//!
+//! Fields can be quoted, quoted fields can contain `,`, line breaks,
+//! and quotes escaped as `""`.
+//! Records can be terminated with either `\n` or `\r\n`.
+//!
+//! # Examples
+//!
+//! ### Parsing a table
+//!
+//! Parsing a table with a header row into an array of structs
+//!
+//! ```rust
+//! use konst::{csv, iter, result};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Item {
+//!     name: &'static str,
+//!     price: u32,
+//! }
+//!
+//! // could also be `include_str!("items.csv")`
+//! const TABLE: &str = "\
+//! name,price\r\n\
+//! pen,3\r\n\
+//! \"paper, A4\",12\r\n\
+//! ";
+//!
+//! const ITEMS: [Item; 2] = iter::collect_const!(Item =>
+//!     csv::records(TABLE),
+//!         skip(1),
+//!         map(|record| {
+//!             let mut fields = record.fields();
+//!             Item {
+//!                 name: fields.next().unwrap().content(),
+//!                 price: result::unwrap!(u32::from_str_radix(fields.next().unwrap().raw(), 10)),
+//!             }
+//!         }),
+//! );
+//!
+//! assert_eq!(
+//!     ITEMS,
+//!     [Item { name: "pen", price: 3 }, Item { name: "paper, A4", price: 12 }],
+//! );
+//!
+//! // the header can be used to find columns by name
+//! const PRICE_COLUMN: Option<usize> = csv::records(TABLE).next().unwrap().field_index("price");
+//! assert_eq!(PRICE_COLUMN, Some(1));
+//!
+//! ```
+//!
+//! ### Parsing a record of integers
+//!
+//! Quoted fields are unquoted by [`Field::content`].
+//!
+//! ```rust
+//! use konst::{csv, iter, result};
+//!
+//! const CSV: &str = "3,8,\"13\",21,34\n";
+//!
+//! static PARSED: [u64; 5] = iter::collect_const!(u64 =>
+//!     csv::records(CSV).next().unwrap(),
+//!         map(|field| field.content()),
+//!         map(|s| result::unwrap!(u64::from_str_radix(s, 10))),
+//! );
+//!
+//! assert_eq!(PARSED, [3, 8, 13, 21, 34]);
+//! ```
+//!
+//! # Malformed CSV
+//!
+//! Iterating over malformed CSV panics (causing a compile-time error in const contexts),
+//! CSV is malformed when:
+//! - a quoted field isn't terminated.
+//! - a quoted field's closing quote is followed by anything other than
+//!   `,` or the end of the record.
+//! - an unquoted field contains a `"`.
+//!
+//! [RFC 4180]: https://datatracker.ietf.org/doc/html/rfc4180
+
+use crate::{
+    iter::{ConstIntoIter, IsIntoIterKind, IsIteratorKind},
+    string::{self, ArrayStr, str_from, str_range, str_up_to},
+    utils::wrong_array_length_panic,
+};
+
+/// Iterates over the records of the `csv` string.
+///
+/// A trailing line break after the last record doesn't produce an empty record,
+/// but empty lines elsewhere are records with a single empty field.
+///
+/// # Panics
+///
+/// Iterating over records panics if the CSV has an unterminated quoted field.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{csv, iter};
+///
+/// const LINES: [&str; 3] = iter::collect_const!(&str =>
+///     csv::records("a,b\r\n\"multi\nline\",c\n\n"),
+///         map(|r| r.as_str()),
+/// );
+///
+/// assert_eq!(LINES, ["a,b", "\"multi\nline\",c", ""]);
+///
+/// ```
+pub const fn records(csv: &str) -> Records<'_> {
+    Records { this: csv }
+}
+
+/// Const iterator over the [`Record`]s in a CSV string.
+///
+/// This is constructed with [`records`] like this:
+/// ```rust
+/// # let csv = "";
+/// # let _ =
+/// konst::csv::records(csv)
+/// # ;
+/// ```
+pub struct Records<'a> {
+    this: &'a str,
+}
+
+impl<'a> ConstIntoIter for Records<'a> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = Record<'a>;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a> Records<'a> {
+    iterator_shared! {
+        is_forward = true,
+        item = Record<'a>,
+        iter_forward = Records<'a>,
+        next(self) {
+            let this = self.this;
+            if this.is_empty() {
+                return None;
+            }
+
+            let bytes = this.as_bytes();
+            let mut in_quotes = false;
+            let mut i = 0;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'"' => in_quotes = !in_quotes,
+                    b'\n' if !in_quotes => break,
+                    _ => {}
+                }
+                i += 1;
+            }
+
+            if in_quotes {
+                panic!("unterminated quoted field in CSV")
+            }
+
+            self.this = if i < bytes.len() { str_from(this, i + 1) } else { "" };
+
+            let line = match str_up_to(this, i).as_bytes() {
+                [.., b'\r'] => str_up_to(this, i - 1),
+                _ => str_up_to(this, i),
+            };
+
+            Some(Record { line })
+        },
+        fields = {this},
+    }
+
+    /// Gets the unparsed remainder of the CSV.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::csv::records("a,b\nc,d\n");
+    ///
+    /// assert_eq!(iter.next().unwrap().as_str(), "a,b");
+    /// assert_eq!(iter.remainder(), "c,d\n");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
+
+/// A CSV record (a line), which can be iterated over to get its [`Field`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{csv, iter};
+///
+/// const RECORD: csv::Record<'_> = csv::records("id,\"quoted \"\"name\"\"\"").next().unwrap();
+///
+/// const FIELDS: [&str; 2] = iter::collect_const!(&str => RECORD, map(|f| f.raw()));
+///
+/// assert_eq!(FIELDS, ["id", "\"quoted \"\"name\"\"\""]);
+/// assert_eq!(RECORD.field(1).unwrap().unescape::<13>().as_str(), "quoted \"name\"");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    line: &'a str,
+}
+
+impl<'a> ConstIntoIter for Record<'a> {
+    type Kind = IsIntoIterKind;
+    type IntoIter = Fields<'a>;
+    type Item = Field<'a>;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a> Record<'a> {
+    /// Gets the record as written, without its line terminator.
+    pub const fn as_str(&self) -> &'a str {
+        self.line
+    }
+
+    /// Iterates over the fields of this record.
+    pub const fn fields(&self) -> Fields<'a> {
+        Fields {
+            this: self.line,
+            finished: false,
+        }
+    }
+
+    /// Converts this record into an iterator over its fields.
+    pub const fn const_into_iter(self) -> Fields<'a> {
+        self.fields()
+    }
+
+    /// Gets the field at `index`, returning None if it's out of bounds.
+    pub const fn field(&self, index: usize) -> Option<Field<'a>> {
+        let mut fields = self.fields();
+        let mut i = 0;
+        while let Some(field) = fields.next() {
+            if i == index {
+                return Some(field);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Gets the index of the first field whose [unescaped](Field::unescape) value
+    /// is `name`.
+    ///
+    /// This is useful to look up columns by name in header records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::csv;
+    ///
+    /// const HEADER: csv::Record<'_> = csv::records("\"x\",y,\"a \"\"z\"\"\"").next().unwrap();
+    ///
+    /// assert_eq!(HEADER.field_index("x"), Some(0));
+    /// assert_eq!(HEADER.field_index("y"), Some(1));
+    /// assert_eq!(HEADER.field_index("a \"z\""), Some(2));
+    /// assert_eq!(HEADER.field_index("w"), None);
+    ///
+    /// ```
+    pub const fn field_index(&self, name: &str) -> Option<usize> {
+        let mut fields = self.fields();
+        let mut i = 0;
+        while let Some(field) = fields.next() {
+            if field.eq_str(name) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+/// Const iterator over the [`Field`]s of a CSV [`Record`].
+///
+/// This is constructed with [`Record::fields`].
+///
+/// # Panics
+///
+/// Iterating panics if the record has malformed quoted fields,
+/// [more details](crate::csv#malformed-csv).
+pub struct Fields<'a> {
+    this: &'a str,
+    finished: bool,
+}
+
+impl<'a> ConstIntoIter for Fields<'a> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = Field<'a>;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a> Fields<'a> {
+    iterator_shared! {
+        is_forward = true,
+        item = Field<'a>,
+        iter_forward = Fields<'a>,
+        next(self) {
+            if self.finished {
+                return None;
+            }
+
+            let this = self.this;
+            let bytes = this.as_bytes();
+
+            let (end, quoted) = if let [b'"', ..] = bytes {
+                let mut i = 1;
+                loop {
+                    if i == bytes.len() {
+                        panic!("unterminated quoted field in CSV")
+                    } else if bytes[i] != b'"' {
+                        i += 1;
+                    } else if i + 1 < bytes.len() && bytes[i + 1] == b'"' {
+                        i += 2;
+                    } else {
+                        break;
+                    }
+                }
+
+                if i + 1 < bytes.len() && bytes[i + 1] != b',' {
+                    panic!("expected `,` or the end of the record after a quoted CSV field")
+                }
+
+                (i + 1, true)
+            } else {
+                let mut i = 0;
+                while i < bytes.len() && bytes[i] != b',' {
+                    if bytes[i] == b'"' {
+                        panic!("unquoted CSV fields can't contain `\"`")
+                    }
+                    i += 1;
+                }
+                (i, false)
+            };
+
+            if end == bytes.len() {
+                self.this = "";
+                self.finished = true;
+            } else {
+                self.this = str_from(this, end + 1);
+            }
+
+            Some(Field { raw: str_up_to(this, end), quoted })
+        },
+        fields = {this, finished},
+    }
+
+    /// Gets the unparsed remainder of the record.
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
+
+/// A field in a CSV [`Record`].
+///
+/// # Example
+///
+/// ```rust
+/// use konst::csv;
+///
+/// const FIELD: csv::Field<'_> = csv::records("\"say \"\"hi\"\"\"").next().unwrap().field(0).unwrap();
+///
+/// assert!(FIELD.is_quoted());
+/// assert_eq!(FIELD.raw(), "\"say \"\"hi\"\"\"");
+/// assert_eq!(FIELD.content(), "say \"\"hi\"\"");
+/// assert_eq!(FIELD.unescaped_len(), 8);
+/// assert_eq!(FIELD.unescape::<8>().as_str(), "say \"hi\"");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Field<'a> {
+    raw: &'a str,
+    quoted: bool,
+}
+
+impl<'a> Field<'a> {
+    /// Gets the field as written, including the quotes of quoted fields.
+    pub const fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// Whether the field is enclosed in quotes.
+    pub const fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Gets the field without its enclosing quotes,
+    /// escaped quotes (`""`) are left as is.
+    ///
+    /// For fields without escaped quotes this is the field's value,
+    /// use [`unescape`](Self::unescape) otherwise.
+    pub const fn content(&self) -> &'a str {
+        if self.quoted {
+            str_range(self.raw, 1, self.raw.len() - 1)
+        } else {
+            self.raw
+        }
+    }
+
+    /// The length of the field's value after [unescaping](Self::unescape) it.
+    pub const fn unescaped_len(&self) -> usize {
+        let content = self.content();
+        if self.quoted {
+            content.len() - count_quotes(content) / 2
+        } else {
+            content.len()
+        }
+    }
+
+    /// Gets the field's value, with enclosing quotes removed
+    /// and escaped quotes (`""`) unescaped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` isn't [`self.unescaped_len()`](Self::unescaped_len).
+    #[track_caller]
+    pub const fn unescape<const N: usize>(&self) -> ArrayStr<N> {
+        let expected_len = self.unescaped_len();
+        if N != expected_len {
+            wrong_array_length_panic(N, expected_len)
+        }
+
+        let content = self.content().as_bytes();
+        let mut out = [0u8; N];
+        let mut i = 0;
+        let mut out_i = 0;
+        while out_i < N {
+            out[out_i] = content[i];
+            i += if self.quoted && content[i] == b'"' {
+                2
+            } else {
+                1
+            };
+            out_i += 1;
+        }
+
+        ArrayStr(out)
+    }
+
+    /// Compares the field's [unescaped](Self::unescape) value with `string`.
+    pub const fn eq_str(&self, string: &str) -> bool {
+        let content = self.content().as_bytes();
+        if !self.quoted {
+            return string::eq_str(self.raw, string);
+        }
+
+        let string = string.as_bytes();
+        let mut i = 0;
+        let mut j = 0;
+        while i < content.len() && j < string.len() {
+            if content[i] != string[j] {
+                return false;
+            }
+            i += if content[i] == b'"' { 2 } else { 1 };
+            j += 1;
+        }
+        i == content.len() && j == string.len()
+    }
+}
+
+const fn count_quotes(s: &str) -> usize {
+    let mut count = 0;
+    crate::for_range! {i in 0..s.len() =>
+        if s.as_bytes()[i] == b'"' {
+            count += 1;
+        }
+    }
+    count
+}
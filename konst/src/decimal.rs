@@ -0,0 +1,739 @@
+//! Const fixed-point decimal numbers.
+//!
+//! [`Decimal<SCALE>`](Decimal) stores numbers with exactly `SCALE` fractional digits,
+//! which is useful for values (eg: currency, calibration constants)
+//! that must not lose precision by going through floats.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "parsing", doc = "```rust")]
+#![cfg_attr(not(feature = "parsing"), doc = "```ignore")]
+//! use konst::decimal::{Decimal, RoundingMode};
+//! use konst::parsing::{Parser, parse_type};
+//! use konst::result;
+//!
+//! type Money = Decimal<2>;
+//!
+//! const PRICE: Money = result::unwrap!(parse_type!(Parser::new("19.99"), Money));
+//! const TAX_RATE: Decimal<4> = result::unwrap!(parse_type!(Parser::new("0.0825"), Decimal<4>));
+//!
+//! const TAX: Money = {
+//!     let price: Decimal<4> = PRICE.rescale(RoundingMode::HalfEven).unwrap();
+//!     let tax = price.checked_mul(TAX_RATE, RoundingMode::HalfEven).unwrap();
+//!     tax.rescale(RoundingMode::HalfEven).unwrap()
+//! };
+//! const TOTAL: Money = PRICE.checked_add(TAX).unwrap();
+//!
+//! assert_eq!(TAX.to_array_str::<4>().as_str(), "1.65");
+//! assert_eq!(TOTAL.to_array_str::<5>().as_str(), "21.64");
+//! assert_eq!(TOTAL.mantissa(), 2164);
+//!
+//! ```
+
+use crate::{string::ArrayStr, utils::wrong_array_length_panic};
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+};
+
+#[cfg(feature = "parsing")]
+use crate::parsing::{ErrorKind, HasParser, ParseDirection, ParseError, Parser};
+
+/// A fixed-point decimal number with `SCALE` fractional digits,
+/// stored as an `i128` mantissa.
+///
+/// The represented value is `mantissa / 10^SCALE`,
+/// eg: a `Decimal<3>` with a mantissa of `-12345` is `-12.345`.
+///
+/// `SCALE` must be at most 38, using larger scales causes a compile-time error.
+///
+/// # Parsing
+///
+/// This implements [`HasParser`], parsing numbers with an optional sign,
+/// at least one integer digit, and optionally a `.` followed by fractional digits,
+/// eg: `"10"`, `"-12.345"`, `"+0.5"`.
+///
+/// Fractional digits beyond `SCALE` are only allowed if they're zeros,
+/// so that parsing never rounds.
+///
+/// [`HasParser`]: crate::parsing::HasParser
+///
+/// # Example
+///
+/// ```rust
+/// use konst::decimal::{Decimal, RoundingMode};
+///
+/// const THIRD: Decimal<4> = {
+///     let one = Decimal::<4>::ONE;
+///     let three = Decimal::from_int(3).unwrap();
+///     one.checked_div(three, RoundingMode::HalfEven).unwrap()
+/// };
+///
+/// assert_eq!(THIRD.mantissa(), 3333);
+/// assert_eq!(THIRD.to_string(), "0.3333");
+/// assert_eq!(THIRD.round_dp(2, RoundingMode::Ceiling), Some(Decimal::from_mantissa(3400)));
+///
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const SCALE: u32> {
+    mantissa: i128,
+}
+
+/// How to round the results of decimal operations that don't fit in the target scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds towards zero, truncating the excess digits.
+    TowardZero,
+    /// Rounds away from zero.
+    AwayFromZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceiling,
+    /// Rounds to the nearest value, rounding ties towards zero.
+    HalfTowardZero,
+    /// Rounds to the nearest value, rounding ties away from zero.
+    HalfAwayFromZero,
+    /// Rounds to the nearest value, rounding ties to the even neighbor
+    /// (also known as banker's rounding).
+    HalfEven,
+}
+
+impl<const SCALE: u32> Decimal<SCALE> {
+    /// The number of fractional digits.
+    pub const SCALE: u32 = SCALE;
+
+    const FACTOR: u128 = {
+        assert!(SCALE <= 38, "the SCALE of a Decimal can be at most 38");
+        10u128.pow(SCALE)
+    };
+
+    /// Zero
+    pub const ZERO: Self = Self { mantissa: 0 };
+
+    /// One
+    pub const ONE: Self = Self {
+        mantissa: Self::FACTOR as i128,
+    };
+
+    /// The smallest representable value.
+    pub const MIN: Self = Self {
+        mantissa: i128::MIN,
+    };
+
+    /// The largest representable value.
+    pub const MAX: Self = Self {
+        mantissa: i128::MAX,
+    };
+
+    /// Constructs a `Decimal` from its mantissa, the value is `mantissa / 10^SCALE`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::<2>::from_mantissa(-1234).to_string(), "-12.34");
+    /// ```
+    pub const fn from_mantissa(mantissa: i128) -> Self {
+        Self { mantissa }
+    }
+
+    /// Constructs a `Decimal` from an integer, returning None on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::<2>::from_int(-12).unwrap().mantissa(), -1200);
+    /// assert_eq!(Decimal::<2>::from_int(i128::MAX), None);
+    /// ```
+    pub const fn from_int(int: i128) -> Option<Self> {
+        match int.checked_mul(Self::FACTOR as i128) {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Gets the mantissa of this decimal, the value is `mantissa / 10^SCALE`.
+    pub const fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+
+    /// Whether this is less than zero.
+    pub const fn is_negative(self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Whether this is zero.
+    pub const fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Converts this to an integer, rounding with `mode`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::{Decimal, RoundingMode};
+    ///
+    /// let num = Decimal::<1>::from_mantissa(-25);
+    ///
+    /// assert_eq!(num.to_int(RoundingMode::TowardZero), -2);
+    /// assert_eq!(num.to_int(RoundingMode::Floor), -3);
+    /// assert_eq!(num.to_int(RoundingMode::HalfAwayFromZero), -3);
+    /// assert_eq!(num.to_int(RoundingMode::HalfEven), -2);
+    /// ```
+    pub const fn to_int(self, mode: RoundingMode) -> i128 {
+        let negative = self.mantissa < 0;
+        let magnitude = self.mantissa.unsigned_abs();
+        let d = Self::FACTOR;
+
+        // can't overflow, because dividing by `FACTOR` shrinks the magnitude,
+        // and it's only rounded up if `FACTOR > 1`.
+        let rounded = round_quotient(negative, magnitude / d, magnitude % d, d, mode);
+        match rounded {
+            Some(rounded) => match from_magnitude(negative, rounded) {
+                Some(x) => x,
+                None => panic!("bug: rounding the integer part can't overflow"),
+            },
+            None => panic!("bug: rounding the integer part can't overflow"),
+        }
+    }
+
+    /// Adds `self` and `rhs`, returning None on overflow.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.mantissa.checked_add(rhs.mantissa) {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from `self`, returning None on overflow.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.mantissa.checked_sub(rhs.mantissa) {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Negates `self`, returning None on overflow.
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.mantissa.checked_neg() {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Gets the absolute value of `self`, returning None on overflow.
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.mantissa.checked_abs() {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Multiplies `self` by `rhs`, rounding the excess digits with `mode`.
+    ///
+    /// Returns None if the result doesn't fit in `Self`,
+    /// the intermediate product is computed with 256 bits, so it can't overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::{Decimal, RoundingMode};
+    ///
+    /// let price = Decimal::<2>::from_mantissa(1999);
+    /// let half = Decimal::<2>::from_mantissa(50);
+    ///
+    /// assert_eq!(price.checked_mul(half, RoundingMode::HalfEven).unwrap().mantissa(), 1000);
+    /// assert_eq!(price.checked_mul(half, RoundingMode::TowardZero).unwrap().mantissa(), 999);
+    /// assert_eq!(Decimal::<2>::MAX.checked_mul(price, RoundingMode::Floor), None);
+    /// ```
+    pub const fn checked_mul(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        let negative = (self.mantissa < 0) != (rhs.mantissa < 0);
+        let lhs = self.mantissa.unsigned_abs();
+        let rhs = rhs.mantissa.unsigned_abs();
+
+        Self::mul_div_rounded(negative, lhs, rhs, Self::FACTOR, mode)
+    }
+
+    /// Divides `self` by `rhs`, rounding the excess digits with `mode`.
+    ///
+    /// Returns None if `rhs` is zero, or if the result doesn't fit in `Self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::{Decimal, RoundingMode};
+    ///
+    /// let total = Decimal::<2>::from_mantissa(1000);
+    /// let people = Decimal::<2>::from_int(3).unwrap();
+    ///
+    /// assert_eq!(total.checked_div(people, RoundingMode::Floor).unwrap().mantissa(), 333);
+    /// assert_eq!(total.checked_div(people, RoundingMode::Ceiling).unwrap().mantissa(), 334);
+    /// assert_eq!(total.checked_div(Decimal::ZERO, RoundingMode::Floor), None);
+    /// ```
+    pub const fn checked_div(self, rhs: Self, mode: RoundingMode) -> Option<Self> {
+        if rhs.mantissa == 0 {
+            return None;
+        }
+
+        let negative = (self.mantissa < 0) != (rhs.mantissa < 0);
+        let lhs = self.mantissa.unsigned_abs();
+        let rhs = rhs.mantissa.unsigned_abs();
+
+        Self::mul_div_rounded(negative, lhs, Self::FACTOR, rhs, mode)
+    }
+
+    const fn mul_div_rounded(
+        negative: bool,
+        lhs: u128,
+        rhs: u128,
+        divisor: u128,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        let Some((quotient, rem)) = mul_div(lhs, rhs, divisor) else {
+            return None;
+        };
+
+        let Some(magnitude) = round_quotient(negative, quotient, rem, divisor, mode) else {
+            return None;
+        };
+
+        match from_magnitude(negative, magnitude) {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Rounds `self` to `decimals` fractional digits with `mode`,
+    /// keeping the same scale.
+    ///
+    /// If `decimals >= SCALE` this returns `self` unchanged.
+    ///
+    /// Returns None if rounding overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::{Decimal, RoundingMode};
+    ///
+    /// let num = Decimal::<3>::from_mantissa(2345);
+    ///
+    /// assert_eq!(num.round_dp(2, RoundingMode::HalfEven).unwrap().mantissa(), 2340);
+    /// assert_eq!(num.round_dp(2, RoundingMode::HalfAwayFromZero).unwrap().mantissa(), 2350);
+    /// assert_eq!(num.round_dp(0, RoundingMode::Ceiling).unwrap().mantissa(), 3000);
+    /// ```
+    pub const fn round_dp(self, decimals: u32, mode: RoundingMode) -> Option<Self> {
+        if decimals >= SCALE {
+            return Some(self);
+        }
+
+        let negative = self.mantissa < 0;
+        let magnitude = self.mantissa.unsigned_abs();
+        let d = 10u128.pow(SCALE - decimals);
+
+        let Some(rounded) = round_quotient(negative, magnitude / d, magnitude % d, d, mode) else {
+            return None;
+        };
+        let Some(rounded) = rounded.checked_mul(d) else {
+            return None;
+        };
+
+        match from_magnitude(negative, rounded) {
+            Some(mantissa) => Some(Self { mantissa }),
+            None => None,
+        }
+    }
+
+    /// Converts `self` to a different scale,
+    /// rounding with `mode` if the new scale is smaller.
+    ///
+    /// Returns None if the value doesn't fit in the new scale.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::{Decimal, RoundingMode};
+    ///
+    /// let num = Decimal::<3>::from_mantissa(-1255);
+    ///
+    /// assert_eq!(num.rescale::<5>(RoundingMode::Floor).unwrap().mantissa(), -125500);
+    /// assert_eq!(num.rescale::<2>(RoundingMode::HalfEven).unwrap().mantissa(), -126);
+    /// assert_eq!(num.rescale::<2>(RoundingMode::Ceiling).unwrap().mantissa(), -125);
+    /// assert_eq!(Decimal::<0>::MAX.rescale::<1>(RoundingMode::Floor), None);
+    /// ```
+    pub const fn rescale<const NEW_SCALE: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<Decimal<NEW_SCALE>> {
+        // forcing the assertion of the new scale
+        let _ = Decimal::<NEW_SCALE>::FACTOR;
+
+        if NEW_SCALE >= SCALE {
+            match self.mantissa.checked_mul(10i128.pow(NEW_SCALE - SCALE)) {
+                Some(mantissa) => Some(Decimal { mantissa }),
+                None => None,
+            }
+        } else {
+            let negative = self.mantissa < 0;
+            let magnitude = self.mantissa.unsigned_abs();
+            let d = 10u128.pow(SCALE - NEW_SCALE);
+
+            let Some(rounded) = round_quotient(negative, magnitude / d, magnitude % d, d, mode)
+            else {
+                return None;
+            };
+
+            match from_magnitude(negative, rounded) {
+                Some(mantissa) => Some(Decimal { mantissa }),
+                None => None,
+            }
+        }
+    }
+
+    /// Compares `self` and `other` for equality.
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.mantissa == other.mantissa
+    }
+
+    /// Compares `self` and `other` for ordering.
+    pub const fn const_cmp(&self, other: &Self) -> Ordering {
+        if self.mantissa < other.mantissa {
+            Ordering::Less
+        } else if self.mantissa == other.mantissa {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// The length of the string that [`to_array_str`](Self::to_array_str) returns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::<2>::from_mantissa(5).to_array_str_len(), 4);
+    /// assert_eq!(Decimal::<2>::from_mantissa(-12345).to_array_str_len(), 7);
+    /// assert_eq!(Decimal::<0>::from_mantissa(-7).to_array_str_len(), 2);
+    /// ```
+    pub const fn to_array_str_len(self) -> usize {
+        let int_digits = count_digits(self.mantissa.unsigned_abs() / Self::FACTOR);
+        let frac_len = if SCALE == 0 { 0 } else { 1 + SCALE as usize };
+
+        (self.mantissa < 0) as usize + int_digits + frac_len
+    }
+
+    /// Renders this decimal into an `ArrayStr`,
+    /// with exactly `SCALE` fractional digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` isn't [`self.to_array_str_len()`](Self::to_array_str_len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::Decimal;
+    /// use konst::string::ArrayStr;
+    ///
+    /// const NUM: Decimal<3> = Decimal::from_mantissa(-1050);
+    /// const STR: ArrayStr<{NUM.to_array_str_len()}> = NUM.to_array_str();
+    ///
+    /// assert_eq!(STR.as_str(), "-1.050");
+    /// ```
+    #[track_caller]
+    pub const fn to_array_str<const N: usize>(self) -> ArrayStr<N> {
+        let expected_len = self.to_array_str_len();
+        if N != expected_len {
+            wrong_array_length_panic(N, expected_len)
+        }
+
+        let mut out = [0u8; N];
+        let mut magnitude = self.mantissa.unsigned_abs();
+        let mut i = N;
+
+        if SCALE != 0 {
+            crate::for_range! {_ in 0..SCALE =>
+                i -= 1;
+                out[i] = b'0' + (magnitude % 10) as u8;
+                magnitude /= 10;
+            }
+            i -= 1;
+            out[i] = b'.';
+        }
+
+        loop {
+            i -= 1;
+            out[i] = b'0' + (magnitude % 10) as u8;
+            magnitude /= 10;
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        if self.mantissa < 0 {
+            out[0] = b'-';
+        }
+
+        ArrayStr(out)
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<const SCALE: u32> Decimal<SCALE> {
+    /// Parses a `Decimal`, formatted as described in the
+    /// [type-level docs](Decimal#parsing).
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseDecimal`] error if:
+    /// - there are no integer digits, or there are no fractional digits after the `.`.
+    /// - there are non-zero fractional digits beyond `SCALE`.
+    /// - the number doesn't fit in `Self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::decimal::Decimal;
+    /// use konst::parsing::{ErrorKind, Parser, parse_type};
+    ///
+    /// let mut parser = Parser::new("-12.345;1.5000;1.25");
+    ///
+    /// let num = parse_type!(parser, Decimal<3>).unwrap();
+    /// assert_eq!(num.mantissa(), -12345);
+    ///
+    /// parser.strip_prefix(';').unwrap();
+    /// let num = parse_type!(parser, Decimal<1>).unwrap();
+    /// assert_eq!(num.mantissa(), 15);
+    ///
+    /// parser.strip_prefix(';').unwrap();
+    /// let err = parse_type!(parser, Decimal<1>).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ParseDecimal);
+    /// assert_eq!(err.offset(), 18);
+    /// ```
+    ///
+    /// [`ErrorKind::ParseDecimal`]: crate::parsing::ErrorKind::ParseDecimal
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let mut p = parser.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let bytes = p.remainder().as_bytes();
+
+        let (negative, mut i) = match bytes {
+            [b'-', ..] => (true, 1),
+            [b'+', ..] => (false, 1),
+            _ => (false, 0),
+        };
+
+        let mut magnitude: u128 = 0;
+
+        let int_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            let Some(x) = push_digit(magnitude, bytes[i]) else {
+                return Err(p.to_error(ErrorKind::ParseDecimal));
+            };
+            magnitude = x;
+            i += 1;
+        }
+        if i == int_start {
+            return Err(p.to_error_at(i, ErrorKind::ParseDecimal));
+        }
+
+        let mut frac_digits = 0;
+        if i < bytes.len() && bytes[i] == b'.' {
+            i += 1;
+            let frac_start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                if frac_digits < SCALE {
+                    let Some(x) = push_digit(magnitude, bytes[i]) else {
+                        return Err(p.to_error(ErrorKind::ParseDecimal));
+                    };
+                    magnitude = x;
+                    frac_digits += 1;
+                } else if bytes[i] != b'0' {
+                    return Err(p.to_error_at(i, ErrorKind::ParseDecimal));
+                }
+                i += 1;
+            }
+            if i == frac_start {
+                return Err(p.to_error_at(i, ErrorKind::ParseDecimal));
+            }
+        }
+
+        let Some(magnitude) = magnitude.checked_mul(10u128.pow(SCALE - frac_digits)) else {
+            return Err(p.to_error(ErrorKind::ParseDecimal));
+        };
+
+        let Some(mantissa) = from_magnitude(negative, magnitude) else {
+            return Err(p.to_error(ErrorKind::ParseDecimal));
+        };
+
+        _ = p.skip(i);
+        *parser = p;
+        Ok(Self { mantissa })
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl<const SCALE: u32> HasParser for Decimal<SCALE> {
+    type Parser = Self;
+}
+
+#[cfg(feature = "cmp")]
+impl<const SCALE: u32> crate::cmp::ConstCmp for Decimal<SCALE> {
+    type Kind = crate::cmp::IsNotStdKind;
+    type This = Self;
+}
+
+impl<const SCALE: u32> Display for Decimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let magnitude = self.mantissa.unsigned_abs();
+        let factor = Self::FACTOR;
+
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        Display::fmt(&(magnitude / factor), f)?;
+        if SCALE != 0 {
+            write!(f, ".{:0width$}", magnitude % factor, width = SCALE as usize)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const SCALE: u32> Debug for Decimal<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "parsing")]
+const fn push_digit(magnitude: u128, digit: u8) -> Option<u128> {
+    match magnitude.checked_mul(10) {
+        Some(x) => x.checked_add((digit - b'0') as u128),
+        None => None,
+    }
+}
+
+// converts a sign and magnitude into an i128, returning None on overflow.
+const fn from_magnitude(negative: bool, magnitude: u128) -> Option<i128> {
+    if negative {
+        if magnitude <= i128::MIN.unsigned_abs() {
+            Some((magnitude as i128).wrapping_neg())
+        } else {
+            None
+        }
+    } else if magnitude <= i128::MAX as u128 {
+        Some(magnitude as i128)
+    } else {
+        None
+    }
+}
+
+// Rounds the magnitude of `quotient + rem / divisor` to an integer,
+// where `negative` is the sign of the value.
+const fn round_quotient(
+    negative: bool,
+    quotient: u128,
+    rem: u128,
+    divisor: u128,
+    mode: RoundingMode,
+) -> Option<u128> {
+    // comparing `rem` with `divisor - rem` avoids overflowing in `rem * 2`
+    let half_cmp = if rem < divisor - rem {
+        Ordering::Less
+    } else if rem == divisor - rem {
+        Ordering::Equal
+    } else {
+        Ordering::Greater
+    };
+
+    let round_up = rem != 0
+        && match mode {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::HalfTowardZero => matches!(half_cmp, Ordering::Greater),
+            RoundingMode::HalfAwayFromZero => !matches!(half_cmp, Ordering::Less),
+            RoundingMode::HalfEven => match half_cmp {
+                Ordering::Less => false,
+                Ordering::Equal => quotient % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+    if round_up {
+        quotient.checked_add(1)
+    } else {
+        Some(quotient)
+    }
+}
+
+// Computes `(lhs * rhs) / divisor` and `(lhs * rhs) % divisor`,
+// with a 256-bit intermediate product.
+//
+// Returns None if the quotient doesn't fit in a u128.
+const fn mul_div(lhs: u128, rhs: u128, divisor: u128) -> Option<(u128, u128)> {
+    let (hi, lo) = widening_mul(lhs, rhs);
+
+    if hi >= divisor {
+        return None;
+    }
+
+    // long division, one bit at a time
+    let mut rem = hi;
+    let mut quotient = 0u128;
+    let mut bit = 128;
+    while bit != 0 {
+        bit -= 1;
+        let carry = rem >> 127;
+        rem = (rem << 1) | ((lo >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || rem >= divisor {
+            rem = rem.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+
+    Some((quotient, rem))
+}
+
+// Computes the 256-bit product of `lhs` and `rhs` as `(high bits, low bits)`
+const fn widening_mul(lhs: u128, rhs: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (l1, l0) = (lhs >> 64, lhs & MASK);
+    let (r1, r0) = (rhs >> 64, rhs & MASK);
+
+    let p00 = l0 * r0;
+    let p01 = l0 * r1;
+    let p10 = l1 * r0;
+    let p11 = l1 * r1;
+
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+
+    let lo = (p00 & MASK) | (mid << 64);
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+const fn count_digits(mut n: u128) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
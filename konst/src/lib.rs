@@ -247,7 +247,7 @@
 //!
 //! - `"konst_proc_macros"`(enabled by default):
 //! Enables items that use proc macros and don't require other crate features:
-//! the [`destructure_rec`] macro.
+//! the [`destructure_rec`] and [`string::format`] macros.
 //!
 //! - `"parsing_proc"`(enabled by default):
 //! Enables the `"parsing"` and `konst_proc_macros` features,
@@ -335,8 +335,12 @@ pub mod alloc_type;
 
 pub mod array;
 
+pub mod base64;
+
 pub mod chr;
 
+pub mod decimal;
+
 #[cfg(feature = "cmp")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "cmp")))]
 pub mod cmp;
@@ -346,6 +350,10 @@ pub mod cmp;
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
 pub mod iter;
 
+#[cfg(feature = "iter")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub mod csv;
+
 pub mod drop_flavor;
 
 pub mod ffi;
@@ -382,7 +390,7 @@ pub use crate::parsing::Parser;
 
 #[cfg(feature = "parsing_proc")]
 #[doc(hidden)]
-pub use konst_proc_macros::{__priv_bstr_end, __priv_bstr_start};
+pub use konst_proc_macros::{__priv_bstr_end, __priv_bstr_list, __priv_bstr_start};
 
 pub mod slice;
 
@@ -405,13 +413,13 @@ pub mod __ {
     pub use core::{
         assert,
         cmp::Ordering::{self, Equal, Greater, Less},
-        compile_error, concat,
+        compile_error, concat, include_str,
         marker::PhantomData,
         matches,
         mem::{self, ManuallyDrop, MaybeUninit, replace},
-        ops::Range,
+        ops::{Bound, Range},
         option::Option::{self, None, Some},
-        panic,
+        option_env, panic,
         primitive::{bool, str, u8, usize},
         ptr,
         result::Result::{self, Err, Ok},
@@ -434,7 +442,7 @@ pub mod __ {
     pub use crate::__for_cmp_impls::U8Ordering;
 
     #[cfg(feature = "konst_proc_macros")]
-    pub use konst_proc_macros::__destructure__unwrap_pats;
+    pub use konst_proc_macros::{__destructure__unwrap_pats, __priv_string_format};
 
     #[cfg(feature = "cmp")]
     pub use crate::cmp::{CmpWrapper, ConstCmp, IsAConstCmp, IsNotStdKind, IsStdKind};
@@ -15,11 +15,22 @@
 //! [`parse_type`]: self::parse_type
 //!
 
+pub mod datetime;
+pub mod uri;
+
+mod bool_parsing;
+mod byte_string;
+mod env_parse_macro;
+mod error_accumulator;
 mod get_parser;
+mod hex_parsing;
+mod include_parse_macro;
+mod int_literal;
 mod non_parsing_methods;
 mod parse_errors;
 mod parsing_polymorphism_macros;
 mod primitive_parsing;
+mod range_parsing;
 
 #[cfg(feature = "parsing_proc")]
 mod parser_method_macro;
@@ -27,17 +38,52 @@ mod parser_method_macro;
 /////////////////////////////////////////////////////////////////////////////////
 
 pub use self::{
+    bool_parsing::{BoolParser, EnvFlag},
+    byte_string::ByteString,
+    error_accumulator::ParseErrors,
     get_parser::{HasParser, StdParser},
+    int_literal::{IntLiteral, IntSuffix},
     parse_errors::{ErrorKind, ParseDirection, ParseError},
+    range_parsing::{OutOfRange, RangeInt},
 };
 
 #[cfg(feature = "parsing_proc")]
 #[doc(inline)]
 pub use self::parser_method_macro::parser_method;
 
+#[cfg(feature = "parsing_proc")]
+#[doc(hidden)]
+pub use self::parser_method_macro::__expected_error;
+
 #[doc(inline)]
 pub use self::parsing_polymorphism_macros::parse_type;
 
+#[doc(inline)]
+pub use self::range_parsing::parse_type_in;
+
+#[doc(inline)]
+pub use self::byte_string::byte_string;
+
+#[doc(inline)]
+pub use self::hex_parsing::{hex_array, uuid};
+
+#[doc(inline)]
+pub use self::include_parse_macro::include_parse;
+
+#[doc(hidden)]
+pub use self::hex_parsing::__expect_end;
+
+#[doc(hidden)]
+pub use self::env_parse_macro::{__env_parse_panic, __env_var_unset_panic};
+
+#[doc(hidden)]
+pub use self::include_parse_macro::__include_parse_panic;
+
+#[doc(hidden)]
+pub use self::range_parsing::{
+    __ParseRange, __ParseRangeWitness, __out_of_range_error, __range_bounds,
+};
+
 use crate::string::{self, Pattern};
 
 /// For parsing and traversing over strings in const contexts.
@@ -138,7 +184,7 @@ use crate::string::{self, Pattern};
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Parser<'a> {
-    parse_direction: ParseDirection,
+    pub(crate) parse_direction: ParseDirection,
     // this allows split methods to return the empty string after
     // the last delimiter, but only once.
     yielded_last_split: bool,
@@ -953,4 +999,46 @@ impl<'a> Parser<'a> {
             };
         }
     }
+
+    /// Skips past the first instance of `delimiter`,
+    /// or to the end of the string if there is none.
+    ///
+    /// Unlike [`find_skip`](Self::find_skip), this never fails,
+    /// which makes it useful for recovering from errors,
+    /// by skipping to the next line or delimiter
+    /// (eg: with [`ParseErrors::recover`](crate::parsing::ParseErrors::recover)).
+    ///
+    /// This method mutates the parser in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::Parser;
+    ///
+    /// let mut parser = Parser::new("foo\nbar\nbaz");
+    ///
+    /// parser.skip_past('\n');
+    /// assert_eq!(parser.remainder(), "bar\nbaz");
+    ///
+    /// parser.skip_past("\n");
+    /// assert_eq!(parser.remainder(), "baz");
+    ///
+    /// parser.skip_past('\n');
+    /// assert_eq!(parser.remainder(), "");
+    ///
+    /// ```
+    ///
+    pub const fn skip_past<'p, P>(&mut self, delimiter: P) -> &mut Self
+    where
+        P: Pattern<'p>,
+    {
+        let rem = match crate::string::find_skip(self.str, delimiter) {
+            Some(x) => x,
+            None => "",
+        };
+        self.parse_direction = ParseDirection::FromStart;
+        self.start_offset += (self.str.len() - rem.len()) as u32;
+        self.str = rem;
+        self
+    }
 }
@@ -0,0 +1,231 @@
+use crate::string;
+
+use super::{ErrorKind, HasParser, ParseDirection, ParseError, Parser};
+
+/// Configures how [`Parser::parse_bool_with`] parses a `bool`,
+/// with a table of keywords and the `bool` that each one parses into.
+///
+/// The [`STRICT`](Self::STRICT) rules are the ones that [`Parser::parse_bool`] uses,
+/// the [`LENIENT`](Self::LENIENT) rules are the ones that [`EnvFlag`] uses.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{
+///     parsing::{BoolParser, ErrorKind, Parser},
+///     result,
+/// };
+///
+/// const SWITCH: BoolParser<'_> = BoolParser::new(&[
+///     ("enabled", true),
+///     ("disabled", false),
+/// ]).ignore_case(true);
+///
+/// const FLAGS: [bool; 3] = {
+///     let mut parser = Parser::new("enabled,DISABLED,Enabled");
+///     let a = result::unwrap!(parser.parse_bool_with(&SWITCH));
+///     result::unwrap!(parser.strip_prefix(','));
+///     let b = result::unwrap!(parser.parse_bool_with(&SWITCH));
+///     result::unwrap!(parser.strip_prefix(','));
+///     let c = result::unwrap!(parser.parse_bool_with(&SWITCH));
+///     [a, b, c]
+/// };
+///
+/// assert_eq!(FLAGS, [true, false, true]);
+///
+/// let err = Parser::new("true").parse_bool_with(&SWITCH).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::ParseBool);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoolParser<'t> {
+    table: &'t [(&'t str, bool)],
+    ignore_case: bool,
+}
+
+impl<'t> BoolParser<'t> {
+    /// Only parses `true` and `false`, case-sensitively.
+    pub const STRICT: BoolParser<'static> = BoolParser {
+        table: &[("true", true), ("false", false)],
+        ignore_case: false,
+    };
+
+    /// Parses `true`/`false`, `yes`/`no`, `on`/`off`, and `1`/`0`,
+    /// ignoring ASCII case.
+    pub const LENIENT: BoolParser<'static> = BoolParser {
+        table: &[
+            ("true", true),
+            ("false", false),
+            ("yes", true),
+            ("no", false),
+            ("on", true),
+            ("off", false),
+            ("1", true),
+            ("0", false),
+        ],
+        ignore_case: true,
+    };
+
+    /// Constructs a `BoolParser` that parses the keywords in `table`,
+    /// case-sensitively.
+    ///
+    /// Empty keywords are ignored.
+    pub const fn new(table: &'t [(&'t str, bool)]) -> Self {
+        Self {
+            table,
+            ignore_case: false,
+        }
+    }
+
+    /// Sets whether the keywords are matched ignoring ASCII case.
+    pub const fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Whether the keywords are matched ignoring ASCII case.
+    pub const fn is_case_insensitive(&self) -> bool {
+        self.ignore_case
+    }
+
+    /// The keywords, and the `bool` that each one parses into.
+    pub const fn table(&self) -> &'t [(&'t str, bool)] {
+        self.table
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a `bool` using the keywords and case sensitivity of `config`.
+    ///
+    /// If multiple keywords match, this parses the longest one.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseBool`] error if no keyword
+    /// is a prefix of the unparsed string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{BoolParser, Parser},
+    ///     result,
+    /// };
+    ///
+    /// {
+    ///     let mut parser = Parser::new("Off;");
+    ///     let boolean = result::unwrap!(parser.parse_bool_with(&BoolParser::LENIENT));
+    ///     assert_eq!(boolean, false);
+    ///     assert_eq!(parser.remainder(), ";");
+    /// }
+    /// {
+    ///     let mut parser = Parser::new("Off;");
+    ///     assert!(parser.parse_bool_with(&BoolParser::STRICT).is_err());
+    ///     assert_eq!(parser.remainder(), "Off;");
+    /// }
+    ///
+    /// ```
+    pub const fn parse_bool_with(
+        &mut self,
+        config: &BoolParser<'_>,
+    ) -> Result<bool, ParseError<'a>> {
+        try_parsing! {self, FromStart, ret;
+            let bytes = self.str.as_bytes();
+            let mut found: Option<(usize, bool)> = None;
+
+            crate::for_range! {i in 0..config.table.len() =>
+                let (keyword, value) = config.table[i];
+                let keyword = keyword.as_bytes();
+
+                let is_longer = match found {
+                    Some((len, _)) => keyword.len() > len,
+                    None => !keyword.is_empty(),
+                };
+
+                if is_longer && starts_with(bytes, keyword, config.ignore_case) {
+                    found = Some((keyword.len(), value));
+                }
+            }
+
+            match found {
+                Some((len, value)) => {
+                    self.str = string::str_from(self.str, len);
+                    value
+                }
+                None => throw!(ErrorKind::ParseBool),
+            }
+        }
+    }
+}
+
+const fn starts_with(bytes: &[u8], prefix: &[u8], ignore_case: bool) -> bool {
+    if bytes.len() < prefix.len() {
+        return false;
+    }
+
+    crate::for_range! {i in 0..prefix.len() =>
+        let equal = if ignore_case {
+            bytes[i].eq_ignore_ascii_case(&prefix[i])
+        } else {
+            bytes[i] == prefix[i]
+        };
+
+        if !equal {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// A `bool` flag, parsed with the [`BoolParser::LENIENT`] rules,
+/// for boolean configuration that comes from environment variables.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{
+///     parsing::{EnvFlag, Parser, parse_type},
+///     result,
+/// };
+///
+/// const FLAGS: [EnvFlag; 4] = {
+///     let mut parser = Parser::new("YES 0 on False");
+///     let mut flags = [EnvFlag(false); 4];
+///
+///     konst::for_range! {i in 0..flags.len() =>
+///         parser.trim_start();
+///         flags[i] = result::unwrap!(parse_type!(parser, EnvFlag));
+///     }
+///     flags
+/// };
+///
+/// assert_eq!(FLAGS, [EnvFlag(true), EnvFlag(false), EnvFlag(true), EnvFlag(false)]);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnvFlag(pub bool);
+
+impl EnvFlag {
+    /// Parses an `EnvFlag` with the [`BoolParser::LENIENT`] rules.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        match parser.parse_bool_with(&BoolParser::LENIENT) {
+            Ok(x) => Ok(Self(x)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl HasParser for EnvFlag {
+    type Parser = Self;
+}
+
+impl From<EnvFlag> for bool {
+    fn from(flag: EnvFlag) -> bool {
+        flag.0
+    }
+}
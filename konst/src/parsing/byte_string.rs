@@ -0,0 +1,241 @@
+use crate::{string, utils::wrong_array_length_panic};
+
+use super::{ErrorKind, ParseDirection, ParseError, Parser, hex_parsing::hex_digit_value};
+
+/// The contents of a byte string literal, eg: `b"\x7fELF\x02"`,
+/// parsed by [`Parser::parse_byte_string`].
+///
+/// The escapes are validated when parsing,
+/// and decoded into a `[u8; N]` by [`decode`](Self::decode).
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{
+///     parsing::{ByteString, Parser},
+///     result,
+/// };
+///
+/// const LIT: ByteString<'_> = result::unwrap!(Parser::new(r#"b"\x7fELF\x02""#).parse_byte_string());
+/// const MAGIC: [u8; LIT.len()] = LIT.decode();
+///
+/// assert_eq!(LIT.contents(), r"\x7fELF\x02");
+/// assert_eq!(MAGIC, *b"\x7fELF\x02");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByteString<'a> {
+    contents: &'a str,
+    len: usize,
+}
+
+impl<'a> ByteString<'a> {
+    /// The text between the quotes of the literal, with the escapes undecoded.
+    pub const fn contents(&self) -> &'a str {
+        self.contents
+    }
+
+    /// The amount of bytes that the literal decodes into.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the literal decodes into no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes the escapes in the literal into an array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` isn't [`self.len()`](Self::len).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{parsing::Parser, result};
+    ///
+    /// let lit = result::unwrap!(Parser::new(r#"b"a\tb\\c\"\0""#).parse_byte_string());
+    ///
+    /// assert_eq!(lit.decode::<7>(), *b"a\tb\\c\"\0");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn decode<const N: usize>(&self) -> [u8; N] {
+        if N != self.len {
+            wrong_array_length_panic(N, self.len)
+        }
+
+        let bytes = self.contents.as_bytes();
+        let mut out = [0u8; N];
+        let mut i = 0;
+
+        crate::for_range! {out_i in 0..N =>
+            (out[out_i], i) = if bytes[i] == b'\\' {
+                match decode_escape(bytes, i) {
+                    Some(x) => x,
+                    None => panic!("escapes are validated when parsing"),
+                }
+            } else {
+                (bytes[i], i + 1)
+            };
+        }
+
+        out
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a byte string literal, eg: `b"\x7fELF\x02"`.
+    ///
+    /// The literal can contain any ASCII character other than `"` and `\`,
+    /// and these escapes:
+    /// - `\xHH`: a byte written as two hexadecimal digits.
+    /// - `\n`, `\r`, `\t`: newline, carriage return, and tab.
+    /// - `\0`: the zero byte.
+    /// - `\\`, `\"`, `\'`: the escaped character.
+    ///
+    /// To decode a byte string literal that takes up the whole string,
+    /// you can use the [`byte_string`] macro.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseByteString`] error whose
+    /// [`offset`](ParseError::offset) is that of:
+    /// - the start of the literal, if it doesn't start with `b"`.
+    /// - the `\` of an invalid escape.
+    /// - the first non-ASCII character.
+    /// - the end of the string, if the literal has no closing `"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, Parser},
+    ///     result,
+    /// };
+    ///
+    /// {
+    ///     let mut parser = Parser::new(r#"b"GIF89a\x01\x00" rest"#);
+    ///     let lit = result::unwrap!(parser.parse_byte_string());
+    ///     assert_eq!(lit.decode::<8>(), *b"GIF89a\x01\x00");
+    ///     assert_eq!(parser.remainder(), " rest");
+    /// }
+    /// {
+    ///     let mut parser = Parser::new(r#"b"\x7\q""#);
+    ///     let err = parser.parse_byte_string().unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::ParseByteString);
+    ///     assert_eq!(err.offset(), 2);
+    /// }
+    ///
+    /// ```
+    ///
+    /// [`byte_string`]: crate::parsing::byte_string
+    /// [`ErrorKind::ParseByteString`]: crate::parsing::ErrorKind::ParseByteString
+    pub const fn parse_byte_string(&mut self) -> Result<ByteString<'a>, ParseError<'a>> {
+        let mut p = self.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let bytes = p.str.as_bytes();
+
+        if !matches!(bytes, [b'b', b'"', ..]) {
+            return Err(p.to_error(ErrorKind::ParseByteString));
+        }
+
+        let mut i = 2;
+        let mut len = 0;
+        loop {
+            if i == bytes.len() {
+                return Err(p.to_error_at(i, ErrorKind::ParseByteString));
+            }
+
+            i = match bytes[i] {
+                b'"' => break,
+                b'\\' => match decode_escape(bytes, i) {
+                    Some((_, next)) => next,
+                    None => return Err(p.to_error_at(i, ErrorKind::ParseByteString)),
+                },
+                byte if byte.is_ascii() => i + 1,
+                _ => return Err(p.to_error_at(i, ErrorKind::ParseByteString)),
+            };
+            len += 1;
+        }
+
+        let contents = string::str_range(p.str, 2, i);
+
+        _ = p.skip(i + 1);
+        *self = p;
+
+        Ok(ByteString { contents, len })
+    }
+}
+
+// Decodes the escape whose `\` is at `bytes[i]`,
+// returning the decoded byte and the index after the escape.
+const fn decode_escape(bytes: &[u8], i: usize) -> Option<(u8, usize)> {
+    let rem = crate::slice::slice_from(bytes, i + 1);
+
+    let byte = match rem {
+        [b'x', hi, lo, ..] => {
+            return match (hex_digit_value(*hi), hex_digit_value(*lo)) {
+                (Some(hi), Some(lo)) => Some((hi * 16 + lo, i + 4)),
+                _ => None,
+            };
+        }
+        [b'n', ..] => b'\n',
+        [b'r', ..] => b'\r',
+        [b't', ..] => b'\t',
+        [b'0', ..] => b'\0',
+        [b @ (b'\\' | b'"' | b'\''), ..] => *b,
+        _ => return None,
+    };
+
+    Some((byte, i + 2))
+}
+
+/// Decodes a byte string literal (eg: `b"\x7fELF\x02"`)
+/// that takes up the whole string into a `[u8; N]` constant.
+///
+/// The length of the array is the amount of bytes that the literal decodes into.
+///
+/// This uses [`Parser::parse_byte_string`] to parse the string,
+/// more details on the syntax in its docs.
+///
+/// # Panics
+///
+/// This causes a compile-time error if the string isn't exactly a valid byte string literal,
+/// reporting the byte offset of the error.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::byte_string;
+///
+/// const MAGIC: [u8; 5] = byte_string!(r#"b"\x7fELF\x02""#);
+///
+/// assert_eq!(MAGIC, [0x7F, b'E', b'L', b'F', 0x02]);
+///
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 2] = konst::parsing::byte_string!(r#"b"\z""#);
+/// ```
+#[doc(inline)]
+pub use crate::__parsing_byte_string as byte_string;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parsing_byte_string {
+    ($string:expr $(,)?) => {{
+        const __LIT_81608BFNA5: $crate::parsing::ByteString<'_> =
+            $crate::__parse_whole_str! {$string, ParseByteString, parse_byte_string()};
+
+        const {
+            let array: [$crate::__::u8; __LIT_81608BFNA5.len()] = __LIT_81608BFNA5.decode();
+            array
+        }
+    }};
+}
@@ -0,0 +1,554 @@
+//! ISO 8601 / RFC 3339 dates and times, parsable in const.
+//!
+//! The types in this module implement [`HasParser`],
+//! so they can be parsed with the [`parse_type`] macro.
+//!
+//! All parsing errors have the [`ErrorKind::ParseDateTime`] kind,
+//! with the offset of the field that failed to parse.
+//!
+//! # Example
+//!
+//! ```rust
+//! use konst::parsing::{Parser, datetime::DateTime, parse_type};
+//! use konst::result;
+//!
+//! const EXPIRY: DateTime = {
+//!     let mut parser = Parser::new("2024-02-29T13:45:00.5+01:00");
+//!     result::unwrap!(parse_type!(parser, DateTime))
+//! };
+//!
+//! assert_eq!(EXPIRY.date().day(), 29);
+//! assert_eq!(EXPIRY.time().nanosecond(), 500_000_000);
+//! assert_eq!(EXPIRY.unix_timestamp(), 1709210700);
+//!
+//! // 2023 isn't a leap year
+//! assert!(parse_type!(Parser::new("2023-02-29T00:00:00Z"), DateTime).is_err());
+//!
+//! ```
+//!
+//! [`HasParser`]: crate::parsing::HasParser
+//! [`parse_type`]: crate::parsing::parse_type
+//! [`ErrorKind::ParseDateTime`]: crate::parsing::ErrorKind::ParseDateTime
+
+use crate::parsing::{ErrorKind, HasParser, ParseDirection, ParseError, Parser};
+
+/// Whether `year` is a leap year in the proleptic Gregorian calendar.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::datetime::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(is_leap_year(2000));
+/// assert!(!is_leap_year(1900));
+/// assert!(!is_leap_year(2023));
+///
+/// ```
+pub const fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// The amount of days in `month` (from 1 to 12) of `year`.
+///
+/// Returns `None` if `month` isn't in the `1..=12` range.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::datetime::days_in_month;
+///
+/// assert_eq!(days_in_month(2023, 2), Some(28));
+/// assert_eq!(days_in_month(2024, 2), Some(29));
+/// assert_eq!(days_in_month(2024, 4), Some(30));
+/// assert_eq!(days_in_month(2024, 12), Some(31));
+/// assert_eq!(days_in_month(2024, 13), None);
+///
+/// ```
+pub const fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    match month {
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1..=12 => Some(31),
+        _ => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A calendar date, parsed from the `YYYY-MM-DD` format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Constructs a `Date`, returning `None` if it's not a valid date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::datetime::Date;
+    ///
+    /// assert!(Date::new(2024, 2, 29).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// assert!(Date::new(2023, 0, 1).is_none());
+    /// assert!(Date::new(10000, 1, 1).is_none());
+    ///
+    /// ```
+    pub const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        match days_in_month(year, month) {
+            Some(max_day) if year <= 9999 && 1 <= day && day <= max_day => {
+                Some(Self { year, month, day })
+            }
+            _ => None,
+        }
+    }
+
+    /// The year, from 0 to 9999.
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month, from 1 to 12.
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting from 1.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The amount of days from 1970-01-01 to this date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::datetime::Date;
+    ///
+    /// let days = |y, m, d| Date::new(y, m, d).unwrap().days_since_unix_epoch();
+    ///
+    /// assert_eq!(days(1970, 1, 1), 0);
+    /// assert_eq!(days(1969, 12, 31), -1);
+    /// assert_eq!(days(2000, 3, 1), 11017);
+    ///
+    /// ```
+    pub const fn days_since_unix_epoch(&self) -> i64 {
+        // Algorithm from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let month = self.month as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The Unix timestamp of the start of this date (in UTC), in seconds.
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.days_since_unix_epoch() * SECONDS_PER_DAY
+    }
+
+    /// Parses a `Date` in the `YYYY-MM-DD` format.
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{ErrorKind, Parser, datetime::Date};
+    ///
+    /// let mut parser = Parser::new("2001-09-09 rest");
+    /// let date = Date::parse_with(&mut parser).unwrap();
+    /// assert_eq!((date.year(), date.month(), date.day()), (2001, 9, 9));
+    /// assert_eq!(parser.remainder(), " rest");
+    ///
+    /// let err = Date::parse_with(&mut Parser::new("2001-09-31")).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ParseDateTime);
+    /// assert_eq!(err.offset(), 8);
+    ///
+    /// ```
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let mut p = parser.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let year = crate::try_!(parse_digits(&mut p, 4, 0, 9999));
+        crate::try_!(expect_byte(&mut p, b'-'));
+        let month = crate::try_!(parse_digits(&mut p, 2, 1, 12));
+        crate::try_!(expect_byte(&mut p, b'-'));
+
+        let max_day = match days_in_month(year as u16, month as u8) {
+            Some(x) => x,
+            None => 0,
+        };
+        let day = crate::try_!(parse_digits(&mut p, 2, 1, max_day as u32));
+
+        *parser = p;
+        Ok(Self {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+}
+
+impl HasParser for Date {
+    type Parser = Self;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A time of day, parsed from the `HH:MM:SS[.fff]` format.
+///
+/// Leap seconds are not supported, the second can only go up to 59.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl Time {
+    /// Midnight, `00:00:00`
+    pub const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+    };
+
+    /// Constructs a `Time`, returning `None` if any argument is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::datetime::Time;
+    ///
+    /// assert!(Time::new(23, 59, 59, 999_999_999).is_some());
+    /// assert!(Time::new(24, 0, 0, 0).is_none());
+    /// assert!(Time::new(0, 60, 0, 0).is_none());
+    /// assert!(Time::new(0, 0, 0, 1_000_000_000).is_none());
+    ///
+    /// ```
+    pub const fn new(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<Self> {
+        if hour < 24 && minute < 60 && second < 60 && nanosecond < 1_000_000_000 {
+            Some(Self {
+                hour,
+                minute,
+                second,
+                nanosecond,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The hour, from 0 to 23.
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// The minute, from 0 to 59.
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// The second, from 0 to 59.
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// The fraction of a second, in nanoseconds.
+    pub const fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// The amount of whole seconds since midnight.
+    pub const fn seconds_since_midnight(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+
+    /// Parses a `Time` in the `HH:MM:SS[.fff]` format.
+    ///
+    /// The fraction of a second is optional, and can have from 1 to 9 digits.
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{ErrorKind, Parser, datetime::Time};
+    ///
+    /// let time = Time::parse_with(&mut Parser::new("12:34:56.789")).unwrap();
+    /// assert_eq!(time, Time::new(12, 34, 56, 789_000_000).unwrap());
+    ///
+    /// let err = Time::parse_with(&mut Parser::new("12:60:00")).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ParseDateTime);
+    /// assert_eq!(err.offset(), 3);
+    ///
+    /// ```
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let mut p = parser.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let hour = crate::try_!(parse_digits(&mut p, 2, 0, 23));
+        crate::try_!(expect_byte(&mut p, b':'));
+        let minute = crate::try_!(parse_digits(&mut p, 2, 0, 59));
+        crate::try_!(expect_byte(&mut p, b':'));
+        let second = crate::try_!(parse_digits(&mut p, 2, 0, 59));
+
+        let mut nanosecond = 0u32;
+        if let [b'.', ..] = p.remainder().as_bytes() {
+            _ = p.skip(1);
+
+            let bytes = p.remainder().as_bytes();
+            let mut digits = 0;
+            while digits < bytes.len() && bytes[digits].is_ascii_digit() {
+                digits += 1;
+            }
+
+            if digits == 0 || digits > 9 {
+                return Err(p.to_error(ErrorKind::ParseDateTime));
+            }
+
+            nanosecond = crate::try_!(parse_digits(&mut p, digits, 0, u32::MAX));
+            crate::for_range! {_ in digits..9 =>
+                nanosecond *= 10;
+            }
+        }
+
+        *parser = p;
+        Ok(Self {
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond,
+        })
+    }
+}
+
+impl HasParser for Time {
+    type Parser = Self;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An offset from UTC, parsed from either `Z` or `±HH:MM`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UtcOffset {
+    minutes: i16,
+}
+
+impl UtcOffset {
+    /// The zero offset.
+    pub const UTC: Self = Self { minutes: 0 };
+
+    /// Constructs a `UtcOffset` from an amount of minutes east of UTC,
+    /// returning `None` if it's 24 hours or more.
+    pub const fn from_minutes(minutes: i16) -> Option<Self> {
+        if minutes.unsigned_abs() < 24 * 60 {
+            Some(Self { minutes })
+        } else {
+            None
+        }
+    }
+
+    /// The amount of minutes east of UTC.
+    pub const fn minutes(&self) -> i16 {
+        self.minutes
+    }
+
+    /// Parses a `UtcOffset` from either `Z` (or `z`), or `+HH:MM`/`-HH:MM`.
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{Parser, datetime::UtcOffset};
+    ///
+    /// let parse = |s| UtcOffset::parse_with(&mut Parser::new(s)).map(|x| x.minutes());
+    ///
+    /// assert_eq!(parse("Z"), Ok(0));
+    /// assert_eq!(parse("+05:30"), Ok(330));
+    /// assert_eq!(parse("-08:00"), Ok(-480));
+    /// assert!(parse("+24:00").is_err());
+    ///
+    /// ```
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let mut p = parser.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let negative = match p.remainder().as_bytes() {
+            [b'Z' | b'z', ..] => {
+                _ = p.skip(1);
+                *parser = p;
+                return Ok(Self::UTC);
+            }
+            [b'+', ..] => false,
+            [b'-', ..] => true,
+            _ => return Err(p.to_error(ErrorKind::ParseDateTime)),
+        };
+        _ = p.skip(1);
+
+        let hours = crate::try_!(parse_digits(&mut p, 2, 0, 23));
+        crate::try_!(expect_byte(&mut p, b':'));
+        let minutes = crate::try_!(parse_digits(&mut p, 2, 0, 59));
+
+        let minutes = (hours * 60 + minutes) as i16;
+
+        *parser = p;
+        Ok(Self {
+            minutes: if negative { -minutes } else { minutes },
+        })
+    }
+}
+
+impl HasParser for UtcOffset {
+    type Parser = Self;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A date and time with an offset from UTC,
+/// parsed from the [RFC 3339] timestamp format (`YYYY-MM-DDTHH:MM:SS[.fff]±HH:MM`).
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::{Parser, datetime::DateTime, parse_type};
+/// use konst::result;
+///
+/// const BUILT_AT: DateTime = {
+///     let mut parser = Parser::new("1985-04-12T23:20:50.52Z");
+///     result::unwrap!(parse_type!(parser, DateTime))
+/// };
+///
+/// assert_eq!(BUILT_AT.unix_timestamp(), 482196050);
+///
+/// ```
+///
+/// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+}
+
+impl DateTime {
+    /// Constructs a `DateTime` from its components.
+    pub const fn new(date: Date, time: Time, offset: UtcOffset) -> Self {
+        Self { date, time, offset }
+    }
+
+    /// The date, in the local time of the offset.
+    pub const fn date(&self) -> Date {
+        self.date
+    }
+
+    /// The time of day, in the local time of the offset.
+    pub const fn time(&self) -> Time {
+        self.time
+    }
+
+    /// The offset from UTC.
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// The Unix timestamp of this date and time, in whole seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{Parser, datetime::DateTime};
+    ///
+    /// let parse = |s| DateTime::parse_with(&mut Parser::new(s)).unwrap().unix_timestamp();
+    ///
+    /// assert_eq!(parse("1970-01-01T00:00:00Z"), 0);
+    /// assert_eq!(parse("1970-01-01T01:00:00+01:00"), 0);
+    /// assert_eq!(parse("1969-12-31T23:59:59.999Z"), -1);
+    ///
+    /// ```
+    pub const fn unix_timestamp(&self) -> i64 {
+        self.date.unix_timestamp() + self.time.seconds_since_midnight() as i64
+            - self.offset.minutes as i64 * 60
+    }
+
+    /// Parses a `DateTime` in the RFC 3339 format.
+    ///
+    /// The date and time can be separated by either `T`, `t`, or a space.
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let mut p = parser.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let date = crate::try_!(Date::parse_with(&mut p));
+
+        match p.remainder().as_bytes() {
+            [b'T' | b't' | b' ', ..] => _ = p.skip(1),
+            _ => return Err(p.to_error(ErrorKind::ParseDateTime)),
+        }
+
+        let time = crate::try_!(Time::parse_with(&mut p));
+        let offset = crate::try_!(UtcOffset::parse_with(&mut p));
+
+        *parser = p;
+        Ok(Self { date, time, offset })
+    }
+}
+
+impl HasParser for DateTime {
+    type Parser = Self;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+// Parses exactly `digits` decimal digits,
+// erroring at the start of the number if it's not in the `min..=max` range.
+const fn parse_digits<'p>(
+    parser: &mut Parser<'p>,
+    digits: usize,
+    min: u32,
+    max: u32,
+) -> Result<u32, ParseError<'p>> {
+    let bytes = parser.remainder().as_bytes();
+    if bytes.len() < digits {
+        return Err(parser.to_error(ErrorKind::ParseDateTime));
+    }
+
+    let mut num = 0u32;
+    crate::for_range! {i in 0..digits =>
+        match bytes[i] {
+            b @ b'0'..=b'9' => num = num * 10 + (b - b'0') as u32,
+            _ => return Err(parser.to_error(ErrorKind::ParseDateTime)),
+        }
+    }
+
+    if num < min || num > max {
+        return Err(parser.to_error(ErrorKind::ParseDateTime));
+    }
+
+    _ = parser.skip(digits);
+    Ok(num)
+}
+
+const fn expect_byte<'p>(parser: &mut Parser<'p>, byte: u8) -> Result<(), ParseError<'p>> {
+    match parser.remainder().as_bytes() {
+        [b, ..] if *b == byte => {
+            _ = parser.skip(1);
+            Ok(())
+        }
+        _ => Err(parser.to_error(ErrorKind::ParseDateTime)),
+    }
+}
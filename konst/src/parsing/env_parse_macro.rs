@@ -0,0 +1,145 @@
+use crate::parsing::ParseError;
+
+use const_panic::{FmtArg, PanicVal};
+
+/// Parses the value of an environment variable at compile-time,
+/// with the [`HasParser`] impl of the type.
+///
+/// The environment variable is read with [`option_env`],
+/// and parsed with the [`parse_type`] macro,
+/// requiring the entire value of the variable to be parsed.
+///
+/// # Syntax
+///
+/// ```text
+/// env_parse!(<type>, <variable_name>)
+/// env_parse!(<type>, <variable_name>, default = <default_value>)
+/// ```
+///
+/// `<default_value>` is a `<type>` that's used when the variable isn't set.
+/// Variables that are set to an empty string are parsed like any other value.
+///
+/// # Panics
+///
+/// This causes a compile-time error if the variable isn't set (and no `default` was passed),
+/// or if it couldn't be parsed as a `<type>` (or had unparsed text after it),
+/// printing the name of the variable and the [`ParseError`].
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::{Parser, ParseError, HasParser, EnvFlag};
+///
+/// const PORT: u16 = konst::env_parse!(u16, "KONST_EXAMPLE_PORT", default = 8080);
+/// const VERBOSE: EnvFlag = konst::env_parse!(EnvFlag, "KONST_EXAMPLE_VERBOSE", default = EnvFlag(false));
+/// const LEVEL: Level = konst::env_parse!(Level, "KONST_EXAMPLE_LEVEL", default = Level::Low);
+///
+/// assert_eq!(PORT, 8080);
+/// assert_eq!(VERBOSE, EnvFlag(false));
+/// assert_eq!(LEVEL, Level::Low);
+///
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// impl HasParser for Level {
+///     type Parser = Self;
+/// }
+///
+/// impl Level {
+///     const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+///         if parser.strip_prefix("low").is_ok() {
+///             Ok(Level::Low)
+///         } else if parser.strip_prefix("high").is_ok() {
+///             Ok(Level::High)
+///         } else {
+///             Err(parser.to_other_error(&"expected `low` or `high`"))
+///         }
+///     }
+/// }
+/// ```
+///
+/// ### Errors
+///
+/// This demonstrates the error for an invalid value.
+///
+/// ```compile_fail
+/// // the `CARGO_PKG_NAME` environment variable is always set by cargo
+/// const _: u32 = konst::env_parse!(u32, "CARGO_PKG_NAME");
+/// ```
+///
+/// the above code fails to compile with this error:
+///
+/// ```text
+/// error[E0080]: evaluation panicked: could not parse the `CARGO_PKG_NAME` environment variable: error from the start at the 0 byte offset while parsing an integer
+/// ```
+///
+/// [`HasParser`]: crate::parsing::HasParser
+/// [`parse_type`]: crate::parsing::parse_type
+/// [`ParseError`]: crate::parsing::ParseError
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
+#[macro_export]
+macro_rules! env_parse {
+    ($type:ty, $name:expr $(,)?) => {
+        $crate::__env_parse! {$type, $name, $crate::parsing::__env_var_unset_panic($name)}
+    };
+    ($type:ty, $name:expr, default = $default:expr $(,)?) => {
+        $crate::__env_parse! {$type, $name, $default}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __env_parse {
+    ($type:ty, $name:expr, $default:expr) => {
+        const {
+            match $crate::__::option_env!($name) {
+                $crate::__::Some(string) => {
+                    let parser = &mut $crate::Parser::new(string);
+                    let res: $crate::__::Result<$type, $crate::parsing::ParseError<'_>> =
+                        <<$type as $crate::parsing::HasParser>::Parser>::parse_with(parser);
+
+                    match res {
+                        $crate::__::Ok(value) => {
+                            if !parser.is_empty() {
+                                $crate::parsing::__env_parse_panic(
+                                    $name,
+                                    &parser.to_other_error(&"expected the end of the value"),
+                                )
+                            }
+                            value
+                        }
+                        $crate::__::Err(e) => $crate::parsing::__env_parse_panic($name, &e),
+                    }
+                }
+                $crate::__::None => $default,
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __env_parse_panic(name: &str, error: &ParseError<'_>) -> ! {
+    const_panic::concat_panic(&[
+        &[
+            PanicVal::write_str("could not parse the `"),
+            PanicVal::write_str(name),
+            PanicVal::write_str("` environment variable: "),
+        ],
+        &error.to_panicvals(FmtArg::DISPLAY),
+    ])
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __env_var_unset_panic(name: &str) -> ! {
+    const_panic::concat_panic(&[&[
+        PanicVal::write_str("the `"),
+        PanicVal::write_str(name),
+        PanicVal::write_str("` environment variable is not set"),
+    ]])
+}
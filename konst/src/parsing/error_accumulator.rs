@@ -0,0 +1,264 @@
+use crate::{
+    Parser,
+    parsing::{ErrorKind, ParseError},
+    string::Pattern,
+};
+
+use const_panic::{FmtArg, PanicFmt, PanicVal};
+
+use core::fmt;
+
+/// A fixed-capacity collection of [`ParseError`]s,
+/// for continuing to parse after errors, and reporting all of them at once.
+///
+/// Errors beyond the `CAP` capacity are counted, but not stored.
+///
+/// [`panic_if_any`](Self::panic_if_any) panics with a message listing all stored errors,
+/// so that a const-validated input reports all of its problems in a single compile error.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::{ParseErrors, Parser};
+///
+/// const fn parse_ports(input: &str) -> Result<[u16; 4], ParseErrors<'_, 8>> {
+///     let mut errors = ParseErrors::new();
+///     let mut parser = Parser::new(input);
+///     let mut ports = [0u16; 4];
+///
+///     let mut i = 0;
+///     while i < ports.len() && !parser.is_empty() {
+///         match parser.parse_u16() {
+///             Ok(port) => {
+///                 ports[i] = port;
+///                 parser.skip_past('\n');
+///             }
+///             Err(e) => errors.recover(&mut parser, e, '\n'),
+///         }
+///         i += 1;
+///     }
+///
+///     if errors.has_errors() {
+///         Err(errors)
+///     } else {
+///         Ok(ports)
+///     }
+/// }
+///
+/// assert_eq!(parse_ports("80\n443\n8080\n3000").ok(), Some([80, 443, 8080, 3000]));
+///
+/// let errors = parse_ports("80\nhttp\n99999\n3000").unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.as_slice()[0].offset(), 3);
+/// assert_eq!(errors.as_slice()[1].offset(), 8);
+///
+/// ```
+///
+/// ### Panicking
+///
+/// This example demonstrates the error message of [`panic_if_any`](Self::panic_if_any).
+///
+/// ```compile_fail
+/// use konst::parsing::{ParseErrors, Parser};
+///
+/// const _: () = {
+///     let mut errors = ParseErrors::<'_, 4>::new();
+///     let mut parser = Parser::new("1,a,3,b");
+///
+///     while !parser.is_empty() {
+///         match parser.parse_u8() {
+///             Ok(_) => _ = parser.skip_past(','),
+///             Err(e) => errors.recover(&mut parser, e, ','),
+///         }
+///     }
+///
+///     errors.panic_if_any();
+/// };
+/// ```
+///
+/// the above code fails to compile with this error:
+///
+/// ```text
+/// error[E0080]: evaluation panicked: found 2 parse errors:
+///   - error from the start at the 2 byte offset while parsing an integer
+///   - error from the start at the 6 byte offset while parsing an integer
+/// ```
+pub struct ParseErrors<'a, const CAP: usize> {
+    errors: [ParseError<'a>; CAP],
+    len: usize,
+    total: usize,
+}
+
+impl<'a, const CAP: usize> ParseErrors<'a, CAP> {
+    /// Constructs an empty `ParseErrors`.
+    pub const fn new() -> Self {
+        Self {
+            errors: [const { PLACEHOLDER }; CAP],
+            len: 0,
+            total: 0,
+        }
+    }
+
+    /// Adds an error, only storing it if there is capacity left.
+    pub const fn push(&mut self, error: ParseError<'a>) {
+        if self.len < CAP {
+            self.errors[self.len] = error;
+            self.len += 1;
+        }
+        self.total += 1;
+    }
+
+    /// Adds the error of `result` (if it's an error),
+    /// returning the `Ok` value otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{ParseErrors, Parser};
+    ///
+    /// let mut errors = ParseErrors::<'_, 2>::new();
+    ///
+    /// assert_eq!(errors.record(Parser::new("12").parse_u8()), Some(12));
+    /// assert_eq!(errors.record(Parser::new("xx").parse_u8()), None);
+    /// assert_eq!(errors.len(), 1);
+    ///
+    /// ```
+    pub const fn record<T: Copy>(&mut self, result: Result<T, ParseError<'a>>) -> Option<T> {
+        match result {
+            Ok(x) => Some(x),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        }
+    }
+
+    /// Adds `error`, then recovers by skipping `parser`
+    /// past the next `delimiter` (eg: the next line),
+    /// or to the end if there is no delimiter.
+    ///
+    /// For an example, you can look at the [type-level docs](Self#example).
+    pub const fn recover<'p, P>(
+        &mut self,
+        parser: &mut Parser<'a>,
+        error: ParseError<'a>,
+        delimiter: P,
+    ) where
+        P: Pattern<'p>,
+    {
+        self.push(error);
+        _ = parser.skip_past(delimiter);
+    }
+
+    /// The amount of stored errors.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no errors are stored, equivalent to `self.len() == 0`.
+    ///
+    /// This can be true after errors were added if `CAP` is `0`,
+    /// use [`has_errors`](Self::has_errors) to check whether any error was added.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether any error was added, including the ones that didn't fit.
+    pub const fn has_errors(&self) -> bool {
+        self.total != 0
+    }
+
+    /// Whether the capacity is full, in which case further errors are only counted.
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// The amount of added errors, including the ones that didn't fit.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The stored errors, in the order that they were added.
+    pub const fn as_slice(&self) -> &[ParseError<'a>] {
+        crate::slice::slice_up_to(&self.errors, self.len)
+    }
+
+    /// Panics if any error was added, with a message listing all stored errors.
+    ///
+    /// If more errors were added than could be stored,
+    /// the message also includes how many were left out.
+    ///
+    /// For an example, you can look at the [type-level docs](Self#panicking).
+    #[track_caller]
+    pub const fn panic_if_any(&self) {
+        if self.total == 0 {
+            return;
+        }
+
+        // each row is a stored error, the header is in the first row,
+        // and the amount of errors that didn't fit is in the last row.
+        const HEADER_LEN: usize = 3;
+        const ROW_LEN: usize = HEADER_LEN + 1 + ParseError::PV_COUNT + 3;
+
+        let header = [
+            PanicVal::write_str("found "),
+            PanicVal::from_usize(self.total, FmtArg::DISPLAY),
+            PanicVal::write_str(if self.total == 1 {
+                " parse error:"
+            } else {
+                " parse errors:"
+            }),
+        ];
+
+        if self.len == 0 {
+            const_panic::concat_panic(&[&header])
+        }
+
+        let mut rows = [[PanicVal::EMPTY; ROW_LEN]; CAP];
+
+        crate::for_range! {i in 0..HEADER_LEN =>
+            rows[0][i] = header[i];
+        }
+
+        crate::for_range! {i in 0..self.len =>
+            let error = self.errors[i].to_panicvals(FmtArg::DISPLAY);
+
+            rows[i][HEADER_LEN] = PanicVal::write_str("\n  - ");
+            crate::for_range! {j in 0..error.len() =>
+                rows[i][HEADER_LEN + 1 + j] = error[j];
+            }
+        }
+
+        let left_out = self.total - self.len;
+        if left_out != 0 {
+            let last = &mut rows[self.len - 1];
+            last[ROW_LEN - 3] = PanicVal::write_str("\n  ... and ");
+            last[ROW_LEN - 2] = PanicVal::from_usize(left_out, FmtArg::DISPLAY);
+            last[ROW_LEN - 1] = PanicVal::write_str(" more");
+        }
+
+        let mut row_refs: [&[PanicVal<'_>]; CAP] = [&[]; CAP];
+        crate::for_range! {i in 0..self.len =>
+            row_refs[i] = &rows[i];
+        }
+
+        const_panic::concat_panic(&row_refs)
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for ParseErrors<'_, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseErrors")
+            .field("errors", &self.as_slice())
+            .field("total", &self.total)
+            .finish()
+    }
+}
+
+impl<const CAP: usize> Default for ParseErrors<'_, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const PLACEHOLDER: ParseError<'static> = ParseError::new(&Parser::new(""), ErrorKind::Other);
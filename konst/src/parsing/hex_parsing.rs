@@ -0,0 +1,359 @@
+use crate::string::{self, Pattern};
+
+use super::{ErrorKind, ParseDirection, ParseError, Parser};
+
+impl<'a> Parser<'a> {
+    /// Parses `N` bytes written as `2 * N` hexadecimal digits,
+    /// optionally prefixed by `0x` or `0X`.
+    ///
+    /// Both uppercase and lowercase hexadecimal digits are accepted.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// To parse a hex string that takes up the whole string,
+    /// you can use the [`hex_array`] macro.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseHex`] error whose
+    /// [`offset`](ParseError::offset) is that of the first nibble
+    /// that isn't a hexadecimal digit (or the end of the string, if it's too short).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, Parser},
+    ///     result,
+    /// };
+    ///
+    /// {
+    ///     let mut parser = Parser::new("0xDEADbeef;rest");
+    ///     let arr: [u8; 4] = result::unwrap!(parser.parse_hex_array());
+    ///     assert_eq!(arr, [0xDE, 0xAD, 0xBE, 0xEF]);
+    ///     assert_eq!(parser.remainder(), ";rest");
+    /// }
+    /// {
+    ///     let mut parser = Parser::new("00ff1z00");
+    ///     let err = parser.parse_hex_array::<4>().unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::ParseHex);
+    ///     assert_eq!(err.offset(), 5);
+    ///     assert_eq!(parser.remainder(), "00ff1z00");
+    /// }
+    ///
+    /// ```
+    ///
+    /// [`hex_array`]: crate::parsing::hex_array
+    pub const fn parse_hex_array<const N: usize>(&mut self) -> Result<[u8; N], ParseError<'a>> {
+        try_parsing! {self, FromStart, ret;
+            let mut rem = strip_hex_prefix(self.str);
+            let mut ret = [0u8; N];
+
+            crate::for_range! {i in 0..N =>
+                ret[i] = crate::try_!(parse_hex_byte(self, &mut rem, ErrorKind::ParseHex));
+            }
+
+            self.str = rem;
+
+            ret
+        }
+    }
+
+    /// Parses `N` bytes written as pairs of hexadecimal digits separated by `separator`,
+    /// optionally prefixed by `0x` or `0X`.
+    ///
+    /// Both uppercase and lowercase hexadecimal digits are accepted.
+    /// The separator is required between every pair of digits,
+    /// and is not parsed after the last pair.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseHex`] error whose
+    /// [`offset`](ParseError::offset) is that of the first nibble
+    /// that isn't a hexadecimal digit, or that of the missing separator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, Parser},
+    ///     result,
+    /// };
+    ///
+    /// {
+    ///     let mut parser = Parser::new("00:1B:44:11:3a:B7 eth0");
+    ///     let mac: [u8; 6] = result::unwrap!(parser.parse_hex_array_separated(':'));
+    ///     assert_eq!(mac, [0x00, 0x1B, 0x44, 0x11, 0x3A, 0xB7]);
+    ///     assert_eq!(parser.remainder(), " eth0");
+    /// }
+    /// {
+    ///     let mut parser = Parser::new("00:1B-44");
+    ///     let err = parser.parse_hex_array_separated::<3, _>(':').unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::ParseHex);
+    ///     assert_eq!(err.offset(), 5);
+    /// }
+    ///
+    /// ```
+    pub const fn parse_hex_array_separated<'p, const N: usize, P>(
+        &mut self,
+        separator: P,
+    ) -> Result<[u8; N], ParseError<'a>>
+    where
+        P: Pattern<'p>,
+    {
+        try_parsing! {self, FromStart, ret;
+            let mut rem = strip_hex_prefix(self.str);
+            let mut ret = [0u8; N];
+
+            crate::for_range! {i in 0..N =>
+                if i != 0 {
+                    rem = match string::strip_prefix(rem, separator) {
+                        Some(x) => x,
+                        None => return Err(error_at(self, rem, ErrorKind::ParseHex)),
+                    };
+                }
+                ret[i] = crate::try_!(parse_hex_byte(self, &mut rem, ErrorKind::ParseHex));
+            }
+
+            self.str = rem;
+
+            ret
+        }
+    }
+
+    /// Parses a UUID in the canonical hyphenated form
+    /// (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`) into its 16 bytes.
+    ///
+    /// Both uppercase and lowercase hexadecimal digits are accepted.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// To parse a UUID that takes up the whole string,
+    /// you can use the [`uuid`] macro.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseUuid`] error whose
+    /// [`offset`](ParseError::offset) is that of the first nibble
+    /// that isn't a hexadecimal digit, or that of the missing hyphen.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, Parser},
+    ///     result,
+    /// };
+    ///
+    /// {
+    ///     let mut parser = Parser::new("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    ///     assert_eq!(
+    ///         result::unwrap!(parser.parse_uuid()),
+    ///         [
+    ///             0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f,
+    ///             0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+    ///         ],
+    ///     );
+    ///     assert!(parser.is_empty());
+    /// }
+    /// {
+    ///     let mut parser = Parser::new("67e55044-10b1-426f-9g47-bb680e5fe0c8");
+    ///     let err = parser.parse_uuid().unwrap_err();
+    ///     assert_eq!(err.kind(), ErrorKind::ParseUuid);
+    ///     assert_eq!(err.offset(), 20);
+    /// }
+    ///
+    /// ```
+    ///
+    /// [`uuid`]: crate::parsing::uuid
+    pub const fn parse_uuid(&mut self) -> Result<[u8; 16], ParseError<'a>> {
+        try_parsing! {self, FromStart, ret;
+            let mut rem = self.str;
+            let mut ret = [0u8; 16];
+
+            crate::for_range! {i in 0..16 =>
+                if matches!(i, 4 | 6 | 8 | 10) {
+                    rem = match rem.as_bytes() {
+                        [b'-', ..] => string::str_from(rem, 1),
+                        _ => return Err(error_at(self, rem, ErrorKind::ParseUuid)),
+                    };
+                }
+                ret[i] = crate::try_!(parse_hex_byte(self, &mut rem, ErrorKind::ParseUuid));
+            }
+
+            self.str = rem;
+
+            ret
+        }
+    }
+}
+
+const fn strip_hex_prefix(string: &str) -> &str {
+    match string.as_bytes() {
+        [b'0', b'x' | b'X', ..] => string::str_from(string, 2),
+        _ => string,
+    }
+}
+
+pub(super) const fn hex_digit_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+// Parses a pair of hex digits from the start of `rem`,
+// which must be a suffix of `parser.str`.
+const fn parse_hex_byte<'a>(
+    parser: &Parser<'a>,
+    rem: &mut &'a str,
+    kind: ErrorKind,
+) -> Result<u8, ParseError<'a>> {
+    let bytes = rem.as_bytes();
+    let mut value = 0u8;
+
+    crate::for_range! {i in 0..2 =>
+        let nibble = if i < bytes.len() {
+            hex_digit_value(bytes[i])
+        } else {
+            None
+        };
+
+        match nibble {
+            Some(nibble) => value = (value << 4) | nibble,
+            None => return Err(error_at(parser, string::str_from(rem, i), kind)),
+        }
+    }
+
+    *rem = string::str_from(rem, 2);
+
+    Ok(value)
+}
+
+// Constructs an error pointing at the start of `rem`,
+// which must be a suffix of `parser.str`.
+const fn error_at<'a>(parser: &Parser<'a>, rem: &str, kind: ErrorKind) -> ParseError<'a> {
+    parser.to_error_at(parser.str.len() - rem.len(), kind)
+}
+
+#[doc(hidden)]
+pub const fn __expect_end<'a>(parser: &Parser<'a>, kind: ErrorKind) -> Result<(), ParseError<'a>> {
+    if parser.str.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::new(parser, kind))
+    }
+}
+
+/// Parses a string of hexadecimal digits (with an optional `0x` prefix)
+/// into a `[u8; N]` at compile-time.
+///
+/// The length of the array is inferred from the context.
+///
+/// This macro takes an optional second argument,
+/// the [`Pattern`](crate::string::Pattern) that separates each byte,
+/// in which case it uses [`Parser::parse_hex_array_separated`] to parse the string,
+/// and otherwise uses [`Parser::parse_hex_array`].
+///
+/// # Panics
+///
+/// This causes a compile-time error if the string isn't exactly `N`
+/// hexadecimal-encoded bytes,
+/// reporting the byte offset of the invalid nibble.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::hex_array;
+///
+/// const KEY: [u8; 8] = hex_array!("0x0123456789abcdef");
+/// assert_eq!(KEY, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+///
+/// const MAC: [u8; 6] = hex_array!("00-1b-44-11-3a-b7", '-');
+/// assert_eq!(MAC, [0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7]);
+///
+/// ```
+///
+/// ### Invalid nibble
+///
+/// ```compile_fail
+/// const KEY: [u8; 4] = konst::parsing::hex_array!("0011x233");
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
+pub use crate::__parsing_hex_array as hex_array;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parsing_hex_array {
+    ($string:expr $(,)?) => {
+        $crate::__parse_whole_str! {$string, ParseHex, parse_hex_array()}
+    };
+    ($string:expr, $separator:expr $(,)?) => {
+        $crate::__parse_whole_str! {$string, ParseHex, parse_hex_array_separated($separator)}
+    };
+}
+
+/// Parses a UUID in the canonical hyphenated form
+/// (`xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`) into a `[u8; 16]` at compile-time.
+///
+/// This uses [`Parser::parse_uuid`] to parse the string.
+///
+/// # Panics
+///
+/// This causes a compile-time error if the string isn't a canonical hyphenated UUID,
+/// reporting the byte offset of the invalid nibble or missing hyphen.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::uuid;
+///
+/// const ID: [u8; 16] = uuid!("00112233-4455-6677-8899-AABBCCDDEEFF");
+///
+/// assert_eq!(
+///     ID,
+///     [
+///         0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+///         0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+///     ],
+/// );
+///
+/// ```
+///
+/// ### Missing hyphen
+///
+/// ```compile_fail
+/// const ID: [u8; 16] = konst::parsing::uuid!("00112233-4455-6677-889900-AABBCCDDEEFF");
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
+pub use crate::__parsing_uuid as uuid;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parsing_uuid {
+    ($string:expr $(,)?) => {
+        $crate::__parse_whole_str! {$string, ParseUuid, parse_uuid()}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_whole_str {
+    ($string:expr, $kind:ident, $method:ident ($($args:tt)*)) => {
+        const {
+            let parser = &mut $crate::Parser::new($string);
+            let ret = $crate::result::unwrap!(parser.$method($($args)*));
+            $crate::result::unwrap!($crate::parsing::__expect_end(
+                parser,
+                $crate::parsing::ErrorKind::$kind,
+            ));
+            ret
+        }
+    };
+}
@@ -0,0 +1,135 @@
+use crate::parsing::ParseError;
+
+use const_panic::{FmtArg, PanicVal};
+
+/// Parses a file at compile-time, with the [`HasParser`] impl of the type.
+///
+/// The file is embedded with [`include_str`]
+/// (so the path is relative to the file that invokes this macro),
+/// and parsed with the [`parse_type`] macro,
+/// requiring the entire file to be parsed, except for trailing whitespace.
+///
+/// # Panics
+///
+/// This causes a compile-time error if the file couldn't be parsed as the type,
+/// or had unparsed text after it,
+/// printing the path of the file, the line and column of the error, and the [`ParseError`].
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::{HasParser, ParseError, Parser, include_parse};
+///
+/// // `version.txt` contains `1.84.0`, followed by a newline
+/// const VERSION: Version = include_parse!(Version, "version.txt");
+///
+/// assert_eq!(VERSION, Version { major: 1, minor: 84, patch: 0 });
+///
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Version {
+///     major: u16,
+///     minor: u16,
+///     patch: u16,
+/// }
+///
+/// impl HasParser for Version {
+///     type Parser = Self;
+/// }
+///
+/// impl Version {
+///     const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+///         let major = konst::try_!(parser.parse_u16());
+///         konst::try_!(parser.strip_prefix('.'));
+///         let minor = konst::try_!(parser.parse_u16());
+///         konst::try_!(parser.strip_prefix('.'));
+///         let patch = konst::try_!(parser.parse_u16());
+///         Ok(Version { major, minor, patch })
+///     }
+/// }
+/// ```
+///
+/// ### Errors
+///
+/// This demonstrates the error for a file that isn't entirely parsed.
+///
+/// ```compile_fail
+/// // `version.txt` contains `1.84.0`, followed by a newline
+/// const _: u32 = konst::parsing::include_parse!(u32, "version.txt");
+/// ```
+///
+/// the above code fails to compile with this error:
+///
+/// ```text
+/// error[E0080]: evaluation panicked: could not parse `version.txt` at line 1, column 2: error from the start at the 1 byte offset: expected the end of the file
+/// ```
+///
+/// [`HasParser`]: crate::parsing::HasParser
+/// [`parse_type`]: crate::parsing::parse_type
+/// [`ParseError`]: crate::parsing::ParseError
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
+pub use crate::__parsing_include_parse as include_parse;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parsing_include_parse {
+    ($type:ty, $path:expr $(,)?) => {
+        const {
+            const SOURCE: &$crate::__::str = $crate::__::include_str!($path);
+
+            let parser = &mut $crate::Parser::new(SOURCE);
+            let res: $crate::__::Result<$type, $crate::parsing::ParseError<'_>> =
+                <<$type as $crate::parsing::HasParser>::Parser>::parse_with(parser);
+
+            match res {
+                $crate::__::Ok(value) => {
+                    if !parser.copy().trim_end().is_empty() {
+                        $crate::parsing::__include_parse_panic(
+                            $path,
+                            SOURCE,
+                            &parser.to_other_error(&"expected the end of the file"),
+                        )
+                    }
+                    value
+                }
+                $crate::__::Err(e) => $crate::parsing::__include_parse_panic($path, SOURCE, &e),
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __include_parse_panic(path: &str, source: &str, error: &ParseError<'_>) -> ! {
+    let (line, column) = line_and_column(source.as_bytes(), error.offset());
+
+    const_panic::concat_panic(&[
+        &[
+            PanicVal::write_str("could not parse `"),
+            PanicVal::write_str(path),
+            PanicVal::write_str("` at line "),
+            PanicVal::from_usize(line, FmtArg::DISPLAY),
+            PanicVal::write_str(", column "),
+            PanicVal::from_usize(column, FmtArg::DISPLAY),
+            PanicVal::write_str(": "),
+        ],
+        &error.to_panicvals(FmtArg::DISPLAY),
+    ])
+}
+
+// computes the 1-based line and column (counted in chars) of the byte at `offset`
+const fn line_and_column(source: &[u8], offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+
+    crate::for_range! {i in 0..offset =>
+        if source[i] == b'\n' {
+            line += 1;
+            column = 1;
+        } else if crate::string::byte_is_char_boundary!(source[i]) {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
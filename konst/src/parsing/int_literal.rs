@@ -0,0 +1,350 @@
+use crate::string;
+
+use super::{
+    ErrorKind, HasParser, ParseDirection, ParseError, Parser, hex_parsing::hex_digit_value,
+};
+
+/// The type suffix of a Rust integer literal, eg: the `u32` in `1_000u32`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IntSuffix {
+    /// The `u8` suffix
+    U8,
+    /// The `u16` suffix
+    U16,
+    /// The `u32` suffix
+    U32,
+    /// The `u64` suffix
+    U64,
+    /// The `u128` suffix
+    U128,
+    /// The `usize` suffix
+    Usize,
+    /// The `i8` suffix
+    I8,
+    /// The `i16` suffix
+    I16,
+    /// The `i32` suffix
+    I32,
+    /// The `i64` suffix
+    I64,
+    /// The `i128` suffix
+    I128,
+    /// The `isize` suffix
+    Isize,
+}
+
+impl IntSuffix {
+    /// The suffix as it's written in the literal.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::U128 => "u128",
+            Self::Usize => "usize",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::I128 => "i128",
+            Self::Isize => "isize",
+        }
+    }
+
+    /// Whether this is the suffix of a signed integer type.
+    pub const fn is_signed(self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+        )
+    }
+
+    /// The size of the integer type in bits,
+    /// `usize` and `isize` are the size of the target's pointers.
+    pub const fn bits(self) -> u32 {
+        match self {
+            Self::U8 | Self::I8 => 8,
+            Self::U16 | Self::I16 => 16,
+            Self::U32 | Self::I32 => 32,
+            Self::U64 | Self::I64 => 64,
+            Self::U128 | Self::I128 => 128,
+            Self::Usize | Self::Isize => usize::BITS,
+        }
+    }
+
+    const fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(match bytes {
+            b"u8" => Self::U8,
+            b"u16" => Self::U16,
+            b"u32" => Self::U32,
+            b"u64" => Self::U64,
+            b"u128" => Self::U128,
+            b"usize" => Self::Usize,
+            b"i8" => Self::I8,
+            b"i16" => Self::I16,
+            b"i32" => Self::I32,
+            b"i64" => Self::I64,
+            b"i128" => Self::I128,
+            b"isize" => Self::Isize,
+            _ => return None,
+        })
+    }
+}
+
+/// A Rust-like integer literal, parsed by [`Parser::parse_int_literal`].
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{
+///     parsing::{IntLiteral, IntSuffix, Parser, parse_type},
+///     result,
+/// };
+///
+/// const LIT: IntLiteral = result::unwrap!(parse_type!(Parser::new("0xFFi8"), IntLiteral));
+///
+/// assert_eq!(LIT.suffix(), Some(IntSuffix::I8));
+/// assert_eq!(LIT.radix(), 16);
+/// assert_eq!(LIT.to_i128(), Some(-1));
+/// assert_eq!(LIT.to_u128(), None);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IntLiteral {
+    magnitude: u128,
+    negative: bool,
+    radix: u32,
+    suffix: Option<IntSuffix>,
+}
+
+impl IntLiteral {
+    /// The type suffix of the literal, if it had one.
+    pub const fn suffix(&self) -> Option<IntSuffix> {
+        self.suffix
+    }
+
+    /// The radix of the literal, determined by its `0x`, `0o`, or `0b` prefix
+    /// (`10` if it had no prefix).
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Whether the value of the literal is negative.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The absolute value of the literal.
+    pub const fn magnitude(&self) -> u128 {
+        self.magnitude
+    }
+
+    /// The value of the literal as a `u128`, `None` if it's negative.
+    pub const fn to_u128(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude)
+        }
+    }
+
+    /// The value of the literal as an `i128`, `None` if it doesn't fit.
+    pub const fn to_i128(&self) -> Option<i128> {
+        if self.negative {
+            if self.magnitude <= i128::MIN.unsigned_abs() {
+                Some((self.magnitude as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else if self.magnitude <= i128::MAX as u128 {
+            Some(self.magnitude as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Parses an `IntLiteral` with [`Parser::parse_int_literal`].
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        parser.parse_int_literal()
+    }
+}
+
+impl HasParser for IntLiteral {
+    type Parser = Self;
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a Rust-like integer literal, eg: `1_000u32`, `0xFFi8`, `-0b1010`, `255_u8`.
+    ///
+    /// The literal is made of (in order):
+    /// - an optional `-` sign.
+    /// - an optional `0x`, `0o`, or `0b` radix prefix.
+    /// - the digits, which can be separated by any amount of `_`,
+    ///   and must start with a digit if there's no radix prefix.
+    /// - an optional type suffix, eg: `u8`, `isize`.
+    ///
+    /// The value is checked to fit in the type of the suffix.
+    /// Literals with a radix prefix and a signed suffix (but no sign)
+    /// can use all the bits of the type,
+    /// reinterpreting them as a two's complement number, eg: `0xFFi8` is `-1`.
+    /// Literals without a suffix must fit in a `u128`, or an `i128` if they're negative.
+    ///
+    /// This method mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseInteger`] error:
+    /// - at the start of the literal if it has no digits,
+    ///   or if its value doesn't fit in the type.
+    /// - at the invalid digit if a digit is too large for the radix.
+    /// - at the suffix if it's not an integer type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, IntSuffix, Parser},
+    ///     result,
+    /// };
+    ///
+    /// let mut parser = Parser::new("1_000u32 + 255_u8 - 0x7f");
+    ///
+    /// let lit = result::unwrap!(parser.parse_int_literal());
+    /// assert_eq!((lit.to_u128(), lit.suffix()), (Some(1000), Some(IntSuffix::U32)));
+    ///
+    /// _ = parser.strip_prefix(" + ");
+    /// let lit = result::unwrap!(parser.parse_int_literal());
+    /// assert_eq!((lit.to_u128(), lit.suffix()), (Some(255), Some(IntSuffix::U8)));
+    ///
+    /// _ = parser.strip_prefix(" - ");
+    /// let lit = result::unwrap!(parser.parse_int_literal());
+    /// assert_eq!((lit.to_u128(), lit.suffix()), (Some(127), None));
+    ///
+    /// // out of range for `u8`
+    /// let err = Parser::new("256u8").parse_int_literal().unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ParseInteger);
+    /// assert_eq!(err.offset(), 0);
+    ///
+    /// // not an integer suffix
+    /// let err = Parser::new("10f32").parse_int_literal().unwrap_err();
+    /// assert_eq!(err.offset(), 2);
+    ///
+    /// ```
+    pub const fn parse_int_literal(&mut self) -> Result<IntLiteral, ParseError<'a>> {
+        let mut p = self.copy();
+        p.parse_direction = ParseDirection::FromStart;
+
+        let bytes = p.str.as_bytes();
+        let mut i = 0;
+
+        let negative = matches!(bytes, [b'-', ..]);
+        if negative {
+            i += 1;
+        }
+
+        let radix = match string::str_from(p.str, i).as_bytes() {
+            [b'0', b'x', ..] => 16,
+            [b'0', b'o', ..] => 8,
+            [b'0', b'b', ..] => 2,
+            _ => 10,
+        };
+        if radix != 10 {
+            i += 2;
+        }
+
+        let mut magnitude: u128 = 0;
+        let mut digit_count = 0usize;
+        while i < bytes.len() {
+            let byte = bytes[i];
+            let digit = match byte {
+                b'_' if radix != 10 || digit_count != 0 => {
+                    i += 1;
+                    continue;
+                }
+                b'0'..=b'9' => byte - b'0',
+                _ if radix == 16 => match hex_digit_value(byte) {
+                    Some(x) => x,
+                    None => break,
+                },
+                _ => break,
+            };
+
+            if digit as u32 >= radix {
+                return Err(p.to_error_at(i, ErrorKind::ParseInteger));
+            }
+
+            magnitude = match magnitude.checked_mul(radix as u128) {
+                Some(x) => match x.checked_add(digit as u128) {
+                    Some(x) => x,
+                    None => return Err(p.to_error(ErrorKind::ParseInteger)),
+                },
+                None => return Err(p.to_error(ErrorKind::ParseInteger)),
+            };
+            digit_count += 1;
+            i += 1;
+        }
+
+        if digit_count == 0 {
+            return Err(p.to_error(ErrorKind::ParseInteger));
+        }
+
+        let suffix_start = i;
+        while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            i += 1;
+        }
+
+        let suffix = if suffix_start == i {
+            None
+        } else {
+            let suffix_bytes = crate::slice::slice_range(bytes, suffix_start, i);
+            match IntSuffix::from_bytes(suffix_bytes) {
+                Some(x) => Some(x),
+                None => return Err(p.to_error_at(suffix_start, ErrorKind::ParseInteger)),
+            }
+        };
+
+        let (magnitude, negative) = match suffix {
+            Some(suffix) => {
+                let bits = suffix.bits();
+                let unsigned_max = u128::MAX >> (128 - bits);
+                let signed_max = unsigned_max >> 1;
+
+                if !suffix.is_signed() {
+                    if magnitude > unsigned_max || negative && magnitude != 0 {
+                        return Err(p.to_error(ErrorKind::ParseInteger));
+                    }
+                    (magnitude, false)
+                } else if negative {
+                    if magnitude > signed_max + 1 {
+                        return Err(p.to_error(ErrorKind::ParseInteger));
+                    }
+                    (magnitude, magnitude != 0)
+                } else if magnitude <= signed_max {
+                    (magnitude, false)
+                } else if radix != 10 && magnitude <= unsigned_max {
+                    // two's complement reinterpretation, eg: `0xFFi8` is `-1`
+                    (unsigned_max - magnitude + 1, true)
+                } else {
+                    return Err(p.to_error(ErrorKind::ParseInteger));
+                }
+            }
+            None => {
+                if negative && magnitude > i128::MIN.unsigned_abs() {
+                    return Err(p.to_error(ErrorKind::ParseInteger));
+                }
+                (magnitude, negative && magnitude != 0)
+            }
+        };
+
+        _ = p.skip(i);
+        *self = p;
+
+        Ok(IntLiteral {
+            magnitude,
+            negative,
+            radix,
+            suffix,
+        })
+    }
+}
@@ -134,6 +134,16 @@ impl<'a> Parser<'a> {
         ParseError::new(self, kind)
     }
 
+    // Constructs a ParseError for `offset` bytes after the start of the parser,
+    // `offset` must be on a char boundary.
+    pub(crate) const fn to_error_at(&self, offset: usize, kind: ErrorKind) -> ParseError<'a> {
+        let at = Parser::with_start_offset(
+            string::str_from(self.str, offset),
+            self.start_offset as usize + offset,
+        );
+        ParseError::new(&at, kind)
+    }
+
     /// Constructs a [`ParseError`] for this point in parsing,
     /// for an [`ErrorKind::Other`] with a custom error message.
     ///
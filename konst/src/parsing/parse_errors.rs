@@ -1,8 +1,12 @@
-use crate::Parser;
+use crate::{
+    Parser,
+    parsing::{OutOfRange, RangeInt},
+};
 
 use core::{
     fmt::{self, Display},
     marker::PhantomData,
+    ops::Bound,
 };
 
 /// Error returned by all parsing methods that return Result.
@@ -16,11 +20,29 @@ pub struct ParseError<'a> {
     end_offset: u32,
     direction: ParseDirection,
     kind: ErrorKind,
-    extra_message: &'static &'static str,
+    extra: ErrorExtra,
     // Just in case that it goes back to storing the parser
     _lifetime: PhantomData<&'a [u8]>,
 }
 
+// The parts that only some errors have, behind `&'static` references to keep
+// `ParseError` small, except for the out of range value, which is only known at runtime.
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum ErrorExtra {
+    Message {
+        extra_message: &'static &'static str,
+        suggestion: &'static &'static str,
+    },
+    Expected {
+        expected: &'static [&'static str],
+        suggestion: &'static &'static str,
+    },
+    OutOfRange {
+        value: RangeInt,
+        bounds: &'static (Option<RangeInt>, Bound<RangeInt>),
+    },
+}
+
 impl<'a> ParseError<'a> {
     /// Constructs a `ParseError`.
     #[inline(always)]
@@ -30,7 +52,10 @@ impl<'a> ParseError<'a> {
             end_offset: parser.start_offset + parser.str.len() as u32,
             direction: parser.parse_direction,
             kind,
-            extra_message: &"",
+            extra: ErrorExtra::Message {
+                extra_message: &"",
+                suggestion: &"",
+            },
             _lifetime: PhantomData,
         }
     }
@@ -43,11 +68,117 @@ impl<'a> ParseError<'a> {
             end_offset: parser.start_offset + parser.str.len() as u32,
             direction: parser.parse_direction,
             kind: ErrorKind::Other,
-            extra_message,
+            extra: ErrorExtra::Message {
+                extra_message,
+                suggestion: &"",
+            },
             _lifetime: PhantomData,
         }
     }
 
+    /// Constructs a `ParseError` for when none of the `expected` strings
+    /// could be parsed.
+    ///
+    /// The `expected` strings are printed as
+    /// `expected one of "foo", "bar", "baz"` after the rest of the error message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{ErrorKind, ParseError, Parser};
+    ///
+    /// let parser = Parser::new("purple");
+    /// let err = ParseError::expected_one_of(&parser, ErrorKind::Strip, &["red", "blue"]);
+    ///
+    /// assert_eq!(err.expected(), ["red", "blue"]);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"error from the start at the 0 byte offset while trying to strip a pattern: expected one of "red", "blue""#,
+    /// );
+    /// ```
+    pub const fn expected_one_of(
+        parser: &Parser<'a>,
+        kind: ErrorKind,
+        expected: &'static [&'static str],
+    ) -> Self {
+        Self {
+            extra: ErrorExtra::Expected {
+                expected,
+                suggestion: &"",
+            },
+            ..Self::new(parser, kind)
+        }
+    }
+
+    /// Sets the string that the error message suggests using instead of the parsed text,
+    /// eg: the keyword that's the closest to a misspelled one.
+    ///
+    /// The suggestion is printed as `; did you mean "color"?`
+    /// after the rest of the error message,
+    /// passing an empty string removes the suggestion.
+    ///
+    /// The suggestion is passed by reference, like the message of
+    /// [`other_error`](Self::other_error), to keep this type small.
+    ///
+    /// Out of range errors (from [`parse_type_in`]) are returned unchanged,
+    /// because they don't store a suggestion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::{
+    ///     parsing::{ErrorKind, ParseError, Parser},
+    ///     string,
+    /// };
+    ///
+    /// const KEYS: &[&str] = &["color", "size"];
+    ///
+    /// const fn parse_key<'p>(parser: &mut Parser<'p>) -> Result<&'static str, ParseError<'p>> {
+    ///     let key = parser.remainder();
+    ///     konst::for_range! {i in 0..KEYS.len() =>
+    ///         if string::eq_str(key, KEYS[i]) {
+    ///             return Ok(KEYS[i]);
+    ///         }
+    ///     }
+    ///
+    ///     let err = ParseError::expected_one_of(parser, ErrorKind::Other, KEYS);
+    ///     match string::closest_match(key, KEYS, 2) {
+    ///         Some(suggestion) => Err(err.with_suggestion(suggestion)),
+    ///         None => Err(err),
+    ///     }
+    /// }
+    ///
+    /// let err = parse_key(&mut Parser::new("colour")).unwrap_err();
+    ///
+    /// assert_eq!(err.suggestion(), Some("color"));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     concat!(
+    ///         r#"error from the start at the 0 byte offset other error: "#,
+    ///         r#"expected one of "color", "size"; did you mean "color"?"#,
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// [`parse_type_in`]: crate::parsing::parse_type_in
+    pub const fn with_suggestion(mut self, suggestion: &'static &'static str) -> Self {
+        match &mut self.extra {
+            ErrorExtra::Message { suggestion: x, .. }
+            | ErrorExtra::Expected { suggestion: x, .. } => *x = suggestion,
+            ErrorExtra::OutOfRange { .. } => {}
+        }
+        self
+    }
+
+    pub(crate) const fn with_out_of_range(
+        mut self,
+        value: RangeInt,
+        bounds: &'static (Option<RangeInt>, Bound<RangeInt>),
+    ) -> Self {
+        self.extra = ErrorExtra::OutOfRange { value, bounds };
+        self
+    }
+
     /// A const fn equivalent of a clone method.
     pub const fn copy(&self) -> Self {
         Self {
@@ -55,7 +186,7 @@ impl<'a> ParseError<'a> {
             end_offset: self.end_offset,
             direction: self.direction,
             kind: self.kind,
-            extra_message: self.extra_message,
+            extra: self.extra,
             _lifetime: PhantomData,
         }
     }
@@ -80,8 +211,78 @@ impl<'a> ParseError<'a> {
         self.kind
     }
 
+    /// The strings that were expected when the error happened,
+    /// empty if the error wasn't constructed with [`expected_one_of`](Self::expected_one_of).
+    pub const fn expected(&self) -> &'static [&'static str] {
+        match self.extra {
+            ErrorExtra::Expected { expected, .. } => expected,
+            _ => &[],
+        }
+    }
+
+    /// The string that the error message suggests using instead of the parsed text,
+    /// set with [`with_suggestion`](Self::with_suggestion).
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        let suggestion = self.suggestion_str();
+        if suggestion.is_empty() {
+            None
+        } else {
+            Some(suggestion)
+        }
+    }
+
+    /// The value and range of an [`ErrorKind::OutOfRange`] error
+    /// returned by [`parse_type_in`], `None` for other errors.
+    ///
+    /// [`parse_type_in`]: crate::parsing::parse_type_in
+    pub const fn out_of_range(&self) -> Option<OutOfRange> {
+        match self.extra {
+            ErrorExtra::OutOfRange { value, bounds } => {
+                let (start, end) = *bounds;
+                Some(OutOfRange::new(value, start, end))
+            }
+            _ => None,
+        }
+    }
+
     const fn extra_message(&self) -> &'static str {
-        self.extra_message
+        match self.extra {
+            ErrorExtra::Message { extra_message, .. } => extra_message,
+            _ => "",
+        }
+    }
+
+    const fn suggestion_str(&self) -> &'static str {
+        match self.extra {
+            ErrorExtra::Message { suggestion, .. } | ErrorExtra::Expected { suggestion, .. } => {
+                suggestion
+            }
+            ErrorExtra::OutOfRange { .. } => "",
+        }
+    }
+
+    const fn expected_prefix(&self) -> &'static str {
+        match self.expected() {
+            [] => "",
+            [_] => ": expected ",
+            _ => ": expected one of ",
+        }
+    }
+
+    const fn suggestion_prefix(&self) -> &'static str {
+        if self.suggestion_str().is_empty() {
+            ""
+        } else {
+            "; did you mean "
+        }
+    }
+
+    const fn suggestion_suffix(&self) -> &'static str {
+        if self.suggestion_str().is_empty() {
+            ""
+        } else {
+            "?"
+        }
     }
 
     const fn error_for_direction(&self) -> &'static str {
@@ -95,12 +296,19 @@ impl<'a> ParseError<'a> {
         match self.kind {
             ErrorKind::ParseInteger => " while parsing an integer",
             ErrorKind::ParseBool => " while parsing a bool",
+            ErrorKind::ParseHex => " while parsing a hexadecimal digit",
+            ErrorKind::ParseUuid => " while parsing a UUID",
+            ErrorKind::ParseDateTime => " while parsing a date or time",
+            ErrorKind::ParseUri => " while parsing a URI",
+            ErrorKind::ParseDecimal => " while parsing a decimal number",
+            ErrorKind::ParseByteString => " while parsing a byte string literal",
+            ErrorKind::OutOfRange => " while parsing a value in a range: ",
             ErrorKind::Find => " while trying to find and skip a pattern",
             ErrorKind::Strip => " while trying to strip a pattern",
             ErrorKind::SplitExhausted => ": called split on empty parser",
             ErrorKind::DelimiterNotFound => ": delimiter (for splitting) could not be found",
             ErrorKind::Other => {
-                if self.extra_message.is_empty() {
+                if self.extra_message().is_empty() {
                     " other error"
                 } else {
                     ": "
@@ -116,33 +324,66 @@ impl<'a> Display for ParseError<'a> {
         Display::fmt(&self.offset(), f)?;
         f.write_str(" byte offset")?;
         f.write_str(self.error_suffix())?;
+        if let Some(oor) = self.out_of_range() {
+            Display::fmt(&oor, f)?;
+        }
         f.write_str(self.extra_message())?;
-        Ok(())
+        f.write_str(self.expected_prefix())?;
+        for (i, expected) in self.expected().iter().enumerate() {
+            if i == MAX_PRINTED_EXPECTED {
+                f.write_str(", ...")?;
+                break;
+            }
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Debug::fmt(expected, f)?;
+        }
+        f.write_str(self.suggestion_prefix())?;
+        if let Some(suggestion) = self.suggestion() {
+            fmt::Debug::fmt(suggestion, f)?;
+        }
+        f.write_str(self.suggestion_suffix())
     }
 }
 
+// The maximum amount of expected strings that are printed,
+// the rest are printed as `...`
+const MAX_PRINTED_EXPECTED: usize = 16;
+
 const _: () = {
     use const_panic::{
         PanicFmt, PanicVal, flatten_panicvals,
         fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind},
     };
 
+    const DEBUG_PV_COUNT: usize = ComputePvCount {
+        field_amount: 8,
+        summed_pv_count: {
+            <u32>::PV_COUNT
+                + <u32>::PV_COUNT
+                + <ParseDirection>::PV_COUNT
+                + <ErrorKind>::PV_COUNT
+                + <&'static str>::PV_COUNT
+                + <&'static [&'static str]>::PV_COUNT
+                + <&'static str>::PV_COUNT
+                + <OutOfRangeFmt>::PV_COUNT
+        },
+        delimiter: cfmt::TypeDelim::Braced,
+    }
+    .call();
+
+    const DISPLAY_PV_COUNT: usize = 6 + OutOfRange::PV_COUNT + 2 * MAX_PRINTED_EXPECTED + 1 + 3;
+
     impl PanicFmt for ParseError<'_> {
         type This = Self;
         type Kind = const_panic::IsCustomType;
 
-        const PV_COUNT: usize = ComputePvCount {
-            field_amount: 5,
-            summed_pv_count: {
-                <u32>::PV_COUNT
-                    + <u32>::PV_COUNT
-                    + <ParseDirection>::PV_COUNT
-                    + <ErrorKind>::PV_COUNT
-                    + <&'static &'static str>::PV_COUNT
-            },
-            delimiter: cfmt::TypeDelim::Braced,
-        }
-        .call();
+        const PV_COUNT: usize = if DEBUG_PV_COUNT > DISPLAY_PV_COUNT {
+            DEBUG_PV_COUNT
+        } else {
+            DISPLAY_PV_COUNT
+        };
     }
 
     impl<'a> ParseError<'a> {
@@ -150,7 +391,7 @@ const _: () = {
         pub const fn to_panicvals(&self, fmtarg: FmtArg) -> [PanicVal<'a>; ParseError::PV_COUNT] {
             match fmtarg.fmt_kind {
                 FmtKind::Debug => {
-                    flatten_panicvals! {fmtarg;
+                    let fields: [PanicVal<'a>; DEBUG_PV_COUNT] = flatten_panicvals! {fmtarg;
                         "ParseError",
                         open: cfmt::OpenBrace,
                             // cfmt::COMMA_SEP must only be used between fields
@@ -158,19 +399,62 @@ const _: () = {
                             "end_offset: ", u32 => self.end_offset, cfmt::COMMA_SEP,
                             "direction: ", ParseDirection => self.direction, cfmt::COMMA_SEP,
                             "kind: ", ErrorKind => self.kind, cfmt::COMMA_SEP,
-                            "extra_message: ", &'static &'static str =>
-                                self.extra_message, cfmt::COMMA_TERM,
+                            "extra_message: ", &'static str =>
+                                self.extra_message(), cfmt::COMMA_SEP,
+                            "expected: ", &'static [&'static str] =>
+                                self.expected(), cfmt::COMMA_SEP,
+                            "suggestion: ", &'static str =>
+                                self.suggestion_str(), cfmt::COMMA_SEP,
+                            "out_of_range: ", OutOfRangeFmt =>
+                                OutOfRangeFmt(self.out_of_range()), cfmt::COMMA_TERM,
                         // the `close:` format override decrements the indentation.
                         close: cfmt::CloseBrace,
+                    };
+                    const_panic::utils::flatten_panicvals(&[&fields])
+                }
+                _ => {
+                    let mut expected = [PanicVal::EMPTY; 2 * MAX_PRINTED_EXPECTED + 1];
+                    let self_expected = self.expected();
+
+                    let mut i = 0;
+                    while i < self_expected.len() {
+                        if i == MAX_PRINTED_EXPECTED {
+                            expected[2 * i] = PanicVal::write_str(", ...");
+                            break;
+                        }
+                        if i != 0 {
+                            expected[2 * i] = PanicVal::write_str(", ");
+                        }
+                        expected[2 * i + 1] = PanicVal::from_str(self_expected[i], FmtArg::DEBUG);
+                        i += 1;
                     }
+
+                    const_panic::utils::flatten_panicvals(&[
+                        &[
+                            PanicVal::write_str(self.error_for_direction()),
+                            PanicVal::from_usize(self.offset(), FmtArg::DEBUG),
+                            PanicVal::write_str(" byte offset"),
+                            PanicVal::write_str(self.error_suffix()),
+                        ],
+                        &match self.out_of_range() {
+                            Some(oor) => oor.to_panicvals(fmtarg),
+                            None => [PanicVal::EMPTY; OutOfRange::PV_COUNT],
+                        },
+                        &[
+                            PanicVal::write_str(self.extra_message()),
+                            PanicVal::write_str(self.expected_prefix()),
+                        ],
+                        &expected,
+                        &[
+                            PanicVal::write_str(self.suggestion_prefix()),
+                            match self.suggestion() {
+                                Some(suggestion) => PanicVal::from_str(suggestion, FmtArg::DEBUG),
+                                None => PanicVal::EMPTY,
+                            },
+                            PanicVal::write_str(self.suggestion_suffix()),
+                        ],
+                    ])
                 }
-                _ => const_panic::utils::flatten_panicvals(&[&[
-                    PanicVal::write_str(self.error_for_direction()),
-                    PanicVal::from_usize(self.offset(), FmtArg::DEBUG),
-                    PanicVal::write_str(" byte offset"),
-                    PanicVal::write_str(self.error_suffix()),
-                    PanicVal::write_str(self.extra_message()),
-                ]]),
             }
         }
     }
@@ -182,7 +466,10 @@ const _: () = {
                 .field("end_offset", &self.end_offset)
                 .field("direction", &self.direction)
                 .field("kind", &self.kind)
-                .field("extra_message", &self.extra_message)
+                .field("extra_message", &self.extra_message())
+                .field("expected", &self.expected())
+                .field("suggestion", &self.suggestion_str())
+                .field("out_of_range", &self.out_of_range())
                 .finish()
         }
     }
@@ -190,9 +477,10 @@ const _: () = {
     ////////
 
     macro_rules! fieldless_enum_fmt {
-        ($self:expr, [$($variant:ident)*]) => (
+        ($self:expr, [$($variant:ident)*] $($other_arms:tt)*) => (
             match $self {
                 $(Self::$variant => PanicVal::write_str(stringify!($variant)),)*
+                $($other_arms)*
             }
         )
     }
@@ -234,6 +522,13 @@ const _: () = {
             fieldless_enum_fmt! {self, [
                 ParseInteger
                 ParseBool
+                ParseHex
+                ParseUuid
+                ParseDateTime
+                ParseUri
+                ParseDecimal
+                ParseByteString
+                OutOfRange
                 Find
                 Strip
                 SplitExhausted
@@ -250,6 +545,40 @@ const _: () = {
             [self.to_panicval(fmtarg)]
         }
     }
+
+    ////////
+
+    // formats an `Option<OutOfRange>`
+    struct OutOfRangeFmt(Option<OutOfRange>);
+
+    impl PanicFmt for OutOfRangeFmt {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 1,
+            summed_pv_count: OutOfRange::PV_COUNT,
+            delimiter: cfmt::TypeDelim::Tupled,
+        }
+        .call();
+    }
+
+    impl OutOfRangeFmt {
+        const fn to_panicvals<'a>(
+            &self,
+            fmtarg: FmtArg,
+        ) -> [PanicVal<'a>; OutOfRangeFmt::PV_COUNT] {
+            match self.0 {
+                Some(oor) => flatten_panicvals! {fmtarg, OutOfRangeFmt::PV_COUNT;
+                    "Some",
+                    open: cfmt::OpenParen,
+                        OutOfRange => oor, cfmt::COMMA_TERM,
+                    close: cfmt::CloseParen,
+                },
+                None => flatten_panicvals! {fmtarg, OutOfRangeFmt::PV_COUNT; "None"},
+            }
+        }
+    }
 };
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -275,6 +604,22 @@ pub enum ErrorKind {
     ParseInteger,
     /// Returned from `parse_bool`
     ParseBool,
+    /// Returned from `parse_hex_array*` methods
+    ParseHex,
+    /// Returned from `parse_uuid`
+    ParseUuid,
+    /// Returned when parsing the types in the [`datetime`](crate::parsing::datetime) module
+    ParseDateTime,
+    /// Returned when parsing the types in the [`uri`](crate::parsing::uri) module
+    ParseUri,
+    /// Returned from parsing [`Decimal`](crate::decimal::Decimal)
+    ParseDecimal,
+    /// Returned from `parse_byte_string`
+    ParseByteString,
+    /// Returned from the [`parse_type_in`](crate::parsing::parse_type_in) macro
+    /// when the parsed value is outside of the range,
+    /// [`ParseError::out_of_range`] returns the value and range.
+    OutOfRange,
     /// Returned from `*find*` methods
     Find,
     /// Returned from `strip_*` methods
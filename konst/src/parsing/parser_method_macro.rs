@@ -1,3 +1,5 @@
+use crate::parsing::{ErrorKind, ParseDirection, ParseError, Parser};
+
 /// Calls a `Parser` method with many alternative string literals.
 ///
 /// If any of the literals match, the parser is mutated accordingly,
@@ -28,7 +30,12 @@
 ///
 /// - A match-like syntax:
 ///   A comma separated sequence of `<patterns> => <expression>` branches,
-///   the last branch must be `_ => <expression>`.
+///   optionally ending with a `_ => <expression>` branch.
+///   If there is no `_` branch, and no pattern matches,
+///   the macro evaluates to an `Err(ParseError)` that
+///   [carries all the patterns](crate::parsing::ParseError::expected),
+///   so the other branches must evaluate to a `Result<_, ParseError<'_>>`.
+///   [example](#expected-example)
 ///
 /// - Just the patterns: `<patterns>`.
 ///
@@ -94,6 +101,39 @@
 ///
 /// ```
 ///
+/// <span id = "expected-example"></span>
+/// ### Error with the expected patterns
+///
+/// ```rust
+/// use konst::parsing::{ErrorKind, Parser, ParseError, parser_method};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Color {
+///     Red,
+///     Blue,
+///     Green,
+/// }
+///
+/// const fn parse_color<'p>(parser: &mut Parser<'p>) -> Result<Color, ParseError<'p>> {
+///     parser_method!{parser, strip_prefix;
+///         "red" => Ok(Color::Red),
+///         "blue" => Ok(Color::Blue),
+///         "green" => Ok(Color::Green),
+///     }
+/// }
+///
+/// assert_eq!(parse_color(&mut Parser::new("blue")), Ok(Color::Blue));
+///
+/// let err = parse_color(&mut Parser::new("purple")).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Strip);
+/// assert_eq!(err.expected(), ["red", "blue", "green"]);
+/// assert_eq!(
+///     err.to_string(),
+///     r#"error from the start at the 0 byte offset while trying to strip a pattern: expected one of "red", "blue", "green""#,
+/// );
+///
+/// ```
+///
 /// <span id = "find-example"></span>
 /// ### `find_skip`
 ///
@@ -198,42 +238,42 @@ pub use crate::__parser_method as parser_method;
 macro_rules! __parser_method {
     ($place:expr, find_skip; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromStart, __priv_pa_find_skip, outside_konst)
+            ($place, FromStart, __priv_pa_find_skip, outside_konst, Find)
             ()
             $($branches)*
         }
     };
     ($place:expr, rfind_skip; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromEnd, __priv_pa_rfind_skip, outside_konst)
+            ($place, FromEnd, __priv_pa_rfind_skip, outside_konst, Find)
             ()
             $($branches)*
         }
     };
     ($place:expr, strip_prefix; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromStart, __priv_pa_strip_prefix, outside_konst)
+            ($place, FromStart, __priv_pa_strip_prefix, outside_konst, Strip)
             ()
             $($branches)*
         }
     };
     ($place:expr, strip_suffix; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromEnd, __priv_pa_strip_suffix, outside_konst)
+            ($place, FromEnd, __priv_pa_strip_suffix, outside_konst, Strip)
             ()
             $($branches)*
         }
     };
     ($place:expr, trim_start_matches; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromStart, __priv_pa_trim_start_matches, outside_konst)
+            ($place, FromStart, __priv_pa_trim_start_matches, outside_konst, Strip)
             ()
             $($branches)*
         }
     };
     ($place:expr, trim_end_matches; $($branches:tt)* ) => {
         $crate::__priv_pa_normalize_branches!{
-            ($place, FromEnd, __priv_pa_trim_end_matches, outside_konst)
+            ($place, FromEnd, __priv_pa_trim_end_matches, outside_konst, Strip)
             ()
             $($branches)*
         }
@@ -256,7 +296,7 @@ macro_rules! __parser_method {
 macro_rules! __priv_pa_normalize_branches {
     // Parsing just pattens
     (
-        ($place:expr, $parse_direction:ident, $method_macro:ident, $call_place:tt)
+        ($place:expr, $parse_direction:ident, $method_macro:ident, $call_place:tt, $kind:ident)
         ()
 
         $($pattern:pat_param)|*
@@ -270,7 +310,7 @@ macro_rules! __priv_pa_normalize_branches {
 
     // Parsing match like syntax
     (
-        ($place:expr, $parse_direction:ident, $method_macro:ident, $call_place:tt)
+        ($place:expr, $parse_direction:ident, $method_macro:ident, $call_place:tt, $kind:ident)
         ( $($branches:tt)* )
 
         _ => $expr:expr
@@ -286,15 +326,34 @@ macro_rules! __priv_pa_normalize_branches {
             default => ($expr)
         }
     }};
+    // No `_ => <expression>` branch, defaults to an error with all the patterns
+    (
+        ($place:expr, $parse_direction:ident, $method_macro:ident, $call_place:tt, $kind:ident)
+        ( $( ($($pattern:pat_param)|*) => ($e:expr) )* )
+    ) => {
+        // the parser is used by the default branch as well as the method,
+        // so it's bound to avoid evaluating the `$place` expression again.
+        match $place.__borrow_mut() {
+            __parser => $crate::$method_macro!{
+                ((*__parser), $parse_direction, $call_place)
+
+                $( ($($pattern)|*) => ($e) )*
+                default => ($crate::__::Err($crate::parsing::__expected_error(
+                    __parser,
+                    $crate::parsing::ParseDirection::$parse_direction,
+                    $crate::parsing::ErrorKind::$kind,
+                    &$crate::__priv_bstr_list!($($($pattern)|*)|*),
+                )))
+            }
+        }
+    };
     (
         $fixed_params:tt
         ( $($prev_branch:tt)* )
 
-        $($pattern:pat_param)|* => $expr:expr,
-        $($rem:tt)*
+        $($pattern:pat_param)|* => $expr:expr
+        $(, $($rem:tt)*)?
     ) => {{
-        $crate::__priv_tokens_after_middle_branch!{$($rem)*}
-
         $crate::__priv_pa_normalize_branches!{
             $fixed_params
             (
@@ -302,7 +361,7 @@ macro_rules! __priv_pa_normalize_branches {
 
                 ($($pattern)|*) => ($expr)
             )
-            $($rem)*
+            $($($rem)*)?
         }
     }};
     (
@@ -312,8 +371,6 @@ macro_rules! __priv_pa_normalize_branches {
         $($pattern:pat_param)|* => $expr:block
         $($rem:tt)*
     ) => {{
-        $crate::__priv_tokens_after_middle_branch!{$($rem)*}
-
         $crate::__priv_pa_normalize_branches!{
             $fixed_params
             (
@@ -511,11 +568,16 @@ macro_rules! __priv_no_tokens_after_last_branch {
     };
 }
 
+//////////////////////////////////////////////////////////////////////////////////
+
 #[doc(hidden)]
-#[macro_export]
-macro_rules! __priv_tokens_after_middle_branch {
-    () => {
-        compile_error! {"expected more branches, ending with a `_ => <expression>` branch"}
-    };
-    ($($tokens:tt)+) => {};
+pub const fn __expected_error<'a>(
+    parser: &Parser<'a>,
+    direction: ParseDirection,
+    kind: ErrorKind,
+    expected: &'static [&'static str],
+) -> ParseError<'a> {
+    let mut parser = parser.copy();
+    parser.parse_direction = direction;
+    ParseError::expected_one_of(&parser, kind, expected)
 }
@@ -357,6 +357,9 @@ impl<'a> Parser<'a> {
     /// You also can use the [`parse_type`](crate::parsing::parse_type)
     /// macro to parse a `bool`, and other [`HasParser`](crate::parsing::HasParser) types.
     ///
+    /// To also accept other keywords (eg: `yes`/`no`, `on`/`off`),
+    /// you can use [`parse_bool_with`](Self::parse_bool_with).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -0,0 +1,535 @@
+use crate::{
+    parsing::{ErrorKind, ParseDirection, ParseError, Parser},
+    range::{OneSidedRange, one_sided_range::__OneSidedRangeWitness},
+};
+
+use core::{
+    fmt::{self, Display},
+    ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
+};
+
+use typewit::TypeEq;
+
+/// Parses an integer type that impls [`HasParser`] with the passed in [`Parser`],
+/// checking that it's in the passed range.
+///
+/// The range can be a [`Range`], a [`RangeInclusive`],
+/// or any [`OneSidedRange`] (eg: `10..`, `..10`, `..=10`).
+///
+/// This evaluates to a `Result<$type, ParseError<'_>>`.
+///
+/// This mutates the parser in place on success, leaving it unmodified on error.
+///
+/// The range must be a constant expression,
+/// so that out of range errors can refer to it without storing it inline.
+///
+/// # Errors
+///
+/// This returns the error from parsing the type,
+/// or an [`ErrorKind::OutOfRange`] error at the start of the value
+/// if it's outside of the range,
+/// whose value and range are returned by [`ParseError::out_of_range`].
+///
+/// # Example
+///
+/// ```rust
+/// use konst::{
+///     parsing::{ErrorKind, Parser, RangeInt, parse_type_in},
+///     result,
+/// };
+///
+/// use core::ops::Bound;
+///
+/// const PORT: u16 = result::unwrap!(parse_type_in!(Parser::new("8080"), u16, 1024..=49151));
+/// assert_eq!(PORT, 8080);
+///
+/// let mut parser = Parser::new("80,");
+/// let err = parse_type_in!(parser, u16, 1024..=49151).unwrap_err();
+///
+/// assert_eq!(parser.remainder(), "80,");
+/// assert_eq!(err.offset(), 0);
+///
+/// assert_eq!(err.kind(), ErrorKind::OutOfRange);
+///
+/// let oor = err.out_of_range().unwrap();
+/// assert_eq!(oor.value(), RangeInt::from_u128(80));
+/// assert_eq!(oor.start(), Some(RangeInt::from_u128(1024)));
+/// assert_eq!(oor.end(), Bound::Included(RangeInt::from_u128(49151)));
+///
+/// assert_eq!(
+///     err.to_string(),
+///     "error from the start at the 0 byte offset \
+///      while parsing a value in a range: 80 is outside of 1024..=49151",
+/// );
+///
+/// ```
+///
+/// [`HasParser`]: crate::parsing::HasParser
+/// [`OneSidedRange`]: crate::range::OneSidedRange
+/// [`ErrorKind::OutOfRange`]: crate::parsing::ErrorKind::OutOfRange
+/// [`ParseError::out_of_range`]: crate::parsing::ParseError::out_of_range
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "parsing")))]
+pub use crate::__parse_type_in as parse_type_in;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_type_in {
+    ($parser:expr, $type:ty, $range:expr $(,)*) => {
+        match $parser.__borrow_mut() {
+            parser @ $crate::Parser { .. } => {
+                let before = parser.copy();
+                let res: $crate::__::Result<$type, $crate::parsing::ParseError<'_>> =
+                    <<$type as $crate::parsing::HasParser>::Parser>::parse_with(parser);
+
+                match res {
+                    $crate::__::Ok(value) => {
+                        let (start, end) =
+                            const { $crate::parsing::__range_bounds::<_, $type>($range) };
+
+                        let in_range = match start {
+                            $crate::__::Some(start) => value >= start,
+                            $crate::__::None => true,
+                        } && match end {
+                            $crate::__::Bound::Included(end) => value <= end,
+                            $crate::__::Bound::Excluded(end) => value < end,
+                            $crate::__::Bound::Unbounded => true,
+                        };
+
+                        if in_range {
+                            $crate::__::Ok(value)
+                        } else {
+                            // the bounds are promoted to a static,
+                            // so that errors don't store them inline.
+                            let bounds: &'static (
+                                $crate::__::Option<$crate::parsing::RangeInt>,
+                                $crate::__::Bound<$crate::parsing::RangeInt>,
+                            ) = const {
+                                let (start, end) =
+                                    $crate::parsing::__range_bounds::<_, $type>($range);
+
+                                &(
+                                    match start {
+                                        $crate::__::Some(x) => {
+                                            $crate::__::Some($crate::__to_range_int!(x))
+                                        }
+                                        $crate::__::None => $crate::__::None,
+                                    },
+                                    match end {
+                                        $crate::__::Bound::Included(x) => {
+                                            $crate::__::Bound::Included($crate::__to_range_int!(x))
+                                        }
+                                        $crate::__::Bound::Excluded(x) => {
+                                            $crate::__::Bound::Excluded($crate::__to_range_int!(x))
+                                        }
+                                        $crate::__::Bound::Unbounded => {
+                                            $crate::__::Bound::Unbounded
+                                        }
+                                    },
+                                )
+                            };
+
+                            let err = $crate::parsing::__out_of_range_error(
+                                &before,
+                                $crate::__to_range_int!(value),
+                                bounds,
+                            );
+                            *parser = before;
+                            $crate::__::Err(err)
+                        }
+                    }
+                    $crate::__::Err(e) => $crate::__::Err(e),
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_range_int {
+    ($value:expr) => {{
+        let value = $value;
+
+        #[allow(unused_comparisons)]
+        let is_negative = value < 0;
+
+        if is_negative {
+            $crate::parsing::RangeInt::from_i128(value as _)
+        } else {
+            $crate::parsing::RangeInt::from_u128(value as _)
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __out_of_range_error<'a>(
+    parser: &Parser<'a>,
+    value: RangeInt,
+    bounds: &'static (Option<RangeInt>, Bound<RangeInt>),
+) -> ParseError<'a> {
+    let mut parser = parser.copy();
+    parser.parse_direction = ParseDirection::FromStart;
+    parser
+        .to_error(ErrorKind::OutOfRange)
+        .with_out_of_range(value, bounds)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An integer of any primitive integer type,
+/// used by [`OutOfRange`] to store the value and bounds.
+///
+/// The [`Debug`] and [`Display`] impls print just the integer.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::RangeInt;
+///
+/// assert_eq!(RangeInt::from_i128(-3).to_i128(), Some(-3));
+/// assert_eq!(RangeInt::from_i128(-3).to_u128(), None);
+///
+/// assert_eq!(RangeInt::from_u128(u128::MAX).to_i128(), None);
+/// assert_eq!(RangeInt::from_u128(u128::MAX).to_u128(), Some(u128::MAX));
+///
+/// assert_eq!(RangeInt::from_i128(5), RangeInt::from_u128(5));
+///
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RangeInt {
+    negative: bool,
+    // stored as bytes so that `ParseError` (which can contain three of these) stays small
+    magnitude: [u8; 16],
+}
+
+impl RangeInt {
+    /// Constructs a `RangeInt` from an `i128`.
+    pub const fn from_i128(n: i128) -> Self {
+        Self {
+            negative: n < 0,
+            magnitude: n.unsigned_abs().to_ne_bytes(),
+        }
+    }
+
+    /// Constructs a `RangeInt` from a `u128`.
+    pub const fn from_u128(n: u128) -> Self {
+        Self {
+            negative: false,
+            magnitude: n.to_ne_bytes(),
+        }
+    }
+
+    /// Whether the integer is negative.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The integer as an `i128`, `None` if it doesn't fit.
+    pub const fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude();
+        if self.negative {
+            Some((magnitude as i128).wrapping_neg())
+        } else if magnitude <= i128::MAX as u128 {
+            Some(magnitude as i128)
+        } else {
+            None
+        }
+    }
+
+    /// The integer as a `u128`, `None` if it's negative.
+    pub const fn to_u128(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude())
+        }
+    }
+
+    /// The absolute value of the integer.
+    pub const fn magnitude(&self) -> u128 {
+        u128::from_ne_bytes(self.magnitude)
+    }
+}
+
+impl fmt::Debug for RangeInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for RangeInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_i128() {
+            Some(n) => Display::fmt(&n, f),
+            None => Display::fmt(&self.magnitude(), f),
+        }
+    }
+}
+
+/// The value and range of an [`ErrorKind::OutOfRange`] error,
+/// returned by [`ParseError::out_of_range`].
+///
+/// The [`Display`] impl prints it like `80 is outside of 1024..=49151`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OutOfRange {
+    value: RangeInt,
+    start: Option<RangeInt>,
+    end: Bound<RangeInt>,
+}
+
+impl OutOfRange {
+    pub(crate) const fn new(
+        value: RangeInt,
+        start: Option<RangeInt>,
+        end: Bound<RangeInt>,
+    ) -> Self {
+        Self { value, start, end }
+    }
+
+    /// The parsed value that's outside of the range.
+    pub const fn value(&self) -> RangeInt {
+        self.value
+    }
+
+    /// The inclusive start of the range, `None` if it's unbounded.
+    pub const fn start(&self) -> Option<RangeInt> {
+        self.start
+    }
+
+    /// The end of the range.
+    pub const fn end(&self) -> Bound<RangeInt> {
+        self.end
+    }
+
+    const fn end_prefix(&self) -> &'static str {
+        match self.end {
+            Bound::Included(_) => "..=",
+            Bound::Excluded(_) | Bound::Unbounded => "..",
+        }
+    }
+}
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.value, f)?;
+        f.write_str(" is outside of ")?;
+        if let Some(start) = self.start {
+            Display::fmt(&start, f)?;
+        }
+        f.write_str(self.end_prefix())?;
+        match self.end {
+            Bound::Included(end) | Bound::Excluded(end) => Display::fmt(&end, f),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+const _: () = {
+    use const_panic::{
+        PanicFmt, PanicVal, flatten_panicvals,
+        fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind},
+    };
+
+    impl PanicFmt for RangeInt {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = 1;
+    }
+
+    impl RangeInt {
+        /// Formats a RangeInt
+        pub const fn to_panicval<'a>(&self, fmtarg: FmtArg) -> PanicVal<'a> {
+            match self.to_i128() {
+                Some(n) => PanicVal::from_i128(n, fmtarg),
+                None => PanicVal::from_u128(self.magnitude(), fmtarg),
+            }
+        }
+
+        /// Formats a RangeInt
+        pub const fn to_panicvals<'a>(&self, fmtarg: FmtArg) -> [PanicVal<'a>; RangeInt::PV_COUNT] {
+            [self.to_panicval(fmtarg)]
+        }
+    }
+
+    // formats an `Option<RangeInt>` or `Bound<RangeInt>` variant
+    struct BoundFmt {
+        variant: &'static str,
+        value: Option<RangeInt>,
+    }
+
+    impl PanicFmt for BoundFmt {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 1,
+            summed_pv_count: RangeInt::PV_COUNT,
+            delimiter: cfmt::TypeDelim::Tupled,
+        }
+        .call();
+    }
+
+    impl BoundFmt {
+        const fn to_panicvals<'a>(&self, fmtarg: FmtArg) -> [PanicVal<'a>; BoundFmt::PV_COUNT] {
+            match self.value {
+                Some(x) => flatten_panicvals! {fmtarg, BoundFmt::PV_COUNT;
+                    display: self.variant,
+                    open: cfmt::OpenParen,
+                        RangeInt => x, cfmt::COMMA_TERM,
+                    close: cfmt::CloseParen,
+                },
+                None => flatten_panicvals! {fmtarg, BoundFmt::PV_COUNT;
+                    display: self.variant
+                },
+            }
+        }
+    }
+
+    const DEBUG_PV_COUNT: usize = ComputePvCount {
+        field_amount: 3,
+        summed_pv_count: RangeInt::PV_COUNT + 2 * BoundFmt::PV_COUNT,
+        delimiter: cfmt::TypeDelim::Braced,
+    }
+    .call();
+
+    const DISPLAY_PV_COUNT: usize = 5;
+
+    impl PanicFmt for OutOfRange {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = if DEBUG_PV_COUNT > DISPLAY_PV_COUNT {
+            DEBUG_PV_COUNT
+        } else {
+            DISPLAY_PV_COUNT
+        };
+    }
+
+    impl OutOfRange {
+        /// Formats an OutOfRange
+        pub const fn to_panicvals<'a>(
+            &self,
+            fmtarg: FmtArg,
+        ) -> [PanicVal<'a>; OutOfRange::PV_COUNT] {
+            match fmtarg.fmt_kind {
+                FmtKind::Debug => {
+                    let start = match self.start {
+                        Some(_) => BoundFmt {
+                            variant: "Some",
+                            value: self.start,
+                        },
+                        None => BoundFmt {
+                            variant: "None",
+                            value: None,
+                        },
+                    };
+                    let end = match self.end {
+                        Bound::Included(x) => BoundFmt {
+                            variant: "Included",
+                            value: Some(x),
+                        },
+                        Bound::Excluded(x) => BoundFmt {
+                            variant: "Excluded",
+                            value: Some(x),
+                        },
+                        Bound::Unbounded => BoundFmt {
+                            variant: "Unbounded",
+                            value: None,
+                        },
+                    };
+
+                    let fields: [PanicVal<'a>; DEBUG_PV_COUNT] = flatten_panicvals! {fmtarg;
+                        "OutOfRange",
+                        open: cfmt::OpenBrace,
+                            "value: ", RangeInt => self.value, cfmt::COMMA_SEP,
+                            "start: ", BoundFmt => start, cfmt::COMMA_SEP,
+                            "end: ", BoundFmt => end, cfmt::COMMA_TERM,
+                        close: cfmt::CloseBrace,
+                    };
+                    const_panic::utils::flatten_panicvals(&[&fields])
+                }
+                _ => const_panic::utils::flatten_panicvals(&[&[
+                    self.value.to_panicval(fmtarg),
+                    PanicVal::write_str(" is outside of "),
+                    match self.start {
+                        Some(start) => start.to_panicval(fmtarg),
+                        None => PanicVal::EMPTY,
+                    },
+                    PanicVal::write_str(self.end_prefix()),
+                    match self.end {
+                        Bound::Included(end) | Bound::Excluded(end) => end.to_panicval(fmtarg),
+                        Bound::Unbounded => PanicVal::EMPTY,
+                    },
+                ]]),
+            }
+        }
+    }
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Trait for the ranges that the [`parse_type_in`] macro takes.
+#[doc(hidden)]
+pub trait __ParseRange: Sized {
+    type Item;
+
+    const __PARSE_RANGE_WITNESS: __ParseRangeWitness<Self>;
+}
+
+impl<T> __ParseRange for Range<T> {
+    type Item = T;
+
+    const __PARSE_RANGE_WITNESS: __ParseRangeWitness<Self> =
+        __ParseRangeWitness::Range(TypeEq::NEW);
+}
+
+impl<T> __ParseRange for RangeInclusive<T> {
+    type Item = T;
+
+    const __PARSE_RANGE_WITNESS: __ParseRangeWitness<Self> =
+        __ParseRangeWitness::RangeInclusive(TypeEq::NEW);
+}
+
+impl<R: OneSidedRange> __ParseRange for R {
+    type Item = R::Item;
+
+    const __PARSE_RANGE_WITNESS: __ParseRangeWitness<Self> = match R::__ONE_SIDED_RANGE_WITNESS {
+        __OneSidedRangeWitness::RangeFrom(te) => __ParseRangeWitness::RangeFrom(te),
+        __OneSidedRangeWitness::RangeTo(te) => __ParseRangeWitness::RangeTo(te),
+        __OneSidedRangeWitness::RangeToInclusive(te) => __ParseRangeWitness::RangeToInclusive(te),
+    };
+}
+
+#[doc(hidden)]
+pub enum __ParseRangeWitness<This: __ParseRange> {
+    Range(TypeEq<This, Range<This::Item>>),
+    RangeInclusive(TypeEq<This, RangeInclusive<This::Item>>),
+    RangeFrom(TypeEq<This, RangeFrom<This::Item>>),
+    RangeTo(TypeEq<This, RangeTo<This::Item>>),
+    RangeToInclusive(TypeEq<This, RangeToInclusive<This::Item>>),
+}
+
+// Returns the inclusive start and the end of the range.
+#[doc(hidden)]
+pub const fn __range_bounds<R, T>(range: R) -> (Option<T>, Bound<T>)
+where
+    R: __ParseRange<Item = T>,
+    T: Copy,
+{
+    match R::__PARSE_RANGE_WITNESS {
+        __ParseRangeWitness::Range(te) => {
+            let Range { start, end } = te.to_right(range);
+            (Some(start), Bound::Excluded(end))
+        }
+        __ParseRangeWitness::RangeInclusive(te) => {
+            let range = te.to_right(range);
+            (Some(*range.start()), Bound::Included(*range.end()))
+        }
+        __ParseRangeWitness::RangeFrom(te) => (Some(te.to_right(range).start), Bound::Unbounded),
+        __ParseRangeWitness::RangeTo(te) => (None, Bound::Excluded(te.to_right(range).end)),
+        __ParseRangeWitness::RangeToInclusive(te) => {
+            (None, Bound::Included(te.to_right(range).end))
+        }
+    }
+}
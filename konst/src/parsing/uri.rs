@@ -0,0 +1,547 @@
+//! Splitting [RFC 3986] URIs into their components, in const.
+//!
+//! The [`Uri`] type implements [`HasParser`],
+//! so it can be parsed with the [`parse_type`] macro,
+//! and [`parse_uri`] parses a `Uri` from an entire string.
+//!
+//! All parsing errors have the [`ErrorKind::ParseUri`] kind,
+//! with the offset of the byte that failed to parse.
+//!
+//! # Example
+//!
+//! ```rust
+//! use konst::parsing::uri::{self, Uri};
+//! use konst::result;
+//!
+//! const SERVICE: Uri<'_> = result::unwrap!(uri::parse_uri(
+//!     "https://user@[::1]:8443/api/v1?verbose=true#top"
+//! ));
+//!
+//! assert_eq!(SERVICE.scheme(), "https");
+//! assert_eq!(SERVICE.userinfo(), Some("user"));
+//! assert_eq!(SERVICE.host(), Some("[::1]"));
+//! assert_eq!(SERVICE.port(), Some(8443));
+//! assert_eq!(SERVICE.path(), "/api/v1");
+//! assert_eq!(SERVICE.query(), Some("verbose=true"));
+//! assert_eq!(SERVICE.fragment(), Some("top"));
+//!
+//! ```
+//!
+//! [RFC 3986]: https://datatracker.ietf.org/doc/html/rfc3986
+//! [`HasParser`]: crate::parsing::HasParser
+//! [`parse_type`]: crate::parsing::parse_type
+//! [`ErrorKind::ParseUri`]: crate::parsing::ErrorKind::ParseUri
+
+use crate::{
+    parsing::{ErrorKind, HasParser, ParseDirection, ParseError, Parser},
+    string::{self, ArrayStr},
+    utils::wrong_array_length_panic,
+};
+
+use super::hex_parsing::hex_digit_value;
+
+/// A URI split into its components, all of which borrow from the parsed string.
+///
+/// Components are returned exactly as written, without percent-decoding them,
+/// you can use [`percent_decode`](fn@percent_decode) to decode them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Uri<'a> {
+    scheme: &'a str,
+    authority: Option<&'a str>,
+    userinfo: Option<&'a str>,
+    host: Option<&'a str>,
+    port: Option<u16>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Uri<'a> {
+    /// The scheme, before the first `:`.
+    pub const fn scheme(&self) -> &'a str {
+        self.scheme
+    }
+
+    /// The authority, between the `//` after the scheme and the path.
+    ///
+    /// This is `None` when the URI has no `//` after the scheme.
+    pub const fn authority(&self) -> Option<&'a str> {
+        self.authority
+    }
+
+    /// The user information, before the `@` in the authority.
+    pub const fn userinfo(&self) -> Option<&'a str> {
+        self.userinfo
+    }
+
+    /// The host in the authority.
+    ///
+    /// IP literals (eg: IPv6 addresses) include the enclosing brackets,
+    /// as in `"[::1]"`.
+    ///
+    /// This is `Some` whenever [`authority`](Self::authority) is.
+    pub const fn host(&self) -> Option<&'a str> {
+        self.host
+    }
+
+    /// The port, after the `:` that follows the host.
+    ///
+    /// This is `None` both when there's no port and when the port is empty.
+    pub const fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The path, which can be empty.
+    pub const fn path(&self) -> &'a str {
+        self.path
+    }
+
+    /// The query, after the `?`.
+    pub const fn query(&self) -> Option<&'a str> {
+        self.query
+    }
+
+    /// The fragment, after the `#`.
+    pub const fn fragment(&self) -> Option<&'a str> {
+        self.fragment
+    }
+}
+
+impl Uri<'_> {
+    /// Parses a [`Uri`], up to the first byte that can't appear in URIs
+    /// (eg: whitespace, `"`, `<`, non-ASCII characters).
+    ///
+    /// This function mutates the parser in place on success, leaving it unmodified on error.
+    ///
+    /// # Errors
+    ///
+    /// This returns an [`ErrorKind::ParseUri`] error if:
+    /// - the scheme is missing or invalid.
+    /// - a component contains a byte that isn't allowed in that component.
+    /// - a `%` isn't followed by two hexadecimal digits.
+    /// - the port doesn't fit in a `u16`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::parsing::{ErrorKind, Parser, parse_type, uri::Uri};
+    ///
+    /// let mut parser = Parser::new("mailto:joe@example.com, other text");
+    /// let uri = parse_type!(parser, Uri).unwrap();
+    /// assert_eq!(uri.scheme(), "mailto");
+    /// assert_eq!(uri.host(), None);
+    /// assert_eq!(uri.path(), "joe@example.com,");
+    /// assert_eq!(parser.remainder(), " other text");
+    ///
+    /// let err = parse_type!(Parser::new("http://host:99999/"), Uri).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ParseUri);
+    /// assert_eq!(err.offset(), 12);
+    ///
+    /// ```
+    ///
+    /// [`ErrorKind::ParseUri`]: crate::parsing::ErrorKind::ParseUri
+    pub const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Uri<'p>, ParseError<'p>> {
+        try_parsing! {parser, FromStart, ret;
+            let str = parser.str;
+            let bytes = str.as_bytes();
+
+            let mut len = 0;
+            while len < bytes.len() && is_uri_byte(bytes[len]) {
+                len += 1;
+            }
+
+            let mut i = 0;
+            while i < len && is_scheme_byte(bytes[i], i == 0) {
+                i += 1;
+            }
+            if i == 0 || i == len || bytes[i] != b':' {
+                return Err(parser.to_error_at(i, ErrorKind::ParseUri));
+            }
+            let scheme = string::str_up_to(str, i);
+            i += 1;
+
+            let mut authority = None;
+            let mut userinfo = None;
+            let mut host = None;
+            let mut port = None;
+
+            if let [b'/', b'/', ..] = string::str_range(str, i, len).as_bytes() {
+                i += 2;
+                let auth_start = i;
+                while i < len && !matches!(bytes[i], b'/' | b'?' | b'#') {
+                    i += 1;
+                }
+                let auth_end = i;
+                authority = Some(string::str_range(str, auth_start, auth_end));
+
+                let mut host_start = auth_start;
+                let mut j = auth_end;
+                while j > auth_start {
+                    j -= 1;
+                    if bytes[j] == b'@' {
+                        crate::try_!(validate(parser, auth_start, j, Component::UserInfo));
+                        userinfo = Some(string::str_range(str, auth_start, j));
+                        host_start = j + 1;
+                        break;
+                    }
+                }
+
+                let mut k = host_start;
+                if k < auth_end && bytes[k] == b'[' {
+                    k += 1;
+                    while k < auth_end && bytes[k] != b']' {
+                        if !matches!(bytes[k], b'0'..=b'9' | b'a'..=b'f' | b'A'..=b'F' | b':' | b'.') {
+                            return Err(parser.to_error_at(k, ErrorKind::ParseUri));
+                        }
+                        k += 1;
+                    }
+                    if k == auth_end {
+                        return Err(parser.to_error_at(k, ErrorKind::ParseUri));
+                    }
+                    k += 1;
+                } else {
+                    while k < auth_end && bytes[k] != b':' {
+                        k += 1;
+                    }
+                    crate::try_!(validate(parser, host_start, k, Component::RegName));
+                }
+                host = Some(string::str_range(str, host_start, k));
+
+                if k < auth_end {
+                    if bytes[k] != b':' {
+                        return Err(parser.to_error_at(k, ErrorKind::ParseUri));
+                    }
+                    k += 1;
+
+                    if k < auth_end {
+                        let port_start = k;
+                        let mut num = 0u32;
+                        while k < auth_end {
+                            match bytes[k] {
+                                b @ b'0'..=b'9' => num = num * 10 + (b - b'0') as u32,
+                                _ => return Err(parser.to_error_at(k, ErrorKind::ParseUri)),
+                            }
+                            if num > u16::MAX as u32 {
+                                return Err(parser.to_error_at(port_start, ErrorKind::ParseUri));
+                            }
+                            k += 1;
+                        }
+                        port = Some(num as u16);
+                    }
+                }
+            }
+
+            let path_start = i;
+            while i < len && !matches!(bytes[i], b'?' | b'#') {
+                i += 1;
+            }
+            crate::try_!(validate(parser, path_start, i, Component::Path));
+            let path = string::str_range(str, path_start, i);
+
+            let mut query = None;
+            if i < len && bytes[i] == b'?' {
+                i += 1;
+                let query_start = i;
+                while i < len && bytes[i] != b'#' {
+                    i += 1;
+                }
+                crate::try_!(validate(parser, query_start, i, Component::QueryOrFragment));
+                query = Some(string::str_range(str, query_start, i));
+            }
+
+            let mut fragment = None;
+            if i < len && bytes[i] == b'#' {
+                i += 1;
+                crate::try_!(validate(parser, i, len, Component::QueryOrFragment));
+                fragment = Some(string::str_range(str, i, len));
+            }
+
+            parser.str = string::str_from(str, len);
+
+            Uri {
+                scheme,
+                authority,
+                userinfo,
+                host,
+                port,
+                path,
+                query,
+                fragment,
+            }
+        }
+    }
+}
+
+impl HasParser for Uri<'_> {
+    type Parser = Self;
+}
+
+/// Parses a [`Uri`] that takes up the entire `string`.
+///
+/// This is like [`Uri::parse_with`],
+/// except that it also errors if there's anything after the URI.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::uri::{Uri, parse_uri};
+/// use konst::result;
+///
+/// const DB: Uri<'_> = result::unwrap!(parse_uri("postgres://localhost/inventory"));
+///
+/// assert_eq!(DB.host(), Some("localhost"));
+/// assert_eq!(DB.port(), None);
+/// assert_eq!(DB.path(), "/inventory");
+///
+/// let err = parse_uri("https://example.com/a b").unwrap_err();
+/// assert_eq!(err.offset(), 21);
+///
+/// ```
+pub const fn parse_uri(string: &str) -> Result<Uri<'_>, ParseError<'_>> {
+    let mut parser = Parser::new(string);
+    let uri = crate::try_!(Uri::parse_with(&mut parser));
+
+    if parser.is_empty() {
+        Ok(uri)
+    } else {
+        Err(parser.to_error(ErrorKind::ParseUri))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the length of `encoded` after [percent-decoding](fn@percent_decode) it.
+///
+/// This assumes that every `%` in `encoded` is followed by two hexadecimal digits,
+/// [`percent_decode`](fn@percent_decode) returns an error if that isn't the case.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::uri::percent_decoded_len;
+///
+/// assert_eq!(percent_decoded_len("hello"), 5);
+/// assert_eq!(percent_decoded_len("hello%20world"), 11);
+/// assert_eq!(percent_decoded_len("%E2%9C%93"), 3);
+///
+/// ```
+pub const fn percent_decoded_len(encoded: &str) -> usize {
+    let bytes = encoded.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        i += if bytes[i] == b'%' { 3 } else { 1 };
+        len += 1;
+    }
+
+    len
+}
+
+/// Decodes the `%XX` escapes in `encoded`.
+///
+/// To get a `&'static str` without having to compute the length explicitly,
+/// you can use the [`percent_decode`](macro@percent_decode) macro.
+///
+/// # Errors
+///
+/// This returns an [`ErrorKind::ParseUri`] error if a `%` isn't followed by
+/// two hexadecimal digits, or if the decoded bytes aren't valid UTF-8.
+/// The error's offset is that of the offending `%` in `encoded`.
+///
+/// # Panics
+///
+/// Panics if `N` isn't [`percent_decoded_len(encoded)`](percent_decoded_len).
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::uri::{self, percent_decoded_len};
+/// use konst::string::ArrayStr;
+/// use konst::result;
+///
+/// const ENCODED: &str = "caf%C3%A9%20menu";
+/// const DECODED: ArrayStr<{percent_decoded_len(ENCODED)}> =
+///     result::unwrap!(uri::percent_decode(ENCODED));
+///
+/// assert_eq!(DECODED.as_str(), "café menu");
+///
+/// assert_eq!(uri::percent_decode::<3>("a%2xb").unwrap_err().offset(), 1);
+///
+/// ```
+///
+/// [`ErrorKind::ParseUri`]: crate::parsing::ErrorKind::ParseUri
+#[track_caller]
+pub const fn percent_decode<const N: usize>(encoded: &str) -> Result<ArrayStr<N>, ParseError<'_>> {
+    let parser = Parser::new(encoded);
+    let bytes = encoded.as_bytes();
+
+    crate::try_!(validate_percent_escapes(&parser, 0, bytes.len()));
+
+    let expected_len = percent_decoded_len(encoded);
+    if N != expected_len {
+        wrong_array_length_panic(N, expected_len)
+    }
+
+    let mut out = [0u8; N];
+    let mut out_i = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        out[out_i] = if bytes[i] == b'%' {
+            i += 3;
+            decode_escape(bytes[i - 2], bytes[i - 1])
+        } else {
+            i += 1;
+            bytes[i - 1]
+        };
+        out_i += 1;
+    }
+
+    if let Err(e) = core::str::from_utf8(&out) {
+        // finding the offset in `encoded` of the first invalid decoded byte
+        let mut decoded_i = 0;
+        let mut i = 0;
+        while decoded_i < e.valid_up_to() {
+            i += if bytes[i] == b'%' { 3 } else { 1 };
+            decoded_i += 1;
+        }
+        return Err(parser.to_error_at(i, ErrorKind::ParseUri));
+    }
+
+    Ok(ArrayStr(out))
+}
+
+/// Decodes the `%XX` escapes in a string constant into a `&'static str`.
+///
+/// This uses [`percent_decode`](fn@percent_decode) to decode the string.
+///
+/// # Panics
+///
+/// This causes a compile-time error if the string has invalid escapes,
+/// or decodes into invalid UTF-8.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::parsing::uri::{self, percent_decode};
+/// use konst::result;
+///
+/// const PATH: &str = result::unwrap!(uri::parse_uri("file:///My%20Documents/notes.txt")).path();
+///
+/// assert_eq!(percent_decode!(PATH), "/My Documents/notes.txt");
+///
+/// ```
+///
+/// ```compile_fail
+/// const S: &str = konst::parsing::uri::percent_decode!("%FF");
+/// ```
+#[doc(inline)]
+pub use crate::__parsing_percent_decode as percent_decode;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parsing_percent_decode {
+    ($encoded:expr $(,)?) => {{
+        const __ENCODED_81608BFNA5: &$crate::__::str = $encoded;
+        {
+            const LEN: $crate::__::usize =
+                $crate::parsing::uri::percent_decoded_len(__ENCODED_81608BFNA5);
+
+            const CONC: &$crate::string::ArrayStr<LEN> = &$crate::result::unwrap!(
+                $crate::parsing::uri::percent_decode(__ENCODED_81608BFNA5)
+            );
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum Component {
+    UserInfo,
+    RegName,
+    Path,
+    QueryOrFragment,
+}
+
+const fn is_unreserved(b: u8) -> bool {
+    matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
+}
+
+const fn is_sub_delim(b: u8) -> bool {
+    matches!(
+        b,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+const fn is_uri_byte(b: u8) -> bool {
+    is_unreserved(b)
+        || is_sub_delim(b)
+        || matches!(b, b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@' | b'%')
+}
+
+const fn is_scheme_byte(b: u8, is_first: bool) -> bool {
+    b.is_ascii_alphabetic() || !is_first && matches!(b, b'0'..=b'9' | b'+' | b'-' | b'.')
+}
+
+const fn is_allowed_in(b: u8, component: Component) -> bool {
+    is_unreserved(b)
+        || is_sub_delim(b)
+        || match component {
+            Component::UserInfo => b == b':',
+            Component::RegName => false,
+            Component::Path => matches!(b, b':' | b'@' | b'/'),
+            Component::QueryOrFragment => matches!(b, b':' | b'@' | b'/' | b'?'),
+        }
+}
+
+// Checks that the bytes in `start..end` of the parser's string
+// are allowed in `component`, including that escapes are valid.
+const fn validate<'p>(
+    parser: &Parser<'p>,
+    start: usize,
+    end: usize,
+    component: Component,
+) -> Result<(), ParseError<'p>> {
+    let bytes = parser.str.as_bytes();
+
+    crate::for_range! {i in start..end =>
+        if bytes[i] != b'%' && !is_allowed_in(bytes[i], component) {
+            return Err(parser.to_error_at(i, ErrorKind::ParseUri));
+        }
+    }
+
+    validate_percent_escapes(parser, start, end)
+}
+
+const fn validate_percent_escapes<'p>(
+    parser: &Parser<'p>,
+    start: usize,
+    end: usize,
+) -> Result<(), ParseError<'p>> {
+    let bytes = parser.str.as_bytes();
+
+    crate::for_range! {i in start..end =>
+        if bytes[i] == b'%' && (
+            i + 2 >= end
+            || hex_digit_value(bytes[i + 1]).is_none()
+            || hex_digit_value(bytes[i + 2]).is_none()
+        ) {
+            return Err(parser.to_error_at(i, ErrorKind::ParseUri));
+        }
+    }
+
+    Ok(())
+}
+
+const fn decode_escape(hi: u8, lo: u8) -> u8 {
+    match (hex_digit_value(hi), hex_digit_value(lo)) {
+        (Some(hi), Some(lo)) => (hi << 4) | lo,
+        _ => panic!("bug: escapes should be validated before decoding"),
+    }
+}
@@ -33,6 +33,10 @@ use core::fmt::{self, Display};
 pub mod cmp;
 
 mod bytes_pattern;
+mod bytes_search;
+
+#[cfg(feature = "iter")]
+mod bytes_matches_items;
 mod slice_concatenation;
 mod slice_const_methods;
 mod slice_filler;
@@ -46,6 +50,12 @@ mod slice_iter_methods;
 
 pub use bytes_pattern::BytesPattern;
 
+#[cfg(feature = "iter")]
+pub use self::bytes_matches_items::{
+    BytesMatchIndices, BytesMatches, BytesRMatchIndices, bytes_match_indices, bytes_matches,
+    bytes_rmatch_indices,
+};
+
 pub(crate) use bytes_pattern::PatternNorm;
 
 pub use self::slice_concatenation::*;
@@ -0,0 +1,346 @@
+use crate::{
+    iter::{ConstIntoIter, IsIteratorKind},
+    slice::{__bytes_find, __bytes_rfind, BytesPattern, PatternNorm},
+};
+
+/// Iterates over the non-overlapping matches of `pattern` in `slice`, from the start.
+///
+/// An empty `pattern` matches at every index from `0` to `slice.len()` inclusive.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::slice;
+/// use konst::iter::collect_const;
+///
+/// const FOUND: [&[u8]; 2] = collect_const!(&[u8] => slice::bytes_matches(b"aaaaa", b"aa"));
+///
+/// assert_eq!(FOUND, [b"aa", b"aa"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn bytes_matches<'a, 'p, const N: usize, P>(
+    slice: &'a [u8],
+    pattern: &'p P,
+) -> BytesMatches<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    BytesMatches {
+        inner: bytes_match_indices(slice, pattern),
+    }
+}
+
+/// Iterates over the non-overlapping matches of `pattern` in `slice`, from the start,
+/// along with the index of each match.
+///
+/// An empty `pattern` matches at every index from `0` to `slice.len()` inclusive.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::slice;
+/// use konst::iter::collect_const;
+///
+/// const FOUND: [(usize, &[u8]); 3] =
+///     collect_const!((usize, &[u8]) => slice::bytes_match_indices(b"foo\nbar\nbaz\n", &'\n'));
+///
+/// assert_eq!(FOUND, [(3, &b"\n"[..]), (7, b"\n"), (11, b"\n")]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn bytes_match_indices<'a, 'p, const N: usize, P>(
+    slice: &'a [u8],
+    pattern: &'p P,
+) -> BytesMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    BytesMatchIndices {
+        slice,
+        offset: 0,
+        pattern,
+        finished: false,
+    }
+}
+
+/// Iterates over the non-overlapping matches of `pattern` in `slice`, from the end,
+/// along with the index of each match.
+///
+/// An empty `pattern` matches at every index from `slice.len()` down to `0` inclusive.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::slice;
+/// use konst::iter::collect_const;
+///
+/// const FOUND: [(usize, &[u8]); 2] =
+///     collect_const!((usize, &[u8]) => slice::bytes_rmatch_indices(b"aaaaa", "aa"));
+///
+/// assert_eq!(FOUND, [(3, &b"aa"[..]), (1, b"aa")]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn bytes_rmatch_indices<'a, 'p, const N: usize, P>(
+    slice: &'a [u8],
+    pattern: &'p P,
+) -> BytesRMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    BytesRMatchIndices {
+        slice,
+        pattern,
+        finished: false,
+    }
+}
+
+/// Iterator over the matches of a pattern in a byte slice.
+///
+/// This is constructed with [`bytes_matches`] like this:
+/// ```rust
+/// # let slice: &[u8] = b"";
+/// # let pattern = "";
+/// # let _ =
+/// konst::slice::bytes_matches(slice, pattern)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct BytesMatches<'a, 'p, const N: usize, P: ?Sized + BytesPattern<N>> {
+    inner: BytesMatchIndices<'a, 'p, N, P>,
+}
+impl<'a, 'p, const N: usize, P> ConstIntoIter for BytesMatches<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a [u8];
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, const N: usize, P> BytesMatches<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    iterator_shared! {
+        is_forward = true,
+        item = &'a [u8],
+        iter_forward = BytesMatches<'a, 'p, N, P>,
+        next(self) {
+            match self.inner.next() {
+                Some((_, matched)) => Some(matched),
+                None => None,
+            }
+        },
+        fields = {inner.copy()},
+    }
+
+    /// Gets the remainder of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::slice::bytes_matches(b"foo-bar", b"-");
+    /// assert_eq!(iter.remainder(), b"foo-bar");
+    ///
+    /// assert_eq!(iter.next().unwrap(), b"-");
+    /// assert_eq!(iter.remainder(), b"bar");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), b"");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.inner.remainder()
+    }
+}
+
+/// Iterator over the matches of a pattern in a byte slice, along with their index.
+///
+/// This is constructed with [`bytes_match_indices`] like this:
+/// ```rust
+/// # let slice: &[u8] = b"";
+/// # let pattern = "";
+/// # let _ =
+/// konst::slice::bytes_match_indices(slice, pattern)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct BytesMatchIndices<'a, 'p, const N: usize, P: ?Sized + BytesPattern<N>> {
+    slice: &'a [u8],
+    // the offset of `slice` in the original slice
+    offset: usize,
+    pattern: &'p P,
+    finished: bool,
+}
+impl<'a, 'p, const N: usize, P> ConstIntoIter for BytesMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = (usize, &'a [u8]);
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, const N: usize, P> BytesMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    iterator_shared! {
+        is_forward = true,
+        item = (usize, &'a [u8]),
+        iter_forward = BytesMatchIndices<'a, 'p, N, P>,
+        next(self) {
+            let slice = self.slice;
+            if self.finished {
+                return None;
+            }
+
+            let pattern = PatternNorm::new(self.pattern);
+            let pattern = pattern.as_bytes();
+
+            let Some(start) = __bytes_find(slice, pattern) else {
+                self.slice = &[];
+                self.finished = true;
+                return None;
+            };
+            let end = start + pattern.len();
+
+            // empty patterns match at every index,
+            // so the next search starts after the next byte.
+            let next = if start != end {
+                end
+            } else if end == slice.len() {
+                self.finished = true;
+                end
+            } else {
+                end + 1
+            };
+
+            let ret = (self.offset + start, slice.split_at(end).0.split_at(start).1);
+            self.slice = slice.split_at(next).1;
+            self.offset += next;
+            Some(ret)
+        },
+        fields = {slice, offset, pattern, finished},
+    }
+
+    /// Gets the remainder of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::slice::bytes_match_indices(b"foo-bar", "-");
+    /// assert_eq!(iter.remainder(), b"foo-bar");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (3, &b"-"[..]));
+    /// assert_eq!(iter.remainder(), b"bar");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), b"");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.slice
+    }
+}
+
+/// Iterator over the matches of a pattern in a byte slice, along with their index,
+/// from the end.
+///
+/// This is constructed with [`bytes_rmatch_indices`] like this:
+/// ```rust
+/// # let slice: &[u8] = b"";
+/// # let pattern = "";
+/// # let _ =
+/// konst::slice::bytes_rmatch_indices(slice, pattern)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct BytesRMatchIndices<'a, 'p, const N: usize, P: ?Sized + BytesPattern<N>> {
+    // a prefix of the original slice, so match indices don't need adjusting
+    slice: &'a [u8],
+    pattern: &'p P,
+    finished: bool,
+}
+impl<'a, 'p, const N: usize, P> ConstIntoIter for BytesRMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = (usize, &'a [u8]);
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, const N: usize, P> BytesRMatchIndices<'a, 'p, N, P>
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    iterator_shared! {
+        is_forward = true,
+        item = (usize, &'a [u8]),
+        iter_forward = BytesRMatchIndices<'a, 'p, N, P>,
+        next(self) {
+            let slice = self.slice;
+            if self.finished {
+                return None;
+            }
+
+            let pattern = PatternNorm::new(self.pattern);
+            let pattern = pattern.as_bytes();
+
+            // `__bytes_rfind` doesn't return the end of the slice for empty patterns
+            let found = if pattern.is_empty() {
+                Some(slice.len())
+            } else {
+                __bytes_rfind(slice, pattern)
+            };
+
+            let Some(start) = found else {
+                self.slice = &[];
+                self.finished = true;
+                return None;
+            };
+            let end = start + pattern.len();
+
+            // empty patterns match at every index,
+            // so the next search ends before the previous byte.
+            let next = if start != end {
+                start
+            } else if start == 0 {
+                self.finished = true;
+                start
+            } else {
+                start - 1
+            };
+
+            let ret = (start, slice.split_at(end).0.split_at(start).1);
+            self.slice = slice.split_at(next).0;
+            Some(ret)
+        },
+        fields = {slice, pattern, finished},
+    }
+
+    /// Gets the remainder of the slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::slice::bytes_rmatch_indices(b"foo-bar", &'-');
+    /// assert_eq!(iter.remainder(), b"foo-bar");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (3, &b"-"[..]));
+    /// assert_eq!(iter.remainder(), b"foo");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), b"");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.slice
+    }
+}
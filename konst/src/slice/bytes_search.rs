@@ -0,0 +1,261 @@
+// Substring search used by all the `find`-like functions of the crate.
+//
+// Const evaluation is slow, and has a limit on how many steps it can take,
+// so these algorithms try to minimize the amount of loop iterations:
+// - single-byte needles are searched for 8 bytes at a time (SWAR).
+// - longer needles are searched for with the two-way algorithm,
+//   which is linear on the combined length of the haystack and needle.
+
+const LO: u64 = u64::from_ne_bytes([0x01; 8]);
+const HI: u64 = u64::from_ne_bytes([0x80; 8]);
+
+// Sets the high bit of the bytes in `x` that are zero.
+//
+// This can also set the high bit of bytes more significant than a zero byte,
+// but never of bytes less significant than the least significant zero byte.
+const fn zero_bytes_mask(x: u64) -> u64 {
+    x.wrapping_sub(LO) & !x & HI
+}
+
+/// Finds the index of the first occurrence of `needle` in `haystack`
+pub(crate) const fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO * needle as u64;
+    let (chunks, rest) = haystack.as_chunks::<8>();
+
+    let mut i = 0;
+    while i < chunks.len() {
+        let mask = zero_bytes_mask(u64::from_le_bytes(chunks[i]) ^ repeated);
+        if mask != 0 {
+            // little-endian, so the least significant byte is the first one
+            return Some(i * 8 + (mask.trailing_zeros() / 8) as usize);
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < rest.len() {
+        if rest[i] == needle {
+            return Some(chunks.len() * 8 + i);
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Finds the index of the last occurrence of `needle` in `haystack`
+pub(crate) const fn memrchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO * needle as u64;
+    let (rest, chunks) = haystack.as_rchunks::<8>();
+
+    let mut i = chunks.len();
+    while i != 0 {
+        i -= 1;
+
+        let chunk = chunks[i];
+        if zero_bytes_mask(u64::from_le_bytes(chunk) ^ repeated) != 0 {
+            // the mask can have false positives after the first match,
+            // so the bytes are compared individually.
+            let mut j = 8;
+            while j != 0 {
+                j -= 1;
+                if chunk[j] == needle {
+                    return Some(rest.len() + i * 8 + j);
+                }
+            }
+        }
+    }
+
+    let mut i = rest.len();
+    while i != 0 {
+        i -= 1;
+        if rest[i] == needle {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+// Indexes `$bytes` from the end when `$rev` is true,
+// searching backwards is done by searching forwards in the reversed haystack and needle.
+macro_rules! at {
+    ($rev:ident, $bytes:ident[$i:expr]) => {
+        if $rev {
+            $bytes[$bytes.len() - 1 - $i]
+        } else {
+            $bytes[$i]
+        }
+    };
+}
+
+/// Finds the index of the first occurrence of `needle` in `haystack`,
+/// or of the last occurrence if `REV` is true.
+///
+/// `needle` must not be empty.
+pub(crate) const fn two_way_search<const REV: bool>(
+    haystack: &[u8],
+    needle: &[u8],
+) -> Option<usize> {
+    let len = needle.len();
+    if len > haystack.len() {
+        return None;
+    }
+
+    let TwoWay {
+        crit_pos,
+        period,
+        byteset,
+        long_period,
+    } = TwoWay::new::<REV>(needle);
+
+    // The position of the current match attempt.
+    let mut position = 0;
+    // How much of the start of the needle is known to match at `position`,
+    // only used when the needle is periodic (`!long_period`).
+    let mut memory = 0;
+
+    'search: while position + len <= haystack.len() {
+        // skips the needle length if the last byte can't be part of the needle
+        let tail = at!(REV, haystack[position + len - 1]);
+        if (byteset >> (tail & 63)) & 1 == 0 {
+            position += len;
+            memory = 0;
+            continue 'search;
+        }
+
+        // matches the right part of the needle
+        let mut i = if long_period || memory < crit_pos {
+            crit_pos
+        } else {
+            memory
+        };
+        while i < len {
+            if at!(REV, needle[i]) != at!(REV, haystack[position + i]) {
+                position += i - crit_pos + 1;
+                memory = 0;
+                continue 'search;
+            }
+            i += 1;
+        }
+
+        // matches the left part of the needle
+        let start = if long_period { 0 } else { memory };
+        let mut i = crit_pos;
+        while i > start {
+            i -= 1;
+            if at!(REV, needle[i]) != at!(REV, haystack[position + i]) {
+                position += period;
+                if !long_period {
+                    memory = len - period;
+                }
+                continue 'search;
+            }
+        }
+
+        return Some(if REV {
+            haystack.len() - position - len
+        } else {
+            position
+        });
+    }
+
+    None
+}
+
+// The precomputed data of the two-way algorithm,
+// as described in "Two-way string-matching" by Crochemore and Perrin.
+struct TwoWay {
+    // the critical factorization of the needle
+    crit_pos: usize,
+    // the period of the needle, if it's not `long_period`,
+    // otherwise a shift that's no larger than the period.
+    period: usize,
+    // bitset of the lowest 6 bits of each byte in the needle
+    byteset: u64,
+    long_period: bool,
+}
+
+impl TwoWay {
+    const fn new<const REV: bool>(needle: &[u8]) -> Self {
+        let (crit_pos_less, period_less) = maximal_suffix::<REV>(needle, false);
+        let (crit_pos_greater, period_greater) = maximal_suffix::<REV>(needle, true);
+
+        let (crit_pos, period) = if crit_pos_less > crit_pos_greater {
+            (crit_pos_less, period_less)
+        } else {
+            (crit_pos_greater, period_greater)
+        };
+
+        // the needle is periodic if the part before the critical position
+        // repeats at `period`
+        let mut long_period = crit_pos + period > needle.len();
+        let mut i = 0;
+        while !long_period && i < crit_pos {
+            long_period = at!(REV, needle[i]) != at!(REV, needle[i + period]);
+            i += 1;
+        }
+
+        let period = if long_period {
+            let right_len = needle.len() - crit_pos;
+            (if crit_pos > right_len {
+                crit_pos
+            } else {
+                right_len
+            }) + 1
+        } else {
+            period
+        };
+
+        let mut byteset = 0u64;
+        let mut i = 0;
+        while i < needle.len() {
+            byteset |= 1 << (needle[i] & 63);
+            i += 1;
+        }
+
+        Self {
+            crit_pos,
+            period,
+            byteset,
+            long_period,
+        }
+    }
+}
+
+// Computes the start and period of the lexicographically maximal suffix of `arr`,
+// using the reverse ordering of bytes if `order_greater` is false.
+const fn maximal_suffix<const REV: bool>(arr: &[u8], order_greater: bool) -> (usize, usize) {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+
+    while right + offset < arr.len() {
+        let a = at!(REV, arr[right + offset]);
+        let b = at!(REV, arr[left + offset]);
+
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            // the suffix is smaller, the period is the entire prefix so far
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            // advances through the repetition of the current period
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // the suffix is larger, starts over from the current location
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+
+    (left, period)
+}
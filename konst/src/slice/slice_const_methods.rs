@@ -1,4 +1,4 @@
-use crate::slice::{BytesPattern, PatternNorm};
+use crate::slice::{BytesPattern, PatternNorm, bytes_search};
 
 #[doc(hidden)]
 #[macro_export]
@@ -674,33 +674,10 @@ where
     __bytes_find(left, pattern.as_bytes())
 }
 pub(crate) const fn __bytes_find(left: &[u8], pattern: &[u8]) -> Option<usize> {
-    let mut matching = pattern;
-
-    crate::for_range! {i in 0..left.len() =>
-        match matching {
-            [mb, m_rem @ ..] => {
-                let b = left[i];
-
-                matching = if b == *mb {
-                    m_rem
-                } else {
-                    match pattern {
-                        // For when the string is "lawlawn" and we are trying to find "lawn"
-                        [mb2, m_rem2 @ ..] if b == *mb2 => m_rem2,
-                        _ => pattern,
-                    }
-                };
-            }
-            [] => {
-                return Some(i - pattern.len())
-            }
-        }
-    }
-
-    if matching.is_empty() {
-        Some(left.len() - pattern.len())
-    } else {
-        None
+    match *pattern {
+        [] => Some(0),
+        [byte] => bytes_search::memchr(byte, left),
+        _ => bytes_search::two_way_search::<false>(left, pattern),
     }
 }
 
@@ -756,34 +733,11 @@ where
     __bytes_rfind(left, pattern.as_bytes())
 }
 pub(crate) const fn __bytes_rfind(left: &[u8], pattern: &[u8]) -> Option<usize> {
-    let mut matching = pattern;
-
-    let llen = left.len();
-
-    let mut i = llen;
-
-    while i != 0 {
-        i -= 1;
-
-        match matching {
-            [m_rem @ .., mb] => {
-                let b = left[i];
-
-                matching = if b == *mb {
-                    m_rem
-                } else {
-                    match pattern {
-                        // For when the string is "lawlawn" and we are trying to find "lawn"
-                        [m_rem2 @ .., mb2] if b == *mb2 => m_rem2,
-                        _ => pattern,
-                    }
-                };
-            }
-            [] => return Some(i + (!pattern.is_empty()) as usize),
-        }
+    match *pattern {
+        [] => Some(left.len().saturating_sub(1)),
+        [byte] => bytes_search::memrchr(byte, left),
+        _ => bytes_search::two_way_search::<true>(left, pattern),
     }
-
-    if matching.is_empty() { Some(i) } else { None }
 }
 
 /// Returns whether `pattern` is contained inside `left`, searching in reverse.
@@ -813,6 +767,42 @@ pub(crate) const fn __bytes_rcontain(left: &[u8], pattern: &[u8]) -> bool {
     matches!(bytes_rfind(left, pattern), Some(_))
 }
 
+/// Counts the non-overlapping matches of `pattern` in `left`.
+///
+/// An empty `pattern` matches at every index from `0` to `left.len()` inclusive.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::slice::bytes_count_matches;
+///
+/// assert_eq!(bytes_count_matches(b"foo\nbar\nbaz\n", &'\n'), 3);
+/// assert_eq!(bytes_count_matches(b"aaaaa", b"aa"), 2);
+/// assert_eq!(bytes_count_matches(b"foo-bar", "baz"), 0);
+/// assert_eq!(bytes_count_matches(b"foo", b""), 4);
+///
+/// ```
+///
+pub const fn bytes_count_matches<const N: usize, P>(left: &[u8], pattern: &P) -> usize
+where
+    P: ?Sized + BytesPattern<N>,
+{
+    let pattern = PatternNorm::new(pattern);
+    let pattern = pattern.as_bytes();
+
+    if pattern.is_empty() {
+        return left.len() + 1;
+    }
+
+    let mut left = left;
+    let mut count = 0;
+    while let Some(pos) = __bytes_find(left, pattern) {
+        count += 1;
+        left = left.split_at(pos + pattern.len()).1;
+    }
+    count
+}
+
 /// Removes all instances of `needle` from the start and end of `this`.
 ///
 /// # Example
@@ -963,57 +953,6 @@ pub(crate) const fn __bytes_trim_end_matches<'a>(mut this: &'a [u8], needle: &[u
     }
 }
 
-macro_rules! elem_then_rem {
-    ($elem:ident, $($rem:tt)*) => { [$elem, $($rem)*] };
-}
-
-macro_rules! rem_then_elem {
-    ($elem:ident, $($rem:tt)*) => { [$($rem)*, $elem] };
-}
-
-macro_rules! byte_find_then {
-    ($slice_order:ident, $this:ident, $needle:ident, |$next:ident| $then:block) => ({
-        if $needle.is_empty() {
-            return Some($this);
-        }
-
-        let mut matching = $needle;
-
-        let mut $next = $this;
-
-        while let $slice_order!(mb, ref m_rem @ ..) = *matching {
-            matching = m_rem;
-
-            if let $slice_order!(b, ref rem @ ..) = *$next {
-                if b != mb {
-                    matching = match *$needle {
-                        // For when the string is "lawlawn" and we are skipping "lawn"
-                        $slice_order!(mb2, ref m_rem2 @ ..) if b == mb2 => {
-                            // This is considered used in half of the macro invocations
-                            #[allow(unused_assignments)]
-                            {$this = $next;}
-                            m_rem2
-                        },
-                        _ => {
-                            // This is considered used in half of the macro invocations
-                            #[allow(unused_assignments)]
-                            {$this = rem;}
-                            $needle
-                        },
-                    };
-                }
-                $next = rem;
-            } else {
-                return None;
-            }
-        }
-
-        $then
-
-        Some($this)
-    });
-}
-
 /// Advances `this` past the first instance of `needle`.
 ///
 /// Return `None` if no instance of `needle` is found.
@@ -1045,8 +984,11 @@ where
     let needle = PatternNorm::new(needle);
     __bytes_find_skip(this, needle.as_bytes())
 }
-pub(crate) const fn __bytes_find_skip<'a>(mut this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
-    byte_find_then! {elem_then_rem, this, needle, |next| {this = next}}
+pub(crate) const fn __bytes_find_skip<'a>(this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
+    match __bytes_find(this, needle) {
+        Some(pos) => Some(this.split_at(pos + needle.len()).1),
+        None => None,
+    }
 }
 
 /// Advances `this` up to the first instance of `needle`.
@@ -1080,8 +1022,11 @@ where
     let needle = PatternNorm::new(needle);
     __bytes_find_keep(this, needle.as_bytes())
 }
-pub(crate) const fn __bytes_find_keep<'a>(mut this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
-    byte_find_then! {elem_then_rem, this, needle, |next| {}}
+pub(crate) const fn __bytes_find_keep<'a>(this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
+    match __bytes_find(this, needle) {
+        Some(pos) => Some(this.split_at(pos).1),
+        None => None,
+    }
 }
 
 /// Truncates `this` to before the last instance of `needle`.
@@ -1115,8 +1060,15 @@ where
     let needle = PatternNorm::new(needle);
     __bytes_rfind_skip(this, needle.as_bytes())
 }
-pub(crate) const fn __bytes_rfind_skip<'a>(mut this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
-    byte_find_then! {rem_then_elem, this, needle, |next| {this = next}}
+pub(crate) const fn __bytes_rfind_skip<'a>(this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
+    if needle.is_empty() {
+        return Some(this);
+    }
+
+    match __bytes_rfind(this, needle) {
+        Some(pos) => Some(this.split_at(pos).0),
+        None => None,
+    }
 }
 
 /// Truncates `this` to the last instance of `needle`.
@@ -1150,6 +1102,13 @@ where
     let needle = PatternNorm::new(needle);
     __bytes_rfind_keep(this, needle.as_bytes())
 }
-pub(crate) const fn __bytes_rfind_keep<'a>(mut this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
-    byte_find_then! {rem_then_elem, this, needle, |next| {}}
+pub(crate) const fn __bytes_rfind_keep<'a>(this: &'a [u8], needle: &[u8]) -> Option<&'a [u8]> {
+    if needle.is_empty() {
+        return Some(this);
+    }
+
+    match __bytes_rfind(this, needle) {
+        Some(pos) => Some(this.split_at(pos + needle.len()).0),
+        None => None,
+    }
 }
@@ -19,10 +19,40 @@ mod chars_methods;
 #[cfg(feature = "iter")]
 pub use chars_methods::*;
 
+mod case_conversion;
+
+pub use self::case_conversion::{
+    to_camel_case, to_kebab_case, to_pascal_case, to_screaming_snake_case, to_snake_case,
+};
+
+#[doc(hidden)]
+pub use self::case_conversion::{__CaseStyle, __convert_case, __convert_case_len};
+
+mod char_predicate;
+
+pub use self::char_predicate::CharPredicate;
+
 mod concatenation;
 
 pub use concatenation::*;
 
+mod edit_distance;
+
+#[cfg(feature = "konst_proc_macros")]
+mod formatting;
+
+#[cfg(feature = "konst_proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "konst_proc_macros")))]
+pub use self::formatting::format;
+
+#[cfg(feature = "konst_proc_macros")]
+#[doc(hidden)]
+pub use self::formatting::{
+    __FmtAlign, __FmtArg, __FmtArgConv, __FmtKind, __FmtPiece, __FmtSpec, __format,
+};
+
+pub use self::edit_distance::{closest_match, edit_distance};
+
 #[cfg(test)]
 mod priv_string_tests;
 
@@ -36,18 +66,69 @@ mod split_once;
 
 pub use split_once::*;
 
+mod str_builder;
+
+pub use self::str_builder::{CapacityError, StrBuilder};
+
+mod number_to_str;
+
+pub use self::number_to_str::{
+    Float, FloatInput, IntFmt, Integer, IntegerInput, float_to_array_str, float_to_str_len,
+    from_float, from_int, int_to_array_str, int_to_str_len,
+};
+
+mod transformation;
+
+pub use self::transformation::{repeat, replace, to_lower, to_upper};
+
+#[doc(hidden)]
+pub use self::transformation::{
+    __repeat, __repeat_len, __replace, __replace_len, __to_lower, __to_upper,
+};
+
+mod writer;
+
 #[cfg(feature = "iter")]
 mod splitting;
 
 #[cfg(feature = "iter")]
 pub use splitting::*;
 
+#[cfg(feature = "iter")]
+mod split_n_items;
+
+#[cfg(feature = "iter")]
+pub use split_n_items::*;
+
 #[cfg(feature = "iter")]
 mod split_terminator_items;
 
 #[cfg(feature = "iter")]
 pub use split_terminator_items::*;
 
+#[cfg(feature = "iter")]
+mod lines;
+
+#[cfg(feature = "iter")]
+pub use self::lines::{Lines, RLines, lines};
+
+#[cfg(feature = "iter")]
+mod matches_items;
+
+#[cfg(feature = "iter")]
+pub use self::matches_items::{
+    MatchIndices, Matches, RMatchIndices, match_indices, matches, rmatch_indices,
+};
+
+#[cfg(feature = "iter")]
+mod split_whitespace;
+
+#[cfg(feature = "iter")]
+pub use self::split_whitespace::{
+    RSplitAsciiWhitespace, RSplitWhitespace, SplitAsciiWhitespace, SplitWhitespace,
+    split_ascii_whitespace, split_whitespace,
+};
+
 __declare_string_cmp_fns! {
     import_path = "konst",
     equality_fn = eq_str,
@@ -93,7 +174,7 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    crate::slice::__bytes_start_with(left.as_bytes(), pat.as_bytes())
+    pat.prefix_len(left.as_bytes()).is_some()
 }
 
 /// A const equivalent of
@@ -120,7 +201,7 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    crate::slice::__bytes_end_with(left.as_bytes(), pat.as_bytes())
+    pat.suffix_len(left.as_bytes()).is_some()
 }
 
 /// A const equivalent of
@@ -148,7 +229,10 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    crate::slice::__bytes_find(left.as_bytes(), pat.as_bytes())
+    match pat.find_in(left.as_bytes()) {
+        Some((start, _)) => Some(start),
+        None => None,
+    }
 }
 
 /// A const equivalent of
@@ -176,10 +260,7 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    matches!(
-        crate::slice::__bytes_find(left.as_bytes(), pat.as_bytes()),
-        Some(_)
-    )
+    pat.find_in(left.as_bytes()).is_some()
 }
 
 /// A const equivalent of
@@ -206,7 +287,15 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    crate::slice::__bytes_rfind(left.as_bytes(), pat.as_bytes())
+    if let Some(pat) = pat.as_bytes() {
+        // same search as `slice::bytes_rfind`, so that both agree on empty patterns
+        return crate::slice::__bytes_rfind(left.as_bytes(), pat);
+    }
+
+    match pat.rfind_in(left.as_bytes()) {
+        Some((start, _)) => Some(start),
+        None => None,
+    }
 }
 
 /// A const equivalent of
@@ -232,10 +321,57 @@ where
     P: Pattern<'a>,
 {
     let pat = PatternNorm::new(pat);
-    matches!(
-        crate::slice::__bytes_rfind(left.as_bytes(), pat.as_bytes()),
-        Some(_)
-    )
+    pat.rfind_in(left.as_bytes()).is_some()
+}
+
+/// Counts the non-overlapping matches of `pat` in `this`,
+/// taking a [`Pattern`] parameter.
+///
+/// This is equivalent to `this.matches(pat).count()`.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const CSV: &str = "foo,1\nbar,2\nbaz,3\n";
+/// const RECORDS: usize = string::count_matches(CSV, '\n');
+///
+/// const TABLE: [&str; RECORDS] = [""; RECORDS];
+/// assert_eq!(TABLE.len(), 3);
+///
+/// assert_eq!(string::count_matches("aaaaa", "aa"), 2);
+/// assert_eq!(string::count_matches("foo", 'q'), 0);
+///
+/// // empty patterns match at every char boundary
+/// assert_eq!(string::count_matches("ñ个", ""), 3);
+///
+/// ```
+///
+pub const fn count_matches<'a, P>(this: &str, pat: P) -> usize
+where
+    P: Pattern<'a>,
+{
+    let pat = PatternNorm::new(pat);
+    let mut bytes = this.as_bytes();
+
+    if pat.is_empty() {
+        let mut count = 1;
+        crate::for_range! {i in 0..bytes.len() =>
+            // counts the bytes that start a char
+            if byte_is_char_boundary!(bytes[i]) {
+                count += 1;
+            }
+        }
+        return count;
+    }
+
+    let mut count = 0;
+    while let Some((_, end)) = pat.find_in(bytes) {
+        count += 1;
+        bytes = bytes.split_at(end).1;
+    }
+    count
 }
 
 /// A const equivalent of `&string[..len]`.
@@ -604,12 +740,9 @@ where
 {
     let pat = PatternNorm::new(pattern);
 
-    // Safety: because `pat` is a `Pattern`, removing it should result in a valid `&str`
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_strip_prefix(string.as_bytes(), pat.as_bytes()),
-            __from_u8_subslice_of_str,
-        )
+    match pat.prefix_len(string.as_bytes()) {
+        Some(len) => Some(str_from(string, len)),
+        None => None,
     }
 }
 
@@ -649,12 +782,9 @@ where
 {
     let pat = PatternNorm::new(pattern);
 
-    // Safety: because `suffix` is a `&str`, removing it should result in a valid `&str`
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_strip_suffix(string.as_bytes(), pat.as_bytes()),
-            __from_u8_subslice_of_str,
-        )
+    match pat.suffix_len(string.as_bytes()) {
+        Some(len) => Some(str_up_to(string, string.len() - len)),
+        None => None,
     }
 }
 
@@ -679,11 +809,7 @@ where
     P: Pattern<'p>,
 {
     let needle = PatternNorm::new(needle);
-    let trimmed = crate::slice::__bytes_trim_matches(this.as_bytes(), needle.as_bytes());
-    // safety:
-    // because bytes_trim_matches was passed `&str`s casted to `&[u8]`s,
-    // it returns a valid utf8 sequence.
-    unsafe { __from_u8_subslice_of_str(trimmed) }
+    trim_end_matches_norm(trim_start_matches_norm(this, &needle), &needle)
 }
 
 /// A const subset of [`str::trim_start_matches`].
@@ -706,12 +832,25 @@ pub const fn trim_start_matches<'a, 'p, P>(this: &'a str, needle: P) -> &'a str
 where
     P: Pattern<'p>,
 {
-    let needle = PatternNorm::new(needle);
-    let trimmed = crate::slice::__bytes_trim_start_matches(this.as_bytes(), needle.as_bytes());
-    // safety:
-    // because bytes_trim_start_matches was passed `&str`s casted to `&[u8]`s,
-    // it returns a valid utf8 sequence.
-    unsafe { __from_u8_subslice_of_str(trimmed) }
+    trim_start_matches_norm(this, &PatternNorm::new(needle))
+}
+
+const fn trim_start_matches_norm<'a, 'p, P>(
+    mut this: &'a str,
+    needle: &PatternNorm<'p, P>,
+) -> &'a str
+where
+    P: Pattern<'p>,
+{
+    if needle.is_empty() {
+        return this;
+    }
+
+    while let Some(len) = needle.prefix_len(this.as_bytes()) {
+        this = str_from(this, len);
+    }
+
+    this
 }
 
 /// A const subset of [`str::trim_end_matches`].
@@ -734,12 +873,22 @@ pub const fn trim_end_matches<'a, 'p, P>(this: &'a str, needle: P) -> &'a str
 where
     P: Pattern<'p>,
 {
-    let needle = PatternNorm::new(needle);
-    let trimmed = crate::slice::__bytes_trim_end_matches(this.as_bytes(), needle.as_bytes());
-    // safety:
-    // because bytes_trim_end_matches was passed `&str`s casted to `&[u8]`s,
-    // it returns a valid utf8 sequence.
-    unsafe { __from_u8_subslice_of_str(trimmed) }
+    trim_end_matches_norm(this, &PatternNorm::new(needle))
+}
+
+const fn trim_end_matches_norm<'a, 'p, P>(mut this: &'a str, needle: &PatternNorm<'p, P>) -> &'a str
+where
+    P: Pattern<'p>,
+{
+    if needle.is_empty() {
+        return this;
+    }
+
+    while let Some(len) = needle.suffix_len(this.as_bytes()) {
+        this = str_up_to(this, this.len() - len);
+    }
+
+    this
 }
 
 /// Advances `this` past the first instance of `needle`.
@@ -774,14 +923,9 @@ where
     P: Pattern<'p>,
 {
     let needle = PatternNorm::new(needle);
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_find_skip(this.as_bytes(), needle.as_bytes()),
-            // safety:
-            // because bytes_find_skip was passed `&str`s casted to `&[u8]`s,
-            // it returns a valid utf8 sequence.
-            __from_u8_subslice_of_str,
-        )
+    match needle.find_in(this.as_bytes()) {
+        Some((_, end)) => Some(str_from(this, end)),
+        None => None,
     }
 }
 
@@ -817,14 +961,9 @@ where
     P: Pattern<'p>,
 {
     let needle = PatternNorm::new(needle);
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_find_keep(this.as_bytes(), needle.as_bytes()),
-            // safety:
-            // because bytes_find_keep was passed `&str`s casted to `&[u8]`s,
-            // it returns a valid utf8 sequence.
-            __from_u8_subslice_of_str,
-        )
+    match needle.find_in(this.as_bytes()) {
+        Some((start, _)) => Some(str_from(this, start)),
+        None => None,
     }
 }
 
@@ -860,14 +999,9 @@ where
     P: Pattern<'p>,
 {
     let needle = PatternNorm::new(needle);
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_rfind_skip(this.as_bytes(), needle.as_bytes()),
-            // safety:
-            // because bytes_rfind_skip was passed `&str`s casted to `&[u8]`s,
-            // it returns a valid utf8 sequence.
-            __from_u8_subslice_of_str,
-        )
+    match needle.rfind_in(this.as_bytes()) {
+        Some((start, _)) => Some(str_up_to(this, start)),
+        None => None,
     }
 }
 
@@ -903,14 +1037,9 @@ where
     P: Pattern<'p>,
 {
     let needle = PatternNorm::new(needle);
-    unsafe {
-        crate::option::map!(
-            crate::slice::__bytes_rfind_keep(this.as_bytes(), needle.as_bytes()),
-            // safety:
-            // because bytes_rfind_keep was passed `&str`s casted to `&[u8]`s,
-            // it returns a valid utf8 sequence.
-            __from_u8_subslice_of_str,
-        )
+    match needle.rfind_in(this.as_bytes()) {
+        Some((_, end)) => Some(str_up_to(this, end)),
+        None => None,
     }
 }
 
@@ -919,7 +1048,7 @@ macro_rules! byte_is_char_boundary {
         ($b as i8) >= -0x40
     };
 }
-use byte_is_char_boundary;
+pub(crate) use byte_is_char_boundary;
 
 #[doc(hidden)]
 #[inline]
@@ -954,6 +1083,42 @@ pub const fn __find_prev_char_boundary(bytes: &[u8], mut position: usize) -> usi
     position
 }
 
+/// Converts the bytes of a single UTF-8 encoded character to its char value
+/// (as a u32)
+pub(crate) const fn char_bytes_to_usv(bytes: &[u8]) -> u32 {
+    match *bytes {
+        [a] => a as _,
+        [a, b] => ((a as u32 & 0x1F) << 6) | (b as u32 & 0x7F),
+        [a, b, c] => ((a as u32 & 0xF) << 12) | ((b as u32 & 0x3F) << 6) | (c as u32 & 0x3F),
+        [a, b, c, d] => {
+            ((a as u32 & 0x7) << 18)
+                | ((b as u32 & 0x3F) << 12)
+                | ((c as u32 & 0x3F) << 6)
+                | (d as u32 & 0x3F)
+        }
+        _ => {
+            #[cfg(feature = "debug")]
+            {
+                panic!("string must be a single char long")
+            }
+            #[cfg(not(feature = "debug"))]
+            {
+                0
+            }
+        }
+    }
+}
+
+/// Decodes the char starting at `bytes[i]`,
+/// returning it (as a u32) along with the index of the next char.
+pub(crate) const fn next_char(bytes: &[u8], i: usize) -> (u32, usize) {
+    let end = __find_next_char_boundary(bytes, i);
+    let (char_bytes, _) = bytes.split_at(end);
+    let (_, char_bytes) = char_bytes.split_at(i);
+
+    (char_bytes_to_usv(char_bytes), end)
+}
+
 #[cold]
 #[track_caller]
 #[doc(hidden)]
@@ -0,0 +1,230 @@
+use crate::string::{ArrayStr, writer::Writer};
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum __CaseStyle {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_convert_case {
+    ($style:ident, $string:expr $(,)?) => {{
+        const __STYLE81608BFNA5: $crate::string::__CaseStyle = $crate::string::__CaseStyle::$style;
+
+        // the argument is evaluated outside the block with the `LEN`/`CONC`/`STR`
+        // constants, so that it can't refer to those constants.
+        const __STR81608BFNA5: &$crate::__::str = $string;
+
+        {
+            const LEN: $crate::__::usize =
+                $crate::string::__convert_case_len(__STR81608BFNA5, __STYLE81608BFNA5);
+
+            const CONC: &$crate::string::ArrayStr<LEN> =
+                &$crate::string::__convert_case(__STR81608BFNA5, __STYLE81608BFNA5);
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __convert_case_len(string: &str, style: __CaseStyle) -> usize {
+    let mut out = Writer::<0>::new();
+    write_converted(&mut out, string, style);
+    out.len
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __convert_case<const N: usize>(string: &str, style: __CaseStyle) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    write_converted(&mut out, string, style);
+    out.into_array_str()
+}
+
+const fn write_converted<const N: usize>(out: &mut Writer<N>, string: &str, style: __CaseStyle) {
+    let bytes = string.as_bytes();
+    let separator = match style {
+        __CaseStyle::Snake | __CaseStyle::ScreamingSnake => "_",
+        __CaseStyle::Kebab => "-",
+        __CaseStyle::Camel | __CaseStyle::Pascal => "",
+    };
+
+    let mut word_index = 0;
+    let mut i = 0;
+    loop {
+        let (start, end) = next_word(bytes, i);
+        if start == end {
+            break;
+        }
+
+        if word_index != 0 {
+            out.write_str(separator);
+        }
+
+        crate::for_range! {j in start..end =>
+            let uppercase = match style {
+                __CaseStyle::Snake | __CaseStyle::Kebab => false,
+                __CaseStyle::ScreamingSnake => true,
+                __CaseStyle::Camel => j == start && word_index != 0,
+                __CaseStyle::Pascal => j == start,
+            };
+
+            out.write_byte(if uppercase {
+                bytes[j].to_ascii_uppercase()
+            } else {
+                bytes[j].to_ascii_lowercase()
+            });
+        }
+
+        word_index += 1;
+        i = end;
+    }
+}
+
+enum CharKind {
+    Upper,
+    // non-ASCII chars are treated as lowercase letters
+    Lower,
+    Digit,
+    Separator,
+}
+
+const fn char_kind(byte: u8) -> CharKind {
+    match byte {
+        b'A'..=b'Z' => CharKind::Upper,
+        b'0'..=b'9' => CharKind::Digit,
+        0..=127 if !byte.is_ascii_lowercase() => CharKind::Separator,
+        _ => CharKind::Lower,
+    }
+}
+
+// returns the `start..end` byte range of the first word at or after `i`,
+// returning an empty range if there's no words left.
+const fn next_word(bytes: &[u8], mut i: usize) -> (usize, usize) {
+    while i < bytes.len() && matches!(char_kind(bytes[i]), CharKind::Separator) {
+        i += 1;
+    }
+
+    let start = i;
+    if i < bytes.len() {
+        i += 1;
+    }
+
+    while i < bytes.len() {
+        let prev = char_kind(bytes[i - 1]);
+        let next_is_lower =
+            i + 1 < bytes.len() && matches!(char_kind(bytes[i + 1]), CharKind::Lower);
+
+        match char_kind(bytes[i]) {
+            CharKind::Separator => break,
+            // boundaries like `fooBar` and `v2Beta`
+            CharKind::Upper if matches!(prev, CharKind::Lower | CharKind::Digit) => break,
+            // the boundary in acronyms followed by a word, like `HTTPServer`
+            CharKind::Upper if matches!(prev, CharKind::Upper) && next_is_lower => break,
+            _ => {}
+        }
+
+        i += 1;
+    }
+
+    (start, i)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_snake_case {
+    ($string:expr $(,)?) => {
+        $crate::__string_convert_case!(Snake, $string)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_camel_case {
+    ($string:expr $(,)?) => {
+        $crate::__string_convert_case!(Camel, $string)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_pascal_case {
+    ($string:expr $(,)?) => {
+        $crate::__string_convert_case!(Pascal, $string)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_kebab_case {
+    ($string:expr $(,)?) => {
+        $crate::__string_convert_case!(Kebab, $string)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_screaming_snake_case {
+    ($string:expr $(,)?) => {
+        $crate::__string_convert_case!(ScreamingSnake, $string)
+    };
+}
+
+macro_rules! declare_case_macro_docs {
+    ($(
+        $name:ident, $macro:ident, $style_name:literal, $example:literal, $example2:literal;
+    )*) => ($(
+        #[doc = concat!("Converts a constant identifier-like string to ", $style_name, ".")]
+        ///
+        /// The string is split into words at
+        /// non-alphanumeric ASCII chars (e.g.: `_`, `-`, and spaces, which are removed),
+        /// and before uppercase letters that follow a lowercase letter or a digit,
+        /// or that begin a word after an acronym (e.g.: `HTTPServer` is `HTTP` + `Server`).
+        /// Digits stay in the word that they follow.
+        ///
+        /// Only ASCII letters change case,
+        /// non-ASCII chars are kept as-is and are treated like lowercase letters.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use konst::string;
+        ///
+        #[doc = concat!(
+            "assert_eq!(string::", stringify!($name), "!(\"maxConnections\"), \"",
+            $example,
+            "\");",
+        )]
+        ///
+        #[doc = concat!(
+            "const S: &str = string::", stringify!($name), "!(\"HTTPServer2_port-number\");"
+        )]
+        #[doc = concat!("assert_eq!(S, \"", $example2, "\");")]
+        /// ```
+        #[doc(inline)]
+        pub use crate::$macro as $name;
+    )*)
+}
+
+declare_case_macro_docs! {
+    to_snake_case, __string_to_snake_case, "`snake_case`",
+        "max_connections", "http_server2_port_number";
+    to_camel_case, __string_to_camel_case, "`camelCase`",
+        "maxConnections", "httpServer2PortNumber";
+    to_pascal_case, __string_to_pascal_case, "`PascalCase`",
+        "MaxConnections", "HttpServer2PortNumber";
+    to_kebab_case, __string_to_kebab_case, "`kebab-case`",
+        "max-connections", "http-server2-port-number";
+    to_screaming_snake_case, __string_to_screaming_snake_case, "`SCREAMING_SNAKE_CASE`",
+        "MAX_CONNECTIONS", "HTTP_SERVER2_PORT_NUMBER";
+}
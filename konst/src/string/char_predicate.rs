@@ -0,0 +1,170 @@
+// The chars are partitioned into disjoint sets (atoms),
+// so that every predicate (and every combination of them) is a set of atoms.
+const DIGIT: u16 = 1 << 0;
+const HEX_LOWER: u16 = 1 << 1;
+const OTHER_LOWER: u16 = 1 << 2;
+const HEX_UPPER: u16 = 1 << 3;
+const OTHER_UPPER: u16 = 1 << 4;
+const PUNCTUATION: u16 = 1 << 5;
+const SPACE: u16 = 1 << 6;
+// `\t`, `\n`, `\x0C`, and `\r`
+const WHITESPACE_CONTROL: u16 = 1 << 7;
+// `\x0B` is whitespace, but not ASCII whitespace
+const VERTICAL_TAB: u16 = 1 << 8;
+const OTHER_CONTROL: u16 = 1 << 9;
+const NON_ASCII_WHITESPACE: u16 = 1 << 10;
+const OTHER_NON_ASCII: u16 = 1 << 11;
+
+const ALL_ATOMS: u16 = (1 << 12) - 1;
+
+/// A predicate over chars, usable as a [`Pattern`](crate::string::Pattern)
+/// that matches any single char for which it returns true.
+///
+/// Closures and function pointers can't be called in `const fn`s,
+/// so predicates are built from the associated constants of this type,
+/// combined with the [`or`](Self::or), [`and`](Self::and), and [`negate`](Self::negate)
+/// methods.
+///
+/// To match a specific set of chars, you can use a `[char; N]` or `&[char]` pattern instead.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, CharPredicate};
+///
+/// const DIGIT: CharPredicate = CharPredicate::ASCII_DIGIT;
+/// const NOT_DIGIT: CharPredicate = DIGIT.negate();
+///
+/// assert_eq!(string::find("foo123bar", DIGIT), Some(3));
+/// assert_eq!(string::trim_matches("foo123bar", NOT_DIGIT), "123");
+///
+/// const SEPARATOR: CharPredicate = CharPredicate::WHITESPACE.or(CharPredicate::ASCII_PUNCTUATION);
+/// assert!(SEPARATOR.matches(' '));
+/// assert!(SEPARATOR.matches(','));
+/// assert!(!SEPARATOR.matches('a'));
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CharPredicate {
+    atoms: u16,
+}
+
+impl CharPredicate {
+    /// Matches no chars.
+    pub const NONE: Self = Self { atoms: 0 };
+
+    /// Matches all chars.
+    pub const ANY: Self = Self { atoms: ALL_ATOMS };
+
+    /// Matches the chars that [`char::is_ascii`] returns true for.
+    pub const ASCII: Self = Self {
+        atoms: ALL_ATOMS & !(NON_ASCII_WHITESPACE | OTHER_NON_ASCII),
+    };
+
+    /// Matches the chars that [`char::is_ascii_digit`] returns true for.
+    pub const ASCII_DIGIT: Self = Self { atoms: DIGIT };
+
+    /// Matches the chars that [`char::is_ascii_hexdigit`] returns true for.
+    pub const ASCII_HEXDIGIT: Self = Self {
+        atoms: DIGIT | HEX_LOWER | HEX_UPPER,
+    };
+
+    /// Matches the chars that [`char::is_ascii_lowercase`] returns true for.
+    pub const ASCII_LOWERCASE: Self = Self {
+        atoms: HEX_LOWER | OTHER_LOWER,
+    };
+
+    /// Matches the chars that [`char::is_ascii_uppercase`] returns true for.
+    pub const ASCII_UPPERCASE: Self = Self {
+        atoms: HEX_UPPER | OTHER_UPPER,
+    };
+
+    /// Matches the chars that [`char::is_ascii_alphabetic`] returns true for.
+    pub const ASCII_ALPHABETIC: Self = Self {
+        atoms: HEX_LOWER | OTHER_LOWER | HEX_UPPER | OTHER_UPPER,
+    };
+
+    /// Matches the chars that [`char::is_ascii_alphanumeric`] returns true for.
+    pub const ASCII_ALPHANUMERIC: Self = Self {
+        atoms: DIGIT | HEX_LOWER | OTHER_LOWER | HEX_UPPER | OTHER_UPPER,
+    };
+
+    /// Matches the chars that [`char::is_ascii_punctuation`] returns true for.
+    pub const ASCII_PUNCTUATION: Self = Self { atoms: PUNCTUATION };
+
+    /// Matches the chars that [`char::is_ascii_graphic`] returns true for.
+    pub const ASCII_GRAPHIC: Self = Self {
+        atoms: DIGIT | HEX_LOWER | OTHER_LOWER | HEX_UPPER | OTHER_UPPER | PUNCTUATION,
+    };
+
+    /// Matches the chars that [`char::is_ascii_whitespace`] returns true for.
+    pub const ASCII_WHITESPACE: Self = Self {
+        atoms: SPACE | WHITESPACE_CONTROL,
+    };
+
+    /// Matches the chars that [`char::is_ascii_control`] returns true for.
+    pub const ASCII_CONTROL: Self = Self {
+        atoms: WHITESPACE_CONTROL | VERTICAL_TAB | OTHER_CONTROL,
+    };
+
+    /// Matches the chars that [`char::is_whitespace`] returns true for.
+    pub const WHITESPACE: Self = Self {
+        atoms: SPACE | WHITESPACE_CONTROL | VERTICAL_TAB | NON_ASCII_WHITESPACE,
+    };
+
+    /// Matches the chars that either `self` or `other` match.
+    pub const fn or(self, other: Self) -> Self {
+        Self {
+            atoms: self.atoms | other.atoms,
+        }
+    }
+
+    /// Matches the chars that both `self` and `other` match.
+    pub const fn and(self, other: Self) -> Self {
+        Self {
+            atoms: self.atoms & other.atoms,
+        }
+    }
+
+    /// Matches the chars that `self` doesn't match.
+    pub const fn negate(self) -> Self {
+        Self {
+            atoms: !self.atoms & ALL_ATOMS,
+        }
+    }
+
+    /// Whether this predicate matches `c`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::CharPredicate;
+    ///
+    /// assert!(CharPredicate::ASCII_HEXDIGIT.matches('f'));
+    /// assert!(!CharPredicate::ASCII_HEXDIGIT.matches('g'));
+    ///
+    /// assert!(CharPredicate::WHITESPACE.matches('\u{A0}'));
+    /// assert!(!CharPredicate::ASCII_WHITESPACE.matches('\u{A0}'));
+    ///
+    /// ```
+    pub const fn matches(self, c: char) -> bool {
+        self.atoms & atom_of(c) != 0
+    }
+}
+
+const fn atom_of(c: char) -> u16 {
+    match c {
+        '0'..='9' => DIGIT,
+        'a'..='f' => HEX_LOWER,
+        'g'..='z' => OTHER_LOWER,
+        'A'..='F' => HEX_UPPER,
+        'G'..='Z' => OTHER_UPPER,
+        ' ' => SPACE,
+        '\t' | '\n' | '\x0C' | '\r' => WHITESPACE_CONTROL,
+        '\x0B' => VERTICAL_TAB,
+        '\0'..='\x1F' | '\x7F' => OTHER_CONTROL,
+        '\0'..='\x7F' => PUNCTUATION,
+        _ if c.is_whitespace() => NON_ASCII_WHITESPACE,
+        _ => OTHER_NON_ASCII,
+    }
+}
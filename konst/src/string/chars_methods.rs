@@ -3,36 +3,10 @@ use crate::{
     string,
 };
 
-use crate::string::{__find_next_char_boundary, __find_prev_char_boundary};
-
-/// Converts a string spanning one character to its char value
-/// (as a u32)
-pub(super) const fn string_to_usv(s: &str) -> u32 {
-    match *s.as_bytes() {
-        [a] => a as _,
-        [a, b] => ((a as u32 & 0x1F) << 6) | (b as u32 & 0x7F),
-        [a, b, c] => ((a as u32 & 0xF) << 12) | ((b as u32 & 0x3F) << 6) | (c as u32 & 0x3F),
-        [a, b, c, d] => {
-            ((a as u32 & 0x7) << 18)
-                | ((b as u32 & 0x3F) << 12)
-                | ((c as u32 & 0x3F) << 6)
-                | (d as u32 & 0x3F)
-        }
-        _ => {
-            #[cfg(feature = "debug")]
-            {
-                panic!("string must be a single char long")
-            }
-            #[cfg(not(feature = "debug"))]
-            {
-                0
-            }
-        }
-    }
-}
+use crate::string::{__find_next_char_boundary, __find_prev_char_boundary, char_bytes_to_usv};
 
 pub(super) const fn string_to_char(s: &str) -> char {
-    let c: u32 = string_to_usv(s);
+    let c: u32 = char_bytes_to_usv(s.as_bytes());
 
     unsafe { char::from_u32_unchecked(c) }
 }
@@ -35,7 +35,7 @@ pub enum __SepArg {
 }
 
 impl __SepArg {
-    const fn len(self) -> usize {
+    pub(crate) const fn len(self) -> usize {
         match self {
             Self::Char(x) => x.len_utf8(),
             Self::Str(x) => x.len(),
@@ -271,16 +271,57 @@ macro_rules! __str_from_iter_hidden {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-#[doc(hidden)]
-pub struct ArrayStr<const N: usize>([u8; N]);
+/// A string stored inline in a `[u8; N]`, where `N` is the length of the string.
+///
+/// This is returned by `const fn`s that build strings,
+/// whose length is computed by a separate `const fn` beforehand.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::base64::{self, Config};
+/// use konst::string::ArrayStr;
+///
+/// const LEN: usize = base64::encoded_len(3, Config::STANDARD);
+/// const ENCODED: ArrayStr<LEN> = base64::encode(&[0xFF, 0xEE, 0xDD], Config::STANDARD);
+///
+/// assert_eq!(ENCODED.as_str(), "/+7d");
+/// assert_eq!(ENCODED.len(), 4);
+///
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayStr<const N: usize>(pub(crate) [u8; N]);
 
 impl<const N: usize> ArrayStr<N> {
+    /// Gets the string as a `&str`.
     pub const fn as_str(&self) -> &str {
         match core::str::from_utf8(&self.0) {
             Ok(s) => s,
             Err(_) => panic!("bug: konst made an invalid string"),
         }
     }
+
+    /// Gets the string as a byte slice.
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The length of the string, in bytes.
+    pub const fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for ArrayStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> core::fmt::Display for ArrayStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Macro equivalent of `<[&str]>::concat`, which takes a constant as an argument.
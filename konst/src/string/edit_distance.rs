@@ -0,0 +1,162 @@
+use crate::string::next_char;
+
+/// The maximum length (in chars) of the shorter string passed to [`edit_distance`].
+const MAX_LEN: usize = 256;
+
+/// Computes the edit distance between two strings.
+///
+/// This is the minimum amount of single char insertions, deletions, substitutions,
+/// and transpositions of adjacent chars that turn one string into the other
+/// (the "optimal string alignment" variant of the Damerau-Levenshtein distance).
+/// Chars are compared exactly, including case.
+///
+/// # Panics
+///
+/// Panics if both strings are longer than 256 chars.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// assert_eq!(string::edit_distance("color", "color"), 0);
+/// assert_eq!(string::edit_distance("colour", "color"), 1);
+/// assert_eq!(string::edit_distance("clor", "color"), 1);
+/// assert_eq!(string::edit_distance("cloor", "color"), 1);
+/// assert_eq!(string::edit_distance("kitten", "sitting"), 3);
+/// assert_eq!(string::edit_distance("", "ñandú"), 5);
+///
+/// ```
+pub const fn edit_distance(left: &str, right: &str) -> usize {
+    let (short, long) = if char_count(left) <= char_count(right) {
+        (left.as_bytes(), right.as_bytes())
+    } else {
+        (right.as_bytes(), left.as_bytes())
+    };
+
+    let mut short_chars = [0u32; MAX_LEN];
+    let mut short_len = 0;
+    let mut i = 0;
+    while i < short.len() {
+        if short_len == MAX_LEN {
+            panic!("edit_distance: both strings are longer than 256 chars")
+        }
+        (short_chars[short_len], i) = next_char(short, i);
+        short_len += 1;
+    }
+
+    // the rows of the distance matrix for the previous two chars of `long`,
+    // and for the current char of `long`.
+    let mut prev_prev_row = [0usize; MAX_LEN + 1];
+    let mut prev_row = [0usize; MAX_LEN + 1];
+    let mut row = [0usize; MAX_LEN + 1];
+
+    crate::for_range! {j in 0..short_len + 1 =>
+        prev_row[j] = j;
+    }
+
+    let mut prev_long_char = None::<u32>;
+    let mut long_len = 0;
+    let mut i = 0;
+    while i < long.len() {
+        let long_char;
+        (long_char, i) = next_char(long, i);
+        long_len += 1;
+
+        row[0] = long_len;
+        crate::for_range! {j in 1..short_len + 1 =>
+            let short_char = short_chars[j - 1];
+            let substitution = (short_char != long_char) as usize;
+
+            let mut dist = min(
+                prev_row[j - 1] + substitution,
+                min(prev_row[j] + 1, row[j - 1] + 1),
+            );
+
+            if let Some(prev_long_char) = prev_long_char
+                && j > 1
+                && short_char == prev_long_char
+                && short_chars[j - 2] == long_char
+            {
+                dist = min(dist, prev_prev_row[j - 2] + 1);
+            }
+
+            row[j] = dist;
+        }
+
+        (prev_prev_row, prev_row, row) = (prev_row, row, prev_prev_row);
+        prev_long_char = Some(long_char);
+    }
+
+    prev_row[short_len]
+}
+
+/// Finds the string in `candidates` that's the closest to `input`,
+/// going by the [`edit_distance`] between them.
+///
+/// This is useful for suggesting the intended keyword when parsing a misspelled one.
+///
+/// Returns a reference to the closest candidate,
+/// or `None` if no candidate is within `max_distance` edits of `input`.
+/// If multiple candidates are equally close, this returns the first one.
+///
+/// # Panics
+///
+/// Panics if both `input` and a candidate are longer than 256 chars.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const KEYS: &[&str] = &["color", "size", "shape"];
+///
+/// assert_eq!(string::closest_match("colour", KEYS, 2), Some(&"color"));
+/// assert_eq!(string::closest_match("sise", KEYS, 2), Some(&"size"));
+/// assert_eq!(string::closest_match("shaep", KEYS, 2), Some(&"shape"));
+/// assert_eq!(string::closest_match("weight", KEYS, 2), None);
+///
+/// ```
+pub const fn closest_match<'a, 'b>(
+    input: &str,
+    candidates: &'b [&'a str],
+    max_distance: usize,
+) -> Option<&'b &'a str> {
+    let mut closest: Option<(&'b &'a str, usize)> = None;
+
+    crate::for_range! {i in 0..candidates.len() =>
+        let candidate = &candidates[i];
+        let distance = edit_distance(input, candidate);
+
+        let is_closer = match closest {
+            Some((_, closest_distance)) => distance < closest_distance,
+            None => distance <= max_distance,
+        };
+
+        if is_closer {
+            closest = Some((candidate, distance));
+        }
+    }
+
+    match closest {
+        Some((candidate, _)) => Some(candidate),
+        None => None,
+    }
+}
+
+const fn char_count(string: &str) -> usize {
+    let bytes = string.as_bytes();
+    let mut count = 0;
+
+    crate::for_range! {i in 0..bytes.len() =>
+        if crate::string::byte_is_char_boundary!(bytes[i]) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+const fn min(l: usize, r: usize) -> usize {
+    if l < r { l } else { r }
+}
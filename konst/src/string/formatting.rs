@@ -0,0 +1,462 @@
+use crate::string::{
+    ArrayStr,
+    writer::{Digits, Writer},
+};
+
+#[doc(hidden)]
+pub struct __FmtArgConv<T>(pub T);
+
+/// A normalized argument of the [`format`](crate::string::format) macro.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum __FmtArg<'a> {
+    Int {
+        negative: bool,
+        magnitude: u128,
+        // the size of the integer type in bits,
+        // used to print negative integers as two's complement in non-decimal radixes
+        bits: u32,
+    },
+    Bool(bool),
+    Char(char),
+    Str(&'a str),
+}
+
+macro_rules! __ref_unref_impls {
+    ($($ref_token:tt $deref_token:tt)?) => {
+        impl<'a> __FmtArgConv<&'a $($ref_token)? bool> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                __FmtArg::Bool($($deref_token)? *self.0)
+            }
+        }
+
+        impl<'a> __FmtArgConv<&'a $($ref_token)? char> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                __FmtArg::Char($($deref_token)? *self.0)
+            }
+        }
+
+        impl<'a> __FmtArgConv<&'a $($ref_token)? &str> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                __FmtArg::Str($($deref_token)? *self.0)
+            }
+        }
+
+        impl<'a, const N: usize> __FmtArgConv<&'a $($ref_token)? ArrayStr<N>> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                __FmtArg::Str(self.0.as_str())
+            }
+        }
+    };
+}
+
+__ref_unref_impls! {}
+__ref_unref_impls! {& *}
+
+macro_rules! __int_impls {
+    ($($unsigned:ident $signed:ident)*) => {$(
+        __int_impls! {@unsigned [] $unsigned}
+        __int_impls! {@unsigned [& *] $unsigned}
+        __int_impls! {@signed [] $signed}
+        __int_impls! {@signed [& *] $signed}
+    )*};
+    (@unsigned [$($ref_token:tt $deref_token:tt)?] $unsigned:ident) => {
+        impl<'a> __FmtArgConv<&'a $($ref_token)? $unsigned> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                let n: $unsigned = $($deref_token)? *self.0;
+                __FmtArg::Int {
+                    negative: false,
+                    magnitude: n as u128,
+                    bits: $unsigned::BITS,
+                }
+            }
+        }
+    };
+    (@signed [$($ref_token:tt $deref_token:tt)?] $signed:ident) => {
+        impl<'a> __FmtArgConv<&'a $($ref_token)? $signed> {
+            pub const fn conv(self) -> __FmtArg<'a> {
+                let n: $signed = $($deref_token)? *self.0;
+                __FmtArg::Int {
+                    negative: n < 0,
+                    magnitude: n.unsigned_abs() as u128,
+                    bits: $signed::BITS,
+                }
+            }
+        }
+    };
+}
+
+__int_impls! {
+    u8 i8
+    u16 i16
+    u32 i32
+    u64 i64
+    u128 i128
+    usize isize
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum __FmtPiece {
+    Str(&'static str),
+    // the index of the argument, and how it's formatted
+    Arg(usize, __FmtSpec),
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct __FmtSpec {
+    pub fill: char,
+    pub align: __FmtAlign,
+    pub sign_plus: bool,
+    pub alternate: bool,
+    pub zero_pad: bool,
+    pub width: usize,
+    pub kind: __FmtKind,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum __FmtAlign {
+    Unspecified,
+    Left,
+    Center,
+    Right,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum __FmtKind {
+    Display,
+    Debug,
+    LowerHex,
+    UpperHex,
+    Binary,
+    Octal,
+}
+
+/// Formats `pieces` into an `ArrayStr<N>`, returning the length of the formatted string.
+///
+/// The string is only written when `N` is the length of the formatted string,
+/// so that this can be called with `N == 0` to compute the length.
+#[doc(hidden)]
+pub const fn __format<const N: usize>(
+    pieces: &[__FmtPiece],
+    args: &[__FmtArg<'_>],
+) -> (usize, ArrayStr<N>) {
+    let mut out = Writer::<N>::new();
+
+    crate::for_range! {i in 0..pieces.len() =>
+        match pieces[i] {
+            __FmtPiece::Str(s) => out.write_str(s),
+            __FmtPiece::Arg(arg_index, spec) => write_arg(&mut out, args[arg_index], spec),
+        }
+    }
+
+    if N != 0 && out.len != N {
+        panic!("bug: formatted string length changed between calls")
+    }
+
+    (out.len, ArrayStr(out.buffer))
+}
+
+const fn write_arg<const N: usize>(out: &mut Writer<N>, arg: __FmtArg<'_>, spec: __FmtSpec) {
+    if let __FmtArg::Int {
+        negative,
+        magnitude,
+        bits,
+    } = arg
+    {
+        write_int(out, negative, magnitude, bits, spec);
+        return;
+    }
+
+    if !matches!(spec.kind, __FmtKind::Display | __FmtKind::Debug) {
+        panic!("only integers can be formatted with the `x`, `X`, `b`, and `o` formatting types")
+    }
+
+    let is_debug = matches!(spec.kind, __FmtKind::Debug);
+
+    // like `core::fmt`, `Debug` formatting of chars and strings ignores the width
+    if is_debug && matches!(arg, __FmtArg::Char(_) | __FmtArg::Str(_)) {
+        write_non_int(out, arg, is_debug);
+        return;
+    }
+
+    let char_count = {
+        let mut counter = Writer::<0>::new();
+        write_non_int(&mut counter, arg, is_debug);
+        counter.char_count
+    };
+
+    let (pad_left, pad_right) = padding(spec, char_count, __FmtAlign::Left);
+
+    out.write_repeated(spec.fill, pad_left);
+    write_non_int(out, arg, is_debug);
+    out.write_repeated(spec.fill, pad_right);
+}
+
+const fn write_non_int<const N: usize>(out: &mut Writer<N>, arg: __FmtArg<'_>, is_debug: bool) {
+    match arg {
+        __FmtArg::Int { .. } => {}
+        __FmtArg::Bool(b) => out.write_str(if b { "true" } else { "false" }),
+        __FmtArg::Char(c) if is_debug => {
+            out.write_byte(b'\'');
+            write_escaped_char(out, c, '\'');
+            out.write_byte(b'\'');
+        }
+        __FmtArg::Char(c) => out.write_char(c),
+        __FmtArg::Str(s) if is_debug => {
+            out.write_byte(b'"');
+            let bytes = s.as_bytes();
+            crate::for_range! {i in 0..bytes.len() =>
+                // only ASCII chars are escaped, so the bytes of other chars are copied as is
+                if bytes[i].is_ascii() {
+                    write_escaped_char(out, bytes[i] as char, '"');
+                } else {
+                    out.write_byte(bytes[i]);
+                }
+            }
+            out.write_byte(b'"');
+        }
+        __FmtArg::Str(s) => out.write_str(s),
+    }
+}
+
+// escapes `c` the same way as `char::escape_debug`, only for ASCII chars.
+const fn write_escaped_char<const N: usize>(out: &mut Writer<N>, c: char, quote: char) {
+    let escape = match c {
+        '\0' => "\\0",
+        '\t' => "\\t",
+        '\r' => "\\r",
+        '\n' => "\\n",
+        '\\' => "\\\\",
+        '\'' if quote == '\'' => "\\'",
+        '"' if quote == '"' => "\\\"",
+        '\x00'..='\x1F' | '\x7F' => {
+            out.write_str("\\u{");
+            out.write_bytes(Digits::new(c as u128, 16, false).as_bytes());
+            out.write_byte(b'}');
+            return;
+        }
+        _ => {
+            out.write_char(c);
+            return;
+        }
+    };
+
+    out.write_str(escape);
+}
+
+const fn write_int<const N: usize>(
+    out: &mut Writer<N>,
+    negative: bool,
+    magnitude: u128,
+    bits: u32,
+    spec: __FmtSpec,
+) {
+    let (radix, uppercase, prefix) = match spec.kind {
+        __FmtKind::Display | __FmtKind::Debug => (10, false, ""),
+        __FmtKind::LowerHex => (16, false, "0x"),
+        __FmtKind::UpperHex => (16, true, "0x"),
+        __FmtKind::Binary => (2, false, "0b"),
+        __FmtKind::Octal => (8, false, "0o"),
+    };
+    let prefix = if spec.alternate { prefix } else { "" };
+
+    // non-decimal radixes print negative integers as two's complement
+    let (negative, magnitude) = if negative && radix != 10 {
+        let mask = if bits == 128 {
+            u128::MAX
+        } else {
+            (1 << bits) - 1
+        };
+        (false, magnitude.wrapping_neg() & mask)
+    } else {
+        (negative, magnitude)
+    };
+
+    let sign = if negative {
+        "-"
+    } else if spec.sign_plus {
+        "+"
+    } else {
+        ""
+    };
+
+    let digits = Digits::new(magnitude, radix, uppercase);
+    let digits = digits.as_bytes();
+    let char_count = sign.len() + prefix.len() + digits.len();
+
+    if spec.zero_pad {
+        out.write_str(sign);
+        out.write_str(prefix);
+        out.write_repeated('0', spec.width.saturating_sub(char_count));
+        out.write_bytes(digits);
+    } else {
+        let (pad_left, pad_right) = padding(spec, char_count, __FmtAlign::Right);
+
+        out.write_repeated(spec.fill, pad_left);
+        out.write_str(sign);
+        out.write_str(prefix);
+        out.write_bytes(digits);
+        out.write_repeated(spec.fill, pad_right);
+    }
+}
+
+// returns the amount of fill chars before and after the formatted value
+const fn padding(spec: __FmtSpec, char_count: usize, default: __FmtAlign) -> (usize, usize) {
+    let pad = spec.width.saturating_sub(char_count);
+
+    let align = if let __FmtAlign::Unspecified = spec.align {
+        default
+    } else {
+        spec.align
+    };
+
+    match align {
+        __FmtAlign::Unspecified | __FmtAlign::Left => (0, pad),
+        __FmtAlign::Center => (pad / 2, pad - pad / 2),
+        __FmtAlign::Right => (pad, 0),
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_format {
+    ($($args:tt)*) => {
+        $crate::__::__priv_string_format!{$crate $($args)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_format_inner {
+    (
+        args($(($arg_index:tt $arg:tt))*)
+        pieces($($piece:tt)*)
+    ) => {{
+        const fn __format_81608BFNA5<const N: $crate::__::usize>(
+        ) -> ($crate::__::usize, $crate::string::ArrayStr<N>) {
+            match ($(&$arg,)*) {
+                __args_81608BFNA5 => $crate::string::__format::<N>(
+                    &[$($crate::__string_format_inner!(@piece $piece),)*],
+                    &[$($crate::string::__FmtArgConv(__args_81608BFNA5.$arg_index).conv(),)*],
+                ),
+            }
+        }
+
+        // declared in a nested block so that the arguments can't refer to these constants
+        {
+            const LEN: $crate::__::usize = __format_81608BFNA5::<0>().0;
+
+            const CONC: &$crate::string::ArrayStr<LEN> = &__format_81608BFNA5::<LEN>().1;
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+    (@piece (str $str:literal)) => {
+        $crate::string::__FmtPiece::Str($str)
+    };
+    (@piece (
+        arg $index:literal $fill:literal $align:ident
+        $sign_plus:literal $alternate:literal $zero_pad:literal
+        $width:literal $kind:ident
+    )) => {
+        $crate::string::__FmtPiece::Arg($index, $crate::string::__FmtSpec {
+            fill: $fill,
+            align: $crate::string::__FmtAlign::$align,
+            sign_plus: $sign_plus,
+            alternate: $alternate,
+            zero_pad: $zero_pad,
+            width: $width,
+            kind: $crate::string::__FmtKind::$kind,
+        })
+    };
+}
+
+/// Formats constants into a `&'static str`, using `format!`-like syntax.
+///
+/// The arguments must be constants (like the arguments of [`str_concat`](crate::string::str_concat)),
+/// they can be any of these types (or references to them):
+/// - all the integer types
+/// - `bool`
+/// - `char`
+/// - `&str`
+/// - [`ArrayStr`]
+///
+/// # Syntax
+///
+/// This supports most of the syntax of [`core::format_args`]:
+///
+/// - `{}`: implicitly positional arguments.
+/// - `{0}`: explicitly positional arguments.
+/// - `{name}`: named arguments (passed as `name = expr`),
+///   or constants captured from the surrounding scope.
+/// - `{{` and `}}`: escaped braces.
+///
+/// Arguments can be followed by a `:` and this formatting spec,
+/// whose parts are all optional: `[[fill]align][+][#][0][width][type]`
+///
+/// - `fill`: the char that the argument is padded with, a space by default.
+/// - `align`: `<`, `^`, or `>`, for left, center, or right alignment.
+///   Integers are right aligned by default, everything else is left aligned by default.
+/// - `+`: prints a `+` before non-negative integers.
+/// - `#`: prefixes integers with `0x`, `0b`, or `0o`
+///   when they're formatted as hexadecimal, binary, or octal.
+/// - `0`: pads integers with zeroes after their sign and prefix.
+/// - `width`: the minimum amount of chars that the argument takes,
+///   padding it to that width when it's shorter.
+/// - `type`:
+///     - nothing: `Display` formatting.
+///     - `?`: `Debug` formatting, which quotes and escapes chars and strings
+///       (only ASCII control chars are escaped with the `\u{...}` syntax),
+///       ignoring the width for them.
+///     - `x`/`X`: lowercase/uppercase hexadecimal formatting of integers.
+///     - `b`: binary formatting of integers.
+///     - `o`: octal formatting of integers.
+///
+/// Negative integers are formatted as two's complement in hexadecimal, binary, and octal,
+/// like `core::fmt` does.
+///
+/// Precision (`.N`) and width arguments (`N$`) are not supported.
+///
+/// # Example
+///
+/// ### Basic
+///
+/// ```rust
+/// use konst::string;
+///
+/// const PORT: u16 = 80;
+/// const MIN: u16 = 1024;
+/// const MAX: u16 = 49151;
+///
+/// const MSG: &str = string::format!("port {PORT} out of range {MIN}..={MAX}");
+///
+/// assert_eq!(MSG, "port 80 out of range 1024..=49151");
+///
+/// ```
+///
+/// ### Formatting specs
+///
+/// ```rust
+/// use konst::string;
+///
+/// const ROW: &str = string::format!(
+///     "|{:<8}|{:>5}|{:#06x}|{:?}|{letter:^5}|",
+///     "name",
+///     -13i8,
+///     255u8,
+///     "quoted\n",
+///     letter = 'Ñ',
+/// );
+///
+/// assert_eq!(ROW, r#"|name    |  -13|0x00ff|"quoted\n"|  Ñ  |"#);
+///
+/// ```
+///
+#[doc(inline)]
+pub use crate::__string_format as format;
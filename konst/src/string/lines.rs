@@ -0,0 +1,191 @@
+use crate::{
+    iter::{ConstIntoIter, IsIteratorKind},
+    string::{self, str_from, str_up_to},
+};
+
+/// Const equivalent of [`str::lines`].
+///
+/// Lines are split at line endings, which are either newlines (`\n`)
+/// or a carriage return followed by a line feed (`\r\n`).
+///
+/// The final line ending is optional,
+/// a string that ends with a line ending returns the same lines as
+/// an otherwise identical string without a final line ending.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const TABLE: &str = "name,age\r\nfoo,3\nbar,5\n";
+///
+/// const LINES: [&str; 3] = collect_const!(&str => string::lines(TABLE));
+/// const REV: [&str; 3] = collect_const!(&str => string::lines(TABLE).rev());
+///
+/// assert_eq!(LINES, ["name,age", "foo,3", "bar,5"]);
+/// assert_eq!(REV, ["bar,5", "foo,3", "name,age"]);
+///
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn lines(this: &str) -> Lines<'_> {
+    Lines { this }
+}
+
+/// Const equivalent of `core::str::Lines<'a>`
+///
+/// This is constructed with [`lines`] like this:
+/// ```rust
+/// # let string = "";
+/// # let _ =
+/// konst::string::lines(string)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct Lines<'a> {
+    this: &'a str,
+}
+
+/// Const equivalent of `core::iter::Rev<core::str::Lines<'a>>`
+///
+/// This is constructed with [`lines`] like this:
+/// ```rust
+/// # let string = "";
+/// # let _ =
+/// konst::string::lines(string).rev()
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct RLines<'a> {
+    this: &'a str,
+}
+
+impl<'a> ConstIntoIter for Lines<'a> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a str;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a> ConstIntoIter for RLines<'a> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a str;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+// removes the `\r` before a `\n` line ending
+const fn strip_carriage_return(line: &str) -> &str {
+    match string::strip_suffix(line, '\r') {
+        Some(x) => x,
+        None => line,
+    }
+}
+
+macro_rules! lines_shared {
+    (is_forward = $is_forward:ident) => {
+        iterator_shared! {
+            is_forward = $is_forward,
+            item = &'a str,
+            iter_forward = Lines<'a>,
+            iter_reversed = RLines<'a>,
+            next(self) {
+                let this = self.this;
+                if this.is_empty() {
+                    return None;
+                }
+
+                Some(match string::find(this, '\n') {
+                    Some(pos) => {
+                        self.this = str_from(this, pos + 1);
+                        strip_carriage_return(str_up_to(this, pos))
+                    }
+                    None => {
+                        self.this = "";
+                        this
+                    }
+                })
+            },
+            next_back {
+                let this = self.this;
+                if this.is_empty() {
+                    return None;
+                }
+
+                let (body, has_line_ending) = match string::strip_suffix(this, '\n') {
+                    Some(body) => (body, true),
+                    None => (this, false),
+                };
+
+                let line = match string::rfind(body, '\n') {
+                    Some(pos) => {
+                        self.this = str_up_to(this, pos + 1);
+                        str_from(body, pos + 1)
+                    }
+                    None => {
+                        self.this = "";
+                        body
+                    }
+                };
+
+                Some(if has_line_ending {
+                    strip_carriage_return(line)
+                } else {
+                    line
+                })
+            },
+            fields = {this},
+        }
+    };
+}
+
+impl<'a> Lines<'a> {
+    lines_shared! {is_forward = true}
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::lines("foo\r\nbar\nbaz");
+    /// assert_eq!(iter.remainder(), "foo\r\nbar\nbaz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "foo");
+    /// assert_eq!(iter.remainder(), "bar\nbaz");
+    ///
+    /// assert_eq!(iter.next_back().unwrap(), "baz");
+    /// assert_eq!(iter.remainder(), "bar\n");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "bar");
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
+
+impl<'a> RLines<'a> {
+    lines_shared! {is_forward = false}
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::lines("foo\nbar\r\nbaz\n").rev();
+    /// assert_eq!(iter.remainder(), "foo\nbar\r\nbaz\n");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "baz");
+    /// assert_eq!(iter.remainder(), "foo\nbar\r\n");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "bar");
+    /// assert_eq!(iter.remainder(), "foo\n");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    iter::{ConstIntoIter, IsIteratorKind},
+    string::{
+        __find_next_char_boundary, __find_prev_char_boundary, Pattern, PatternNorm, str_from,
+        str_range, str_up_to,
+    },
+};
+
+/// Const equivalent of [`str::matches`].
+///
+/// Iterates over the non-overlapping matches of `pat` in `this`, from the start.
+///
+/// This takes [`Pattern`] implementors as the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, CharPredicate};
+/// use konst::iter::collect_const;
+///
+/// const NUMS: [&str; 4] =
+///     collect_const!(&str => string::matches("1-2, 3 and 4", CharPredicate::ASCII_DIGIT));
+///
+/// assert_eq!(NUMS, ["1", "2", "3", "4"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn matches<'a, 'p, P>(this: &'a str, pat: P) -> Matches<'a, 'p, P>
+where
+    P: Pattern<'p>,
+{
+    Matches {
+        inner: match_indices(this, pat),
+    }
+}
+
+/// Const equivalent of [`str::match_indices`].
+///
+/// Iterates over the non-overlapping matches of `pat` in `this`, from the start,
+/// along with the byte offset of each match.
+///
+/// This takes [`Pattern`] implementors as the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const FOUND: [(usize, &str); 3] =
+///     collect_const!((usize, &str) => string::match_indices("abcXXXabcYYYabc", "abc"));
+///
+/// assert_eq!(FOUND, [(0, "abc"), (6, "abc"), (12, "abc")]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn match_indices<'a, 'p, P>(this: &'a str, pat: P) -> MatchIndices<'a, 'p, P>
+where
+    P: Pattern<'p>,
+{
+    MatchIndices {
+        this,
+        offset: 0,
+        pattern: PatternNorm::new(pat),
+        finished: false,
+    }
+}
+
+/// Const equivalent of [`str::rmatch_indices`].
+///
+/// Iterates over the non-overlapping matches of `pat` in `this`, from the end,
+/// along with the byte offset of each match.
+///
+/// This takes [`Pattern`] implementors as the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const FOUND: [(usize, &str); 2] =
+///     collect_const!((usize, &str) => string::rmatch_indices("aaaaa", "aa"));
+///
+/// assert_eq!(FOUND, [(3, "aa"), (1, "aa")]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn rmatch_indices<'a, 'p, P>(this: &'a str, pat: P) -> RMatchIndices<'a, 'p, P>
+where
+    P: Pattern<'p>,
+{
+    RMatchIndices {
+        this,
+        pattern: PatternNorm::new(pat),
+        finished: false,
+    }
+}
+
+/// Const equivalent of `core::str::Matches<'a, P>`
+///
+/// This is constructed with [`matches()`] like this:
+/// ```rust
+/// # let string = "";
+/// # let pat = "";
+/// # let _ =
+/// konst::string::matches(string, pat)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct Matches<'a, 'p, P: Pattern<'p>> {
+    inner: MatchIndices<'a, 'p, P>,
+}
+impl<'a, 'p, P: Pattern<'p>> ConstIntoIter for Matches<'a, 'p, P> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a str;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, P: Pattern<'p>> Matches<'a, 'p, P> {
+    iterator_shared! {
+        is_forward = true,
+        item = &'a str,
+        iter_forward = Matches<'a, 'p, P>,
+        next(self) {
+            match self.inner.next() {
+                Some((_, matched)) => Some(matched),
+                None => None,
+            }
+        },
+        fields = {inner.copy()},
+    }
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::matches("foo-bar-baz", "-");
+    /// assert_eq!(iter.remainder(), "foo-bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "-");
+    /// assert_eq!(iter.remainder(), "bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "-");
+    /// assert_eq!(iter.remainder(), "baz");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.inner.remainder()
+    }
+}
+
+/// Const equivalent of `core::str::MatchIndices<'a, P>`
+///
+/// This is constructed with [`match_indices`] like this:
+/// ```rust
+/// # let string = "";
+/// # let pat = "";
+/// # let _ =
+/// konst::string::match_indices(string, pat)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct MatchIndices<'a, 'p, P: Pattern<'p>> {
+    this: &'a str,
+    // the offset of `this` in the original string
+    offset: usize,
+    pattern: PatternNorm<'p, P>,
+    finished: bool,
+}
+impl<'a, 'p, P: Pattern<'p>> ConstIntoIter for MatchIndices<'a, 'p, P> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = (usize, &'a str);
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, P: Pattern<'p>> MatchIndices<'a, 'p, P> {
+    iterator_shared! {
+        is_forward = true,
+        item = (usize, &'a str),
+        iter_forward = MatchIndices<'a, 'p, P>,
+        next(self) {
+            let this = self.this;
+            if self.finished {
+                return None;
+            }
+
+            let Some((start, end)) = self.pattern.find_in(this.as_bytes()) else {
+                self.this = "";
+                self.finished = true;
+                return None;
+            };
+
+            // empty patterns match at every char boundary,
+            // so the next search starts after the next char.
+            let next = if start != end {
+                end
+            } else if end == this.len() {
+                self.finished = true;
+                end
+            } else {
+                __find_next_char_boundary(this.as_bytes(), end)
+            };
+
+            let ret = (self.offset + start, str_range(this, start, end));
+            self.this = str_from(this, next);
+            self.offset += next;
+            Some(ret)
+        },
+        fields = {this, offset, pattern, finished},
+    }
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::match_indices("foo-bar-baz", "-");
+    /// assert_eq!(iter.remainder(), "foo-bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (3, "-"));
+    /// assert_eq!(iter.remainder(), "bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (7, "-"));
+    /// assert_eq!(iter.remainder(), "baz");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
+
+/// Const equivalent of `core::str::RMatchIndices<'a, P>`
+///
+/// This is constructed with [`rmatch_indices`] like this:
+/// ```rust
+/// # let string = "";
+/// # let pat = "";
+/// # let _ =
+/// konst::string::rmatch_indices(string, pat)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct RMatchIndices<'a, 'p, P: Pattern<'p>> {
+    // a prefix of the original string, so match offsets don't need adjusting
+    this: &'a str,
+    pattern: PatternNorm<'p, P>,
+    finished: bool,
+}
+impl<'a, 'p, P: Pattern<'p>> ConstIntoIter for RMatchIndices<'a, 'p, P> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = (usize, &'a str);
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, P: Pattern<'p>> RMatchIndices<'a, 'p, P> {
+    iterator_shared! {
+        is_forward = true,
+        item = (usize, &'a str),
+        iter_forward = RMatchIndices<'a, 'p, P>,
+        next(self) {
+            let this = self.this;
+            if self.finished {
+                return None;
+            }
+
+            let Some((start, end)) = self.pattern.rfind_in(this.as_bytes()) else {
+                self.this = "";
+                self.finished = true;
+                return None;
+            };
+
+            // empty patterns match at every char boundary,
+            // so the next search ends before the previous char.
+            let next = if start != end {
+                start
+            } else if start == 0 {
+                self.finished = true;
+                start
+            } else {
+                __find_prev_char_boundary(this.as_bytes(), start)
+            };
+
+            let ret = (start, str_range(this, start, end));
+            self.this = str_up_to(this, next);
+            Some(ret)
+        },
+        fields = {this, pattern, finished},
+    }
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::rmatch_indices("foo-bar-baz", "-");
+    /// assert_eq!(iter.remainder(), "foo-bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (7, "-"));
+    /// assert_eq!(iter.remainder(), "foo-bar");
+    ///
+    /// assert_eq!(iter.next().unwrap(), (3, "-"));
+    /// assert_eq!(iter.remainder(), "foo");
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        self.this
+    }
+}
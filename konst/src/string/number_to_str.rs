@@ -0,0 +1,773 @@
+use crate::{
+    polymorphism::{HasTypeWitness, MakeTypeWitness, TypeEq, TypeWitnessTypeArg},
+    string::{
+        ArrayStr,
+        writer::{Digits, Writer},
+    },
+};
+
+/// An integer type that can be converted to a string with [`int_to_array_str`].
+///
+/// This trait can only be implemented in the `konst` crate.
+pub trait Integer: HasTypeWitness<IntegerInput<Self>> + Copy + Sized {}
+
+/// A float type that can be converted to a string with [`float_to_array_str`].
+///
+/// This trait can only be implemented in the `konst` crate.
+pub trait Float: HasTypeWitness<FloatInput<Self>> + Copy + Sized {}
+
+macro_rules! declare_number_types {
+    (
+        $trait:ident, $input:ident, $input_inner:ident;
+        $(($variant:ident, $ty:ty))*
+    ) => (
+        #[doc = concat!("A type witness for [`", stringify!($trait), "`] types.")]
+        pub struct $input<T: $trait>($input_inner<T>);
+
+        enum $input_inner<T: $trait> {
+            $($variant {te: TypeEq<T, $ty>},)*
+        }
+
+        impl<T: $trait> TypeWitnessTypeArg for $input<T> {
+            type Arg = T;
+        }
+
+        $(
+            impl MakeTypeWitness for $input<$ty> {
+                const MAKE: Self = $input($input_inner::$variant{te: TypeEq::NEW});
+            }
+
+            impl $trait for $ty {}
+        )*
+    )
+}
+
+declare_number_types! {
+    Integer, IntegerInput, IntegerInputInner;
+    (U8, u8) (U16, u16) (U32, u32) (U64, u64) (U128, u128) (Usize, usize)
+    (I8, i8) (I16, i16) (I32, i32) (I64, i64) (I128, i128) (Isize, isize)
+}
+
+declare_number_types! {
+    Float, FloatInput, FloatInputInner;
+    (F32, f32) (F64, f64)
+}
+
+// converts the integer into its sign and magnitude
+const fn int_parts<I: Integer>(int: I) -> (bool, u128) {
+    macro_rules! unsigned {
+        ($te:expr) => {
+            (false, $te.to_right(int) as u128)
+        };
+    }
+    macro_rules! signed {
+        ($te:expr) => {{
+            let n = $te.to_right(int);
+            (n < 0, n.unsigned_abs() as u128)
+        }};
+    }
+
+    match I::WITNESS.0 {
+        IntegerInputInner::U8 { te } => unsigned!(te),
+        IntegerInputInner::U16 { te } => unsigned!(te),
+        IntegerInputInner::U32 { te } => unsigned!(te),
+        IntegerInputInner::U64 { te } => unsigned!(te),
+        IntegerInputInner::U128 { te } => unsigned!(te),
+        IntegerInputInner::Usize { te } => unsigned!(te),
+        IntegerInputInner::I8 { te } => signed!(te),
+        IntegerInputInner::I16 { te } => signed!(te),
+        IntegerInputInner::I32 { te } => signed!(te),
+        IntegerInputInner::I64 { te } => signed!(te),
+        IntegerInputInner::I128 { te } => signed!(te),
+        IntegerInputInner::Isize { te } => signed!(te),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Options for converting integers to strings, with [`int_to_array_str`].
+///
+/// The default options write integers in decimal, with no padding nor grouping.
+///
+/// Negative integers are written with a `-` sign in every radix.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, IntFmt};
+///
+/// assert_eq!(string::from_int!(-1234567), "-1234567");
+///
+/// const GROUPED: IntFmt = IntFmt::new().grouping(3, ',');
+/// assert_eq!(string::from_int!(1234567u32, GROUPED), "1,234,567");
+///
+/// const HEX: IntFmt = IntFmt::new().radix(16).uppercase(true).min_digits(8).grouping(4, '_');
+/// assert_eq!(string::from_int!(0xBEEFu16, HEX), "0000_BEEF");
+///
+/// const PADDED: IntFmt = IntFmt::new().plus_sign(true).pad(6, ' ');
+/// assert_eq!(string::from_int!(42u8, PADDED), "   +42");
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IntFmt {
+    radix: u32,
+    uppercase: bool,
+    plus_sign: bool,
+    min_digits: usize,
+    group_size: usize,
+    separator: char,
+    width: usize,
+    fill: char,
+}
+
+impl IntFmt {
+    /// Constructs the default options: decimal, with no padding nor grouping.
+    pub const fn new() -> Self {
+        Self {
+            radix: 10,
+            uppercase: false,
+            plus_sign: false,
+            min_digits: 0,
+            group_size: 0,
+            separator: ',',
+            width: 0,
+            fill: ' ',
+        }
+    }
+
+    /// Sets the radix that integers are written in, 10 by default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    #[track_caller]
+    pub const fn radix(mut self, radix: u32) -> Self {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the `2..=36` range"
+        );
+        self.radix = radix;
+        self
+    }
+
+    /// Sets whether digits above 9 are written as uppercase letters, `false` by default.
+    pub const fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets whether non-negative integers are prefixed with a `+`, `false` by default.
+    pub const fn plus_sign(mut self, plus_sign: bool) -> Self {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Pads the digits with leading zeroes, so that there's at least `min_digits` digits.
+    pub const fn min_digits(mut self, min_digits: usize) -> Self {
+        self.min_digits = min_digits;
+        self
+    }
+
+    /// Separates the digits into groups of `group_size` digits (starting from the right)
+    /// with `separator`.
+    ///
+    /// A `group_size` of 0 disables grouping, which is the default.
+    pub const fn grouping(mut self, group_size: usize, separator: char) -> Self {
+        self.group_size = group_size;
+        self.separator = separator;
+        self
+    }
+
+    /// Pads the start of the string with `fill`,
+    /// so that it's at least `width` chars long.
+    pub const fn pad(mut self, width: usize, fill: char) -> Self {
+        self.width = width;
+        self.fill = fill;
+        self
+    }
+}
+
+impl Default for IntFmt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the length of the string that [`int_to_array_str`] writes `int` into.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, IntFmt};
+///
+/// assert_eq!(string::int_to_str_len(100u8, IntFmt::new()), 3);
+/// assert_eq!(string::int_to_str_len(-100i64, IntFmt::new()), 4);
+/// assert_eq!(string::int_to_str_len(255u8, IntFmt::new().radix(2)), 8);
+///
+/// ```
+pub const fn int_to_str_len<I: Integer>(int: I, fmt: IntFmt) -> usize {
+    let mut out = Writer::<0>::new();
+    write_int(&mut out, int, fmt);
+    out.len
+}
+
+/// Writes `int` into an [`ArrayStr`], with the formatting options in `fmt`.
+///
+/// The length of the string can be computed with [`int_to_str_len`],
+/// the [`from_int`] macro does both steps to produce a `&'static str`.
+///
+/// # Panics
+///
+/// Panics if `N` is not the length of the string.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, ArrayStr, IntFmt};
+///
+/// const fn hex_byte(byte: u8) -> ArrayStr<4> {
+///     string::int_to_array_str(byte, IntFmt::new().radix(16).min_digits(2).pad(4, ' '))
+/// }
+///
+/// assert_eq!(hex_byte(10).as_str(), "  0a");
+/// assert_eq!(hex_byte(255).as_str(), "  ff");
+///
+/// ```
+#[track_caller]
+pub const fn int_to_array_str<I: Integer, const N: usize>(int: I, fmt: IntFmt) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    write_int(&mut out, int, fmt);
+    out.into_array_str()
+}
+
+const fn write_int<I: Integer, const N: usize>(out: &mut Writer<N>, int: I, fmt: IntFmt) {
+    let (negative, magnitude) = int_parts(int);
+
+    let sign = if negative {
+        "-"
+    } else if fmt.plus_sign {
+        "+"
+    } else {
+        ""
+    };
+
+    let mut counter = Writer::<0>::new();
+    write_digits(&mut counter, magnitude, fmt);
+
+    out.write_repeated(
+        fmt.fill,
+        fmt.width.saturating_sub(sign.len() + counter.char_count),
+    );
+    out.write_str(sign);
+    write_digits(out, magnitude, fmt);
+}
+
+// writes the digits of `magnitude`, including the padding zeroes and group separators
+const fn write_digits<const N: usize>(out: &mut Writer<N>, magnitude: u128, fmt: IntFmt) {
+    let digits = Digits::new(magnitude, fmt.radix, fmt.uppercase);
+    let digits = digits.as_bytes();
+
+    let zeroes = fmt.min_digits.saturating_sub(digits.len());
+    let total = zeroes + digits.len();
+
+    crate::for_range! {i in 0..total =>
+        if i != 0 && fmt.group_size != 0 && (total - i).is_multiple_of(fmt.group_size) {
+            out.write_char(fmt.separator);
+        }
+
+        if i < zeroes {
+            out.write_byte(b'0');
+        } else {
+            out.write_byte(digits[i - zeroes]);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the length of the string that [`float_to_array_str`] writes `float` into.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// assert_eq!(string::float_to_str_len(0.5f32), 3);
+/// assert_eq!(string::float_to_str_len(-1.25f64), 5);
+/// assert_eq!(string::float_to_str_len(1e10f64), 11);
+///
+/// ```
+pub const fn float_to_str_len<F: Float>(float: F) -> usize {
+    let mut out = Writer::<0>::new();
+    write_float(&mut out, float);
+    out.len
+}
+
+/// Writes `float` into an [`ArrayStr`],
+/// using the shortest decimal representation that parses back into the same float.
+///
+/// This writes the same string as the `Display` impls of `f32` and `f64`,
+/// which write the number without an exponent, e.g.: `1e20` is written as
+/// `"100000000000000000000"`.
+///
+/// The length of the string can be computed with [`float_to_str_len`],
+/// the [`from_float`] macro does both steps to produce a `&'static str`.
+///
+/// # Panics
+///
+/// Panics if `N` is not the length of the string.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, ArrayStr};
+///
+/// const TENTH: ArrayStr<3> = string::float_to_array_str(0.1f32);
+/// assert_eq!(TENTH.as_str(), "0.1");
+///
+/// const NEG: ArrayStr<7> = string::float_to_array_str(-1.5e-3f64);
+/// assert_eq!(NEG.as_str(), "-0.0015");
+///
+/// ```
+#[track_caller]
+pub const fn float_to_array_str<F: Float, const N: usize>(float: F) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    write_float(&mut out, float);
+    out.into_array_str()
+}
+
+// the decomposed float: `(-1)^negative * mantissa * 2^exponent`
+struct FloatParts {
+    negative: bool,
+    mantissa: u64,
+    exponent: i32,
+    // the mantissa of the smallest normal float, `2^(precision - 1)`
+    min_normal_mantissa: u64,
+    // the exponent of subnormal floats
+    min_exponent: i32,
+    kind: FloatKind,
+}
+
+enum FloatKind {
+    Finite,
+    Infinite,
+    Nan,
+}
+
+const fn float_parts<F: Float>(float: F) -> FloatParts {
+    macro_rules! decompose {
+        ($bits:expr, $bits_ty:ty, $mantissa_bits:expr, $exponent_bits:expr) => {{
+            const MANTISSA_BITS: u32 = $mantissa_bits;
+            const MAX_BIASED_EXP: u64 = (1 << $exponent_bits) - 1;
+            const BIAS: i32 = (MAX_BIASED_EXP >> 1) as i32 + MANTISSA_BITS as i32;
+
+            let bits: $bits_ty = $bits;
+            let fraction = (bits & ((1 << MANTISSA_BITS) - 1)) as u64;
+            let biased_exp = ((bits >> MANTISSA_BITS) as u64) & MAX_BIASED_EXP;
+            let min_normal_mantissa = 1u64 << MANTISSA_BITS;
+
+            let (mantissa, exponent) = if biased_exp == 0 {
+                (fraction, 1 - BIAS)
+            } else {
+                (fraction | min_normal_mantissa, biased_exp as i32 - BIAS)
+            };
+
+            FloatParts {
+                negative: bits >> (<$bits_ty>::BITS - 1) == 1,
+                mantissa,
+                exponent,
+                min_normal_mantissa,
+                min_exponent: 1 - BIAS,
+                kind: if biased_exp != MAX_BIASED_EXP {
+                    FloatKind::Finite
+                } else if fraction == 0 {
+                    FloatKind::Infinite
+                } else {
+                    FloatKind::Nan
+                },
+            }
+        }};
+    }
+
+    match F::WITNESS.0 {
+        FloatInputInner::F32 { te } => decompose!(te.to_right(float).to_bits(), u32, 23, 8),
+        FloatInputInner::F64 { te } => decompose!(te.to_right(float).to_bits(), u64, 52, 11),
+    }
+}
+
+const fn write_float<F: Float, const N: usize>(out: &mut Writer<N>, float: F) {
+    let parts = float_parts(float);
+
+    match parts.kind {
+        FloatKind::Nan => return out.write_str("NaN"),
+        FloatKind::Infinite | FloatKind::Finite if parts.negative => out.write_byte(b'-'),
+        _ => {}
+    }
+
+    if let FloatKind::Infinite = parts.kind {
+        return out.write_str("inf");
+    } else if parts.mantissa == 0 {
+        return out.write_byte(b'0');
+    }
+
+    // enough digits for the shortest representation of any f64
+    let mut digits = [0u8; 17];
+    let (digit_count, exponent) = shortest_digits(&parts, &mut digits);
+    let digits = crate::slice::slice_up_to(&digits, digit_count);
+
+    // the float is `0.{digits} * 10^exponent`
+    if exponent <= 0 {
+        out.write_str("0.");
+        out.write_repeated('0', exponent.unsigned_abs() as usize);
+        write_ascii_digits(out, digits);
+    } else if (exponent as usize) < digit_count {
+        let (int, fract) = digits.split_at(exponent as usize);
+        write_ascii_digits(out, int);
+        out.write_byte(b'.');
+        write_ascii_digits(out, fract);
+    } else {
+        write_ascii_digits(out, digits);
+        out.write_repeated('0', exponent as usize - digit_count);
+    }
+}
+
+const fn write_ascii_digits<const N: usize>(out: &mut Writer<N>, digits: &[u8]) {
+    crate::for_range! {i in 0..digits.len() =>
+        out.write_byte(b'0' + digits[i]);
+    }
+}
+
+// Computes the shortest digits that round-trip to the float,
+// using the "free-format" algorithm from
+// "Printing Floating-Point Numbers Quickly and Accurately" by Burger and Dybvig.
+//
+// Returns the amount of digits written into `digits`,
+// and the exponent `k` such that the float is `0.{digits} * 10^k`.
+const fn shortest_digits(parts: &FloatParts, digits: &mut [u8; 17]) -> (usize, i32) {
+    let FloatParts {
+        mantissa,
+        exponent,
+        min_normal_mantissa,
+        min_exponent,
+        ..
+    } = *parts;
+
+    // whether the boundaries of the rounding range round to this float
+    let inclusive = mantissa % 2 == 0;
+    let is_boundary = mantissa == min_normal_mantissa && exponent != min_exponent;
+
+    // the float is `r / s`, and the distances to the halfway points
+    // between it and the adjacent floats are `m_plus / s` and `m_minus / s`
+    let (mut r, mut s, mut m_plus, mut m_minus);
+    if exponent >= 0 {
+        let exp = exponent as u32;
+        m_minus = Big::from_u64(1).mul_pow2(exp);
+        if is_boundary {
+            r = Big::from_u64(mantissa).mul_pow2(exp + 2);
+            s = Big::from_u64(4);
+            m_plus = m_minus.mul_pow2(1);
+        } else {
+            r = Big::from_u64(mantissa).mul_pow2(exp + 1);
+            s = Big::from_u64(2);
+            m_plus = m_minus;
+        }
+    } else {
+        let exp = exponent.unsigned_abs();
+        m_minus = Big::from_u64(1);
+        if is_boundary {
+            r = Big::from_u64(mantissa).mul_pow2(2);
+            s = Big::from_u64(1).mul_pow2(exp + 2);
+            m_plus = Big::from_u64(2);
+        } else {
+            r = Big::from_u64(mantissa).mul_pow2(1);
+            s = Big::from_u64(1).mul_pow2(exp + 1);
+            m_plus = m_minus;
+        }
+    }
+
+    // estimates `k = ceil(log10(float))`, fixed up below
+    let log2 = exponent + (u64::BITS - mantissa.leading_zeros()) as i32 - 1;
+    let mut k = ((log2 as i64 * 1233) >> 12) as i32 + 1;
+
+    if k >= 0 {
+        s = s.mul_pow10(k as u32);
+    } else {
+        let k = k.unsigned_abs();
+        r = r.mul_pow10(k);
+        m_plus = m_plus.mul_pow10(k);
+        m_minus = m_minus.mul_pow10(k);
+    }
+
+    // whether `high` is above the upper boundary of the digits that can be generated
+    const fn exceeds(high: &Big, s: &Big, inclusive: bool) -> bool {
+        match Big::cmp(high, s) {
+            1 => true,
+            0 => inclusive,
+            _ => false,
+        }
+    }
+
+    while exceeds(&r.add(&m_plus), &s, inclusive) {
+        s = s.mul_small(10);
+        k += 1;
+    }
+    while !exceeds(&r.add(&m_plus).mul_small(10), &s, inclusive) {
+        r = r.mul_small(10);
+        m_plus = m_plus.mul_small(10);
+        m_minus = m_minus.mul_small(10);
+        k -= 1;
+    }
+
+    let mut len = 0;
+    loop {
+        r = r.mul_small(10);
+        m_plus = m_plus.mul_small(10);
+        m_minus = m_minus.mul_small(10);
+
+        let mut digit = 0u8;
+        while Big::cmp(&r, &s) >= 0 {
+            r = r.sub(&s);
+            digit += 1;
+        }
+
+        let low = match Big::cmp(&r, &m_minus) {
+            -1 => true,
+            0 => inclusive,
+            _ => false,
+        };
+        let high = exceeds(&r.add(&m_plus), &s, inclusive);
+
+        if !low && !high {
+            digits[len] = digit;
+            len += 1;
+            continue;
+        }
+
+        // rounds up if only the upper boundary was reached,
+        // or if both were reached and `r` is at least halfway to the next digit
+        if high && (!low || Big::cmp(&r.mul_pow2(1), &s) >= 0) {
+            digit += 1;
+        }
+
+        digits[len] = digit;
+        len += 1;
+        break;
+    }
+
+    // propagates the carry from rounding up a 9 digit
+    let mut i = len - 1;
+    while digits[i] == 10 {
+        if i == 0 {
+            digits[0] = 1;
+            return (1, k + 1);
+        }
+        len -= 1;
+        i -= 1;
+        digits[i] += 1;
+    }
+
+    (len, k)
+}
+
+const LIMBS: usize = 40;
+
+// An unsigned integer big enough for the computations in `shortest_digits`,
+// stored as little-endian 32 bit limbs.
+#[derive(Copy, Clone)]
+struct Big {
+    limbs: [u32; LIMBS],
+}
+
+impl Big {
+    const fn from_u64(n: u64) -> Self {
+        let mut limbs = [0u32; LIMBS];
+        limbs[0] = n as u32;
+        limbs[1] = (n >> 32) as u32;
+        Self { limbs }
+    }
+
+    const fn mul_small(mut self, factor: u32) -> Self {
+        let mut carry = 0u64;
+        crate::for_range! {i in 0..LIMBS =>
+            let prod = self.limbs[i] as u64 * factor as u64 + carry;
+            self.limbs[i] = prod as u32;
+            carry = prod >> 32;
+        }
+        assert!(carry == 0, "bug: Big overflowed");
+        self
+    }
+
+    const fn mul_pow10(mut self, mut pow: u32) -> Self {
+        while pow >= 9 {
+            self = self.mul_small(1_000_000_000);
+            pow -= 9;
+        }
+        self.mul_small(10u32.pow(pow))
+    }
+
+    const fn mul_pow2(self, pow: u32) -> Self {
+        let limb_shift = (pow / 32) as usize;
+        let bit_shift = pow % 32;
+        let mut limbs = [0u32; LIMBS];
+
+        crate::for_range! {i in limb_shift..LIMBS =>
+            let src = i - limb_shift;
+            limbs[i] = self.limbs[src] << bit_shift;
+            if bit_shift != 0 && src != 0 {
+                limbs[i] |= self.limbs[src - 1] >> (32 - bit_shift);
+            }
+        }
+
+        Self { limbs }
+    }
+
+    const fn add(&self, other: &Big) -> Self {
+        let mut limbs = [0u32; LIMBS];
+        let mut carry = false;
+        crate::for_range! {i in 0..LIMBS =>
+            let (sum, c0) = self.limbs[i].overflowing_add(other.limbs[i]);
+            let (sum, c1) = sum.overflowing_add(carry as u32);
+            limbs[i] = sum;
+            carry = c0 | c1;
+        }
+        assert!(!carry, "bug: Big overflowed");
+        Self { limbs }
+    }
+
+    // requires `self >= other`
+    const fn sub(&self, other: &Big) -> Self {
+        let mut limbs = [0u32; LIMBS];
+        let mut borrow = false;
+        crate::for_range! {i in 0..LIMBS =>
+            let (diff, b0) = self.limbs[i].overflowing_sub(other.limbs[i]);
+            let (diff, b1) = diff.overflowing_sub(borrow as u32);
+            limbs[i] = diff;
+            borrow = b0 | b1;
+        }
+        Self { limbs }
+    }
+
+    // returns -1, 0, or 1 for less, equal, or greater
+    const fn cmp(&self, other: &Big) -> i8 {
+        let mut i = LIMBS;
+        while i != 0 {
+            i -= 1;
+            if self.limbs[i] != other.limbs[i] {
+                return if self.limbs[i] < other.limbs[i] {
+                    -1
+                } else {
+                    1
+                };
+            }
+        }
+        0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_from_int {
+    ($int:expr $(,)?) => {
+        $crate::string::from_int!($int, $crate::string::IntFmt::new())
+    };
+    ($int:expr, $fmt:expr $(,)?) => {{
+        // the arguments are used in functions declared outside the block with
+        // the `LEN`/`CONC`/`STR` constants, so that they can't refer to those constants.
+        const fn __len_81608BFNA5() -> $crate::__::usize {
+            $crate::string::int_to_str_len($int, $fmt)
+        }
+
+        const fn __conc_81608BFNA5<const N: $crate::__::usize>() -> $crate::string::ArrayStr<N> {
+            $crate::string::int_to_array_str($int, $fmt)
+        }
+
+        {
+            const LEN: $crate::__::usize = __len_81608BFNA5();
+
+            const CONC: &$crate::string::ArrayStr<LEN> = &__conc_81608BFNA5::<LEN>();
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_from_float {
+    ($float:expr $(,)?) => {{
+        // the argument is used in functions declared outside the block with
+        // the `LEN`/`CONC`/`STR` constants, so that it can't refer to those constants.
+        const fn __len_81608BFNA5() -> $crate::__::usize {
+            $crate::string::float_to_str_len($float)
+        }
+
+        const fn __conc_81608BFNA5<const N: $crate::__::usize>() -> $crate::string::ArrayStr<N> {
+            $crate::string::float_to_array_str($float)
+        }
+
+        {
+            const LEN: $crate::__::usize = __len_81608BFNA5();
+
+            const CONC: &$crate::string::ArrayStr<LEN> = &__conc_81608BFNA5::<LEN>();
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+/// Converts a constant integer to a `&'static str`,
+/// optionally taking an [`IntFmt`] with formatting options.
+///
+/// This macro takes these arguments:
+/// - an integer constant of any [`Integer`] type.
+/// - an optional [`IntFmt`] constant, which defaults to `IntFmt::new()`.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{self, IntFmt};
+///
+/// const SIZE: usize = 64;
+///
+/// const NAME: &str = string::str_concat!(&["buffer_", string::from_int!(SIZE)]);
+/// assert_eq!(NAME, "buffer_64");
+///
+/// const MASK: &str = string::from_int!(0b1010u8, IntFmt::new().radix(2).min_digits(8));
+/// assert_eq!(MASK, "00001010");
+///
+/// assert_eq!(string::from_int!(i128::MIN), i128::MIN.to_string());
+///
+/// ```
+#[doc(inline)]
+pub use __string_from_int as from_int;
+
+/// Converts a constant float to a `&'static str`,
+/// using the shortest decimal representation that parses back into the same float.
+///
+/// This writes the same string as the `Display` impls of `f32` and `f64`,
+/// [`float_to_array_str`] has more details.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const PI: &str = string::from_float!(core::f64::consts::PI);
+/// assert_eq!(PI, "3.141592653589793");
+///
+/// assert_eq!(string::from_float!(0.1f32 + 0.2), "0.3");
+/// assert_eq!(string::from_float!(0.1f64 + 0.2), "0.30000000000000004");
+/// assert_eq!(string::from_float!(-2.5e-5), "-0.000025");
+/// assert_eq!(string::from_float!(f32::MAX), "340282350000000000000000000000000000000");
+/// assert_eq!(string::from_float!(f64::NAN), "NaN");
+/// assert_eq!(string::from_float!(f64::NEG_INFINITY), "-inf");
+///
+/// ```
+#[doc(inline)]
+pub use __string_from_float as from_float;
@@ -1,90 +1,247 @@
 use crate::{
     chr,
     polymorphism::{HasTypeWitness, MakeTypeWitness, TypeEq, TypeWitnessTypeArg},
+    string::{__find_prev_char_boundary, CharPredicate, next_char},
 };
 
 /// A string pattern.
 ///
 /// Types that implement this trait can be used to search into a string.
 ///
+/// These are the implementors of this trait:
+/// - `&str`: matches the string.
+/// - `char`: matches the char.
+/// - `&[char]`, `[char; N]`, `&[char; N]`: match any char in the slice/array.
+/// - [`CharPredicate`]: matches any char that the predicate returns true for.
+///
 /// This trait can only be implemented in the `konst` crate.
 ///
+/// # Example
+///
+#[cfg_attr(feature = "iter", doc = "```rust")]
+#[cfg_attr(not(feature = "iter"), doc = "```ignore")]
+/// use konst::iter::collect_const;
+/// use konst::string::{self, CharPredicate};
+///
+/// const WORDS: [&str; 4] = collect_const!(&str => string::split("foo bar,baz\tqux", [' ', '\t', ',']));
+/// assert_eq!(WORDS, ["foo", "bar", "baz", "qux"]);
+///
+/// const DIGITS: &[char] = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+/// assert_eq!(string::trim_start_matches("0012300", DIGITS), "");
+/// assert_eq!(string::find("foo12", DIGITS), Some(3));
+///
+/// assert_eq!(string::find("foo12", CharPredicate::ASCII_DIGIT), Some(3));
+///
+/// ```
+///
 pub trait Pattern<'a>: HasTypeWitness<PatternInput<'a, Self>> + Copy + Sized {}
 
-macro_rules! declare_patterns {
-    ($((
-        $variant:ident, $ty:ty, $normalized:ty, $index:expr,
-        |$param:tt| $normalizer:expr
-    ),)*) => (
-        pub struct PatternInput<'a, P: Pattern<'a>>(PatternInputInner<'a, P>);
-
-        enum PatternInputInner<'a, P: Pattern<'a>> {
-            $(
-                $variant {te: TypeEq<P, $ty>},
-            )*
-        }
+pub struct PatternInput<'a, P: Pattern<'a>>(PatternInputInner<'a, P>);
+
+enum PatternInputInner<'a, P: Pattern<'a>> {
+    Str { te: TypeEq<P, &'a str> },
+    Char { te: TypeEq<P, char> },
+    CharSlice { te: TypeEq<P, &'a [char]> },
+    // `P` is a `[char; len]`
+    CharArray { len: usize },
+    // `P` is a `&'a [char; len]`
+    CharArrayRef { len: usize },
+    Predicate { te: TypeEq<P, CharPredicate> },
+}
 
-        impl<'a, Arg> TypeWitnessTypeArg for PatternInput<'a, Arg>
-        where
-            Arg: Pattern<'a>
-        {
-            type Arg = Arg;
+impl<'a, Arg> TypeWitnessTypeArg for PatternInput<'a, Arg>
+where
+    Arg: Pattern<'a>,
+{
+    type Arg = Arg;
+}
+
+macro_rules! impl_pattern {
+    ($(
+        impl[$($impl:tt)*] $ty:ty => $variant:ident $fields:tt
+    )*) => ($(
+        impl<'a, $($impl)*> MakeTypeWitness for PatternInput<'a, $ty> {
+            const MAKE: Self = PatternInput(PatternInputInner::$variant $fields);
         }
 
-        $(
-            impl<'a> MakeTypeWitness for PatternInput<'a, $ty> {
-                const MAKE: Self =
-                    PatternInput(PatternInputInner::$variant{
-                        te: TypeEq::NEW,
-                    });
+        impl<'a, $($impl)*> Pattern<'a> for $ty {}
+    )*)
+}
+
+impl_pattern! {
+    impl[] &'a str => Str {te: TypeEq::NEW}
+    impl[] char => Char {te: TypeEq::NEW}
+    impl[] &'a [char] => CharSlice {te: TypeEq::NEW}
+    impl[const N: usize] [char; N] => CharArray {len: N}
+    impl[const N: usize] &'a [char; N] => CharArrayRef {len: N}
+    impl[] CharPredicate => Predicate {te: TypeEq::NEW}
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct PatternNorm<'a, P: Pattern<'a>> {
+    pattern: P,
+    kind: PatternKind<'a>,
+}
+
+#[derive(Copy, Clone)]
+enum PatternKind<'a> {
+    Str(&'a str),
+    Char(chr::Utf8Encoded),
+    CharSlice(&'a [char]),
+    // the pattern is a `[char; N]` of this length
+    CharArray(usize),
+    Predicate(CharPredicate),
+}
+
+impl<'a, P: Pattern<'a>> PatternNorm<'a, P> {
+    pub(crate) const fn new(pattern: P) -> Self {
+        let kind = match P::WITNESS.0 {
+            PatternInputInner::Str { te } => PatternKind::Str(te.to_right(pattern)),
+            PatternInputInner::Char { te } => {
+                PatternKind::Char(chr::encode_utf8(te.to_right(pattern)))
+            }
+            PatternInputInner::CharSlice { te } => PatternKind::CharSlice(te.to_right(pattern)),
+            PatternInputInner::CharArray { len } => PatternKind::CharArray(len),
+            PatternInputInner::CharArrayRef { len } => {
+                // SAFETY: the `CharArrayRef` variant is only constructed for `&'a [char; len]`
+                let array = unsafe { *(&pattern as *const P as *const *const char) };
+                // SAFETY: `array` points to a `[char; len]` that lives for `'a`
+                PatternKind::CharSlice(unsafe { core::slice::from_raw_parts(array, len) })
             }
+            PatternInputInner::Predicate { te } => PatternKind::Predicate(te.to_right(pattern)),
+        };
+
+        Self { pattern, kind }
+    }
 
-            impl<'a> Pattern<'a> for $ty {}
-        )*
-
-        #[derive(Copy, Clone)]
-        pub(crate) enum PatternNorm<'a, P: Pattern<'a>> {
-            $(
-                $variant{
-                    val: $normalized,
-                    te: TypeEq<P, $ty>,
-                },
-            )*
+    // whether the pattern is an empty string, which matches at every char boundary
+    pub(crate) const fn is_empty(&self) -> bool {
+        matches!(self.kind, PatternKind::Str(s) if s.is_empty())
+    }
+
+    // the string that the pattern matches, if it's a string or char pattern
+    pub(crate) const fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.kind {
+            PatternKind::Str(s) => Some(s.as_bytes()),
+            PatternKind::Char(c) => Some(c.as_bytes()),
+            _ => None,
         }
+    }
 
-        impl<'a, P: Pattern<'a>> PatternNorm<'a, P> {
-            pub(crate) const fn new(pattern: P) -> Self
-            where
-                P: Pattern<'a>
-            {
-                match P::WITNESS.0 {
-                    $(
-                        PatternInputInner::$variant{te} => {
-                            let $param = te.to_right(pattern);
-                            PatternNorm::$variant{val: $normalizer, te}
-                        }
-                    )*
+    // whether `c` is matched by a char set/predicate pattern
+    const fn matches_char(&self, c: u32) -> bool {
+        let chars = match self.kind {
+            // string and char patterns are matched with `as_bytes`
+            PatternKind::Str(_) | PatternKind::Char(_) => return false,
+            PatternKind::CharSlice(chars) => chars,
+            PatternKind::CharArray(len) => {
+                // SAFETY: the `CharArray` kind is only constructed when `P` is a `[char; len]`
+                unsafe {
+                    core::slice::from_raw_parts(&self.pattern as *const P as *const char, len)
                 }
             }
+            PatternKind::Predicate(pred) => {
+                return match char::from_u32(c) {
+                    Some(c) => pred.matches(c),
+                    None => false,
+                };
+            }
+        };
 
-            pub(crate) const fn as_str(&self) -> &str {
-                match self {
-                    PatternNorm::Str{val, te} => te.reachability_hint(*val),
-                    PatternNorm::Char{val, te} => te.reachability_hint(val.as_str()),
-                }
+        crate::for_range! {i in 0..chars.len() =>
+            if chars[i] as u32 == c {
+                return true;
             }
+        }
 
-            pub(crate) const fn as_bytes(&self) -> &[u8] {
-                match self {
-                    PatternNorm::Str{val, te} => te.reachability_hint(val.as_bytes()),
-                    PatternNorm::Char{val, te} => te.reachability_hint(val.as_bytes()),
-                }
+        false
+    }
+
+    // the `start..end` range of the first match in `string`
+    pub(crate) const fn find_in(&self, string: &[u8]) -> Option<(usize, usize)> {
+        if let Some(pattern) = self.as_bytes() {
+            return match crate::slice::__bytes_find(string, pattern) {
+                Some(pos) => Some((pos, pos + pattern.len())),
+                None => None,
+            };
+        }
+
+        let mut i = 0;
+        while i < string.len() {
+            let (c, next) = next_char(string, i);
+            if self.matches_char(c) {
+                return Some((i, next));
             }
+            i = next;
+        }
+
+        None
+    }
+
+    // the `start..end` range of the last match in `string`
+    pub(crate) const fn rfind_in(&self, string: &[u8]) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return Some((string.len(), string.len()));
+        } else if let Some(pattern) = self.as_bytes() {
+            return match crate::slice::__bytes_rfind(string, pattern) {
+                Some(pos) => Some((pos, pos + pattern.len())),
+                None => None,
+            };
+        }
+
+        let mut end = string.len();
+        while end != 0 {
+            let start = __find_prev_char_boundary(string, end);
+            if self.matches_char(next_char(string, start).0) {
+                return Some((start, end));
+            }
+            end = start;
         }
-    )
-}
 
-declare_patterns! {
-    (Str, &'a str, &'a str, 0, |x| x),
-    (Char, char, chr::Utf8Encoded, 1, |char| chr::encode_utf8(char)),
+        None
+    }
+
+    // the length of the match at the start of `string`, if any
+    pub(crate) const fn prefix_len(&self, string: &[u8]) -> Option<usize> {
+        if let Some(pattern) = self.as_bytes() {
+            return if crate::slice::__bytes_start_with(string, pattern) {
+                Some(pattern.len())
+            } else {
+                None
+            };
+        }
+
+        if string.is_empty() {
+            return None;
+        }
+
+        let (c, len) = next_char(string, 0);
+        if self.matches_char(c) {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    // the length of the match at the end of `string`, if any
+    pub(crate) const fn suffix_len(&self, string: &[u8]) -> Option<usize> {
+        if let Some(pattern) = self.as_bytes() {
+            return if crate::slice::__bytes_end_with(string, pattern) {
+                Some(pattern.len())
+            } else {
+                None
+            };
+        }
+
+        if string.is_empty() {
+            return None;
+        }
+
+        let start = __find_prev_char_boundary(string, string.len());
+        if self.matches_char(next_char(string, start).0) {
+            Some(string.len() - start)
+        } else {
+            None
+        }
+    }
 }
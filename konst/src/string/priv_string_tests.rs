@@ -1,5 +1,5 @@
 #[cfg(feature = "iter")]
-use crate::string::chars_methods::{string_to_char, string_to_usv};
+use crate::string::{char_bytes_to_usv, chars_methods::string_to_char};
 
 // index: 00 char: '🧡' len_utf8: 4
 // index: 04 char: '🧠' len_utf8: 4
@@ -52,7 +52,7 @@ fn str_to_codepoint_test() {
         let mut arr = [0u8; 8];
         let string = c.encode_utf8(&mut arr);
 
-        let found = core::char::from_u32(string_to_usv(string)).unwrap();
+        let found = core::char::from_u32(char_bytes_to_usv(string.as_bytes())).unwrap();
         assert_eq!(found, c, "{c:?}");
     }
 }
@@ -0,0 +1,220 @@
+use crate::{
+    iter::{ConstIntoIter, IsIteratorKind},
+    string::{self, Pattern, RSplit, Split},
+};
+
+/// Const equivalent of [`str::splitn`].
+///
+/// This does the same as [`split`](crate::string::split),
+/// except that it returns at most `n` strings,
+/// the last one being the rest of the string (which can contain the delimiter).
+///
+/// This takes [`Pattern`] implementors as the delimiter.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const STRS: [&str; 2] = collect_const!(&str =>
+///     string::splitn("key=value=with=equals", 2, '=')
+/// );
+///
+/// assert_eq!(STRS, ["key", "value=with=equals"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn splitn<'a, 'p, P>(this: &'a str, n: usize, delim: P) -> SplitN<'a, 'p, P>
+where
+    P: Pattern<'p>,
+{
+    SplitN {
+        inner: string::split(this, delim),
+        count: n,
+    }
+}
+
+/// Const equivalent of [`str::rsplitn`].
+///
+/// This does the same as [`rsplit`](crate::string::rsplit),
+/// except that it returns at most `n` strings,
+/// the last one being the start of the string (which can contain the delimiter).
+///
+/// This takes [`Pattern`] implementors as the delimiter.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const STRS: [&str; 2] = collect_const!(&str =>
+///     string::rsplitn("path/to/file.txt", 2, "/")
+/// );
+///
+/// assert_eq!(STRS, ["file.txt", "path/to"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn rsplitn<'a, 'p, P>(this: &'a str, n: usize, delim: P) -> RSplitN<'a, 'p, P>
+where
+    P: Pattern<'p>,
+{
+    RSplitN {
+        inner: string::rsplit(this, delim),
+        count: n,
+    }
+}
+
+/// Const equivalent of `core::str::SplitN<'a, P>`
+///
+/// This is constructed with [`splitn`] like this:
+/// ```rust
+/// # let string = "";
+/// # let delim = "";
+/// # let _: konst::string::SplitN<'_, '_, &str> =
+/// konst::string::splitn(string, 2, delim)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct SplitN<'a, 'p, P: Pattern<'p>> {
+    inner: Split<'a, 'p, P>,
+    // the amount of strings that can still be returned
+    count: usize,
+}
+impl<'a, 'p, P: Pattern<'p>> ConstIntoIter for SplitN<'a, 'p, P> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a str;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, P: Pattern<'p>> SplitN<'a, 'p, P> {
+    iterator_shared! {
+        is_forward = true,
+        item = &'a str,
+        iter_forward = SplitN<'a, 'p, P>,
+
+        next(self){
+            match self.count {
+                0 => None,
+                1 => {
+                    self.count = 0;
+                    if self.inner.is_finished() {
+                        None
+                    } else {
+                        Some(self.inner.remainder())
+                    }
+                }
+                _ => {
+                    self.count -= 1;
+                    self.inner.next()
+                }
+            }
+        },
+
+        fields = {inner.copy(), count},
+    }
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::splitn("foo-bar-baz", 2, "-");
+    /// assert_eq!(iter.remainder(), "foo-bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "foo");
+    /// assert_eq!(iter.remainder(), "bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "bar-baz");
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        if self.count == 0 {
+            ""
+        } else {
+            self.inner.remainder()
+        }
+    }
+}
+
+/// Const equivalent of `core::str::RSplitN<'a, P>`
+///
+/// This is constructed with [`rsplitn`] like this:
+/// ```rust
+/// # let string = "";
+/// # let delim = "";
+/// # let _: konst::string::RSplitN<'_, '_, &str> =
+/// konst::string::rsplitn(string, 2, delim)
+/// # ;
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub struct RSplitN<'a, 'p, P: Pattern<'p>> {
+    inner: RSplit<'a, 'p, P>,
+    // the amount of strings that can still be returned
+    count: usize,
+}
+impl<'a, 'p, P: Pattern<'p>> ConstIntoIter for RSplitN<'a, 'p, P> {
+    type Kind = IsIteratorKind;
+    type IntoIter = Self;
+    type Item = &'a str;
+    const ITEMS_NEED_DROP: bool = false;
+}
+
+impl<'a, 'p, P: Pattern<'p>> RSplitN<'a, 'p, P> {
+    iterator_shared! {
+        is_forward = true,
+        item = &'a str,
+        iter_forward = RSplitN<'a, 'p, P>,
+
+        next(self){
+            match self.count {
+                0 => None,
+                1 => {
+                    self.count = 0;
+                    if self.inner.is_finished() {
+                        None
+                    } else {
+                        Some(self.inner.remainder())
+                    }
+                }
+                _ => {
+                    self.count -= 1;
+                    self.inner.next()
+                }
+            }
+        },
+
+        fields = {inner.copy(), count},
+    }
+
+    /// Gets the remainder of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let mut iter = konst::string::rsplitn("foo-bar-baz", 2, "-");
+    /// assert_eq!(iter.remainder(), "foo-bar-baz");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "baz");
+    /// assert_eq!(iter.remainder(), "foo-bar");
+    ///
+    /// assert_eq!(iter.next().unwrap(), "foo-bar");
+    /// assert_eq!(iter.remainder(), "");
+    ///
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// ```
+    pub const fn remainder(&self) -> &'a str {
+        if self.count == 0 {
+            ""
+        } else {
+            self.inner.remainder()
+        }
+    }
+}
@@ -25,15 +25,14 @@ where
     P: Pattern<'p>,
 {
     let delim = PatternNorm::new(delim);
-    let delim = delim.as_str();
 
     if delim.is_empty() {
         // using split_at so that the pointer points within the string
         Some(string::split_at(this, 0))
     } else {
         crate::option::map! {
-            string::find(this, delim),
-            |pos| (str_up_to(this, pos), str_from(this, pos + delim.len()))
+            delim.find_in(this.as_bytes()),
+            |(start, end)| (str_up_to(this, start), str_from(this, end))
         }
     }
 }
@@ -64,15 +63,14 @@ where
     P: Pattern<'p>,
 {
     let delim = PatternNorm::new(delim);
-    let delim = delim.as_str();
 
     if delim.is_empty() {
         // using split_at so that the pointer points within the string
         Some(string::split_at(this, this.len()))
     } else {
         crate::option::map! {
-            string::rfind(this, delim),
-            |pos| (str_up_to(this, pos), str_from(this, pos + delim.len()))
+            delim.rfind_in(this.as_bytes()),
+            |(start, end)| (str_up_to(this, start), str_from(this, end))
         }
     }
 }
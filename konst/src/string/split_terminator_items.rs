@@ -32,7 +32,7 @@ where
     SplitTerminator {
         inner: string::split(this, delim),
         terminator_visited: !string::ends_with(this, delim),
-        skip_iterating: this.is_empty() && !delim_norm.as_str().is_empty(),
+        skip_iterating: this.is_empty() && !delim_norm.is_empty(),
     }
 }
 
@@ -65,7 +65,7 @@ where
     RSplitTerminator {
         inner: string::rsplit(this, delim),
         terminator_visited: !string::ends_with(this, delim),
-        skip_iterating: this.is_empty() && !delim_norm.as_str().is_empty(),
+        skip_iterating: this.is_empty() && !delim_norm.is_empty(),
     }
 }
 
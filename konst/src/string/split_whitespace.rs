@@ -0,0 +1,226 @@
+use crate::{
+    iter::{ConstIntoIter, IsIteratorKind},
+    string::{
+        CharPredicate, PatternNorm, str_from, str_up_to, trim_end_matches, trim_start_matches,
+    },
+};
+
+/// Const equivalent of [`str::split_whitespace`].
+///
+/// Splits the string at whitespace, as defined by [`char::is_whitespace`],
+/// skipping empty strings between consecutive whitespace chars.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// const WORDS: [&str; 3] = collect_const!(&str =>
+///     string::split_whitespace("  foo\tbar\u{3000} baz\n")
+/// );
+///
+/// assert_eq!(WORDS, ["foo", "bar", "baz"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn split_whitespace(this: &str) -> SplitWhitespace<'_> {
+    SplitWhitespace { this }
+}
+
+/// Const equivalent of [`str::split_ascii_whitespace`].
+///
+/// Splits the string at ASCII whitespace, as defined by [`char::is_ascii_whitespace`],
+/// skipping empty strings between consecutive whitespace chars.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+/// use konst::iter::collect_const;
+///
+/// // `\u{3000}` is not ASCII whitespace, so it's not split at
+/// const WORDS: [&str; 2] = collect_const!(&str =>
+///     string::split_ascii_whitespace(" foo\u{3000}bar \r\n baz").rev()
+/// );
+///
+/// assert_eq!(WORDS, ["baz", "foo\u{3000}bar"]);
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+pub const fn split_ascii_whitespace(this: &str) -> SplitAsciiWhitespace<'_> {
+    SplitAsciiWhitespace { this }
+}
+
+// returns the first word in `this`, and the string after that word,
+// or `None` if `this` only contains whitespace.
+const fn next_word(this: &str, whitespace: CharPredicate) -> Option<(&str, &str)> {
+    let this = trim_start_matches(this, whitespace);
+    if this.is_empty() {
+        return None;
+    }
+
+    Some(
+        match PatternNorm::new(whitespace).find_in(this.as_bytes()) {
+            Some((start, _)) => (str_up_to(this, start), str_from(this, start)),
+            None => (this, ""),
+        },
+    )
+}
+
+// returns the last word in `this`, and the string before that word,
+// or `None` if `this` only contains whitespace.
+const fn next_back_word(this: &str, whitespace: CharPredicate) -> Option<(&str, &str)> {
+    let this = trim_end_matches(this, whitespace);
+    if this.is_empty() {
+        return None;
+    }
+
+    Some(
+        match PatternNorm::new(whitespace).rfind_in(this.as_bytes()) {
+            Some((_, end)) => (str_from(this, end), str_up_to(this, end)),
+            None => (this, ""),
+        },
+    )
+}
+
+macro_rules! declare_split_whitespace_iters {
+    (
+        $(#[$fwd_attr:meta])*
+        struct $Fwd:ident;
+
+        $(#[$rev_attr:meta])*
+        struct $Rev:ident;
+
+        whitespace = $whitespace:expr,
+    ) => {
+        $(#[$fwd_attr])*
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+        pub struct $Fwd<'a> {
+            this: &'a str,
+        }
+
+        $(#[$rev_attr])*
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "iter")))]
+        pub struct $Rev<'a> {
+            this: &'a str,
+        }
+
+        impl<'a> ConstIntoIter for $Fwd<'a> {
+            type Kind = IsIteratorKind;
+            type IntoIter = Self;
+            type Item = &'a str;
+            const ITEMS_NEED_DROP: bool = false;
+        }
+
+        impl<'a> ConstIntoIter for $Rev<'a> {
+            type Kind = IsIteratorKind;
+            type IntoIter = Self;
+            type Item = &'a str;
+            const ITEMS_NEED_DROP: bool = false;
+        }
+
+        declare_split_whitespace_iters! {
+            @impl $Fwd, $Fwd, $Rev, is_forward = true, $whitespace
+        }
+
+        declare_split_whitespace_iters! {
+            @impl $Rev, $Fwd, $Rev, is_forward = false, $whitespace
+        }
+    };
+    (@impl $Self:ident, $Fwd:ident, $Rev:ident, is_forward = $is_forward:ident, $whitespace:expr) => {
+        impl<'a> $Self<'a> {
+            iterator_shared! {
+                is_forward = $is_forward,
+                item = &'a str,
+                iter_forward = $Fwd<'a>,
+                iter_reversed = $Rev<'a>,
+                next(self) {
+                    match next_word(self.this, $whitespace) {
+                        Some((word, rem)) => {
+                            self.this = rem;
+                            Some(word)
+                        }
+                        None => {
+                            self.this = "";
+                            None
+                        }
+                    }
+                },
+                next_back {
+                    match next_back_word(self.this, $whitespace) {
+                        Some((word, rem)) => {
+                            self.this = rem;
+                            Some(word)
+                        }
+                        None => {
+                            self.this = "";
+                            None
+                        }
+                    }
+                },
+                fields = {this},
+            }
+
+            /// Gets the remainder of the string,
+            /// which can start or end with whitespace.
+            pub const fn remainder(&self) -> &'a str {
+                self.this
+            }
+        }
+    };
+}
+
+declare_split_whitespace_iters! {
+    /// Const equivalent of `core::str::SplitWhitespace<'a>`
+    ///
+    /// This is constructed with [`split_whitespace`] like this:
+    /// ```rust
+    /// # let string = "";
+    /// # let _ =
+    /// konst::string::split_whitespace(string)
+    /// # ;
+    /// ```
+    ///
+    struct SplitWhitespace;
+
+    /// Const equivalent of `core::iter::Rev<core::str::SplitWhitespace<'a>>`
+    ///
+    /// This is constructed with [`split_whitespace`] like this:
+    /// ```rust
+    /// # let string = "";
+    /// # let _ =
+    /// konst::string::split_whitespace(string).rev()
+    /// # ;
+    /// ```
+    ///
+    struct RSplitWhitespace;
+
+    whitespace = CharPredicate::WHITESPACE,
+}
+
+declare_split_whitespace_iters! {
+    /// Const equivalent of `core::str::SplitAsciiWhitespace<'a>`
+    ///
+    /// This is constructed with [`split_ascii_whitespace`] like this:
+    /// ```rust
+    /// # let string = "";
+    /// # let _ =
+    /// konst::string::split_ascii_whitespace(string)
+    /// # ;
+    /// ```
+    ///
+    struct SplitAsciiWhitespace;
+
+    /// Const equivalent of `core::iter::Rev<core::str::SplitAsciiWhitespace<'a>>`
+    ///
+    /// This is constructed with [`split_ascii_whitespace`] like this:
+    /// ```rust
+    /// # let string = "";
+    /// # let _ =
+    /// konst::string::split_ascii_whitespace(string).rev()
+    /// # ;
+    /// ```
+    ///
+    struct RSplitAsciiWhitespace;
+
+    whitespace = CharPredicate::ASCII_WHITESPACE,
+}
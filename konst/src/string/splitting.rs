@@ -27,7 +27,7 @@ where
     let delim = PatternNorm::new(delim);
     Split {
         this,
-        state: if delim.as_str().is_empty() {
+        state: if delim.is_empty() {
             State::Empty(EmptyState::Start)
         } else {
             State::Normal { delim }
@@ -110,11 +110,10 @@ impl<'a, 'p, P: Pattern<'p>> Split<'a, 'p, P> {
 
             match state {
                 State::Normal{delim} => {
-                    let delim = delim.as_str();
-                    match string::find(this, delim) {
-                        Some(pos) => {
-                            self.this = str_from(this, pos + delim.len());
-                            Some(str_up_to(this, pos))
+                    match delim.find_in(this.as_bytes()) {
+                        Some((start, end)) => {
+                            self.this = str_from(this, end);
+                            Some(str_up_to(this, start))
                         }
                         None => {
                             self.this = "";
@@ -215,11 +214,10 @@ impl<'a, 'p, P: Pattern<'p>> RSplit<'a, 'p, P> {
             } = *self;
             match state {
                 State::Normal{delim} => {
-                    let delim = delim.as_str();
-                    match string::rfind(this, delim) {
-                        Some(pos) => {
-                            self.this = str_up_to(this, pos);
-                            Some(str_from(this, pos + delim.len()))
+                    match delim.rfind_in(this.as_bytes()) {
+                        Some((start, end)) => {
+                            self.this = str_up_to(this, start);
+                            Some(str_from(this, end))
                         }
                         None => {
                             self.this = "";
@@ -235,6 +233,10 @@ impl<'a, 'p, P: Pattern<'p>> RSplit<'a, 'p, P> {
         fields = {this, state},
     }
 
+    pub(crate) const fn is_finished(&self) -> bool {
+        matches!(self.state, State::Finished)
+    }
+
     const fn next_back_from_empty(&mut self, es: EmptyState) -> Option<&'a str> {
         match es {
             EmptyState::Start => {
@@ -0,0 +1,526 @@
+use core::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    string::{ArrayStr, writer::Digits},
+    utils::wrong_length_panic,
+};
+
+/// For building a string incrementally, in a fixed-capacity buffer.
+///
+/// The `push_*` methods panic when the pushed string doesn't fit in the buffer,
+/// while the `try_push_*` methods return a [`CapacityError`] instead,
+/// leaving the builder unmodified.
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string::{ArrayStr, StrBuilder};
+///
+/// const fn describe(port: u16, flags: u8) -> StrBuilder<64> {
+///     let mut builder = StrBuilder::new();
+///     builder.push_str("port ");
+///     builder.push_u64(port as u64, 10, 0);
+///     builder.push_str(", flags: 0b");
+///     builder.push_u64(flags as u64, 2, 8);
+///     builder
+/// }
+///
+/// const BUILDER: &StrBuilder<64> = &describe(8080, 5);
+/// assert_eq!(BUILDER.as_str(), "port 8080, flags: 0b00000101");
+///
+/// // converting to an exactly-sized `ArrayStr`
+/// const ARR_STR: ArrayStr<{ BUILDER.len() }> = BUILDER.to_array_str();
+/// assert_eq!(ARR_STR.as_str(), "port 8080, flags: 0b00000101");
+///
+/// ```
+#[derive(Copy, Clone)]
+pub struct StrBuilder<const CAP: usize> {
+    buffer: [u8; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> StrBuilder<CAP> {
+    /// Constructs an empty `StrBuilder`.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; CAP],
+            len: 0,
+        }
+    }
+
+    /// The length of the string, in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum length of the string, in bytes.
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// How many more bytes can be pushed.
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len
+    }
+
+    /// Gets the built string.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: the bytes up to `len` are only written with whole strings,
+        // and `len` is only reduced to char boundaries.
+        unsafe { crate::string::__from_u8_subslice_of_str(self.as_bytes()) }
+    }
+
+    /// Gets the built string as a byte slice.
+    pub const fn as_bytes(&self) -> &[u8] {
+        crate::slice::slice_up_to(&self.buffer, self.len)
+    }
+
+    /// Copies the built string into an [`ArrayStr`] of the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` isn't the length of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::{ArrayStr, StrBuilder};
+    ///
+    /// const BUILDER: &StrBuilder<16> = &{
+    ///     let mut builder = StrBuilder::new();
+    ///     builder.push_str("foo");
+    ///     builder.push_char('-');
+    ///     builder.push_i64(-3, 10, 0);
+    ///     builder
+    /// };
+    ///
+    /// const STR: ArrayStr<{ BUILDER.len() }> = BUILDER.to_array_str();
+    ///
+    /// assert_eq!(STR.as_str(), "foo--3");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn to_array_str<const N: usize>(&self) -> ArrayStr<N> {
+        if N != self.len {
+            wrong_length_panic(self.len, N)
+        }
+
+        let mut array = [0u8; N];
+        crate::for_range! {i in 0..N =>
+            array[i] = self.buffer[i];
+        }
+        ArrayStr(array)
+    }
+
+    /// Removes the contents of the string.
+    pub const fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Shortens the string to `new_len` bytes,
+    /// doing nothing if `new_len >= self.len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on a char boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::StrBuilder;
+    ///
+    /// let mut builder = StrBuilder::<8>::new();
+    /// builder.push_str("héllo");
+    ///
+    /// builder.truncate(3);
+    /// assert_eq!(builder.as_str(), "hé");
+    ///
+    /// builder.truncate(10);
+    /// assert_eq!(builder.as_str(), "hé");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+
+        if !crate::string::byte_is_char_boundary!(self.buffer[new_len]) {
+            truncate_boundary_panic(new_len)
+        }
+
+        self.len = new_len;
+    }
+
+    /// Removes the last char of the string and returns it,
+    /// returning `None` if the string is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::StrBuilder;
+    ///
+    /// let mut builder = StrBuilder::<8>::new();
+    /// builder.push_str("añ");
+    ///
+    /// assert_eq!(builder.pop(), Some('ñ'));
+    /// assert_eq!(builder.pop(), Some('a'));
+    /// assert_eq!(builder.pop(), None);
+    ///
+    /// ```
+    pub const fn pop(&mut self) -> Option<char> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let bytes = self.as_bytes();
+        let start = crate::string::__find_prev_char_boundary(bytes, bytes.len());
+        let (c, _) = crate::string::next_char(bytes, start);
+
+        self.len = start;
+
+        char::from_u32(c)
+    }
+
+    /// Appends `string`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `string` doesn't fit in the remaining capacity.
+    #[track_caller]
+    pub const fn push_str(&mut self, string: &str) {
+        unwrap_capacity(self.try_push_str(string))
+    }
+
+    /// Appends `string`, returning an error if it doesn't fit in the remaining capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::StrBuilder;
+    ///
+    /// let mut builder = StrBuilder::<8>::new();
+    ///
+    /// assert!(builder.try_push_str("hello").is_ok());
+    ///
+    /// let err = builder.try_push_str(", world").unwrap_err();
+    /// assert_eq!(err.additional(), 7);
+    /// assert_eq!(err.remaining(), 3);
+    ///
+    /// assert_eq!(builder.as_str(), "hello");
+    ///
+    /// ```
+    pub const fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        let bytes = string.as_bytes();
+        if let Err(e) = self.reserve(bytes.len()) {
+            return Err(e);
+        }
+
+        crate::for_range! {i in 0..bytes.len() =>
+            self.buffer[self.len + i] = bytes[i];
+        }
+        self.len += bytes.len();
+
+        Ok(())
+    }
+
+    /// Appends `c`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` doesn't fit in the remaining capacity.
+    #[track_caller]
+    pub const fn push_char(&mut self, c: char) {
+        unwrap_capacity(self.try_push_char(c))
+    }
+
+    /// Appends `c`, returning an error if it doesn't fit in the remaining capacity.
+    pub const fn try_push_char(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push_str(crate::chr::encode_utf8(c).as_str())
+    }
+
+    /// Appends `n` formatted in base `radix`,
+    /// padded with leading zeroes to be at least `min_width` bytes long.
+    ///
+    /// Digits above 9 are written as lowercase letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range,
+    /// or if the number doesn't fit in the remaining capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::StrBuilder;
+    ///
+    /// let mut builder = StrBuilder::<32>::new();
+    ///
+    /// builder.push_u64(1234, 10, 0);
+    /// builder.push_char(' ');
+    /// builder.push_u64(255, 16, 4);
+    /// builder.push_char(' ');
+    /// builder.push_u64(5, 2, 0);
+    ///
+    /// assert_eq!(builder.as_str(), "1234 00ff 101");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn push_u64(&mut self, n: u64, radix: u32, min_width: usize) {
+        unwrap_capacity(self.try_push_u64(n, radix, min_width))
+    }
+
+    /// Appends `n` like [`push_u64`](Self::push_u64),
+    /// returning an error if it doesn't fit in the remaining capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    #[track_caller]
+    pub const fn try_push_u64(
+        &mut self,
+        n: u64,
+        radix: u32,
+        min_width: usize,
+    ) -> Result<(), CapacityError> {
+        self.push_int(false, n, radix, min_width)
+    }
+
+    /// Appends `n` formatted in base `radix`,
+    /// padded with leading zeroes (after the `-` sign)
+    /// to be at least `min_width` bytes long.
+    ///
+    /// Digits above 9 are written as lowercase letters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range,
+    /// or if the number doesn't fit in the remaining capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konst::string::StrBuilder;
+    ///
+    /// let mut builder = StrBuilder::<32>::new();
+    ///
+    /// builder.push_i64(-1234, 10, 0);
+    /// builder.push_char(' ');
+    /// builder.push_i64(-42, 10, 5);
+    /// builder.push_char(' ');
+    /// builder.push_i64(35, 36, 0);
+    ///
+    /// assert_eq!(builder.as_str(), "-1234 -0042 z");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn push_i64(&mut self, n: i64, radix: u32, min_width: usize) {
+        unwrap_capacity(self.try_push_i64(n, radix, min_width))
+    }
+
+    /// Appends `n` like [`push_i64`](Self::push_i64),
+    /// returning an error if it doesn't fit in the remaining capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    #[track_caller]
+    pub const fn try_push_i64(
+        &mut self,
+        n: i64,
+        radix: u32,
+        min_width: usize,
+    ) -> Result<(), CapacityError> {
+        self.push_int(n < 0, n.unsigned_abs(), radix, min_width)
+    }
+
+    #[track_caller]
+    const fn push_int(
+        &mut self,
+        negative: bool,
+        magnitude: u64,
+        radix: u32,
+        min_width: usize,
+    ) -> Result<(), CapacityError> {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the `2..=36` range"
+        );
+
+        let digits = Digits::new(magnitude as u128, radix, false);
+        let digits = digits.as_bytes();
+
+        let sign_len = negative as usize;
+        let digit_count = digits.len();
+        let zeroes = min_width.saturating_sub(sign_len + digit_count);
+
+        if let Err(e) = self.reserve(sign_len + zeroes + digit_count) {
+            return Err(e);
+        }
+
+        if negative {
+            self.buffer[self.len] = b'-';
+            self.len += 1;
+        }
+        crate::for_range! {_ in 0..zeroes =>
+            self.buffer[self.len] = b'0';
+            self.len += 1;
+        }
+        crate::for_range! {i in 0..digits.len() =>
+            self.buffer[self.len] = digits[i];
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    // checks that `additional` bytes fit in the remaining capacity
+    const fn reserve(&self, additional: usize) -> Result<(), CapacityError> {
+        if additional <= CAP - self.len {
+            Ok(())
+        } else {
+            Err(CapacityError {
+                additional,
+                remaining: CAP - self.len,
+            })
+        }
+    }
+}
+
+impl<const CAP: usize> Default for StrBuilder<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// compares only the built string, ignoring the bytes past `len`
+impl<const CAP: usize> PartialEq for StrBuilder<CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const CAP: usize> Eq for StrBuilder<CAP> {}
+
+impl<const CAP: usize> Hash for StrBuilder<CAP> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_bytes().hash(hasher)
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for StrBuilder<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const CAP: usize> Display for StrBuilder<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned by the `try_push_*` methods of [`StrBuilder`]
+/// when the pushed string doesn't fit in the remaining capacity.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct CapacityError {
+    additional: usize,
+    remaining: usize,
+}
+
+impl CapacityError {
+    /// The length (in bytes) of the string that couldn't be pushed.
+    pub const fn additional(&self) -> usize {
+        self.additional
+    }
+
+    /// The remaining capacity of the builder when the push was attempted.
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not push `{}` bytes to a string builder with `{}` bytes of remaining capacity",
+            self.additional, self.remaining,
+        )
+    }
+}
+
+const _: () = {
+    use const_panic::{
+        PanicFmt, PanicVal, flatten_panicvals,
+        fmt::{self as cfmt, ComputePvCount, FmtArg, FmtKind},
+    };
+
+    impl PanicFmt for CapacityError {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 2,
+            summed_pv_count: <usize>::PV_COUNT * 2,
+            delimiter: cfmt::TypeDelim::Braced,
+        }
+        .call();
+    }
+
+    impl CapacityError {
+        /// Formats a CapacityError
+        pub const fn to_panicvals(
+            &self,
+            fmtarg: FmtArg,
+        ) -> [PanicVal<'static>; CapacityError::PV_COUNT] {
+            match fmtarg.fmt_kind {
+                FmtKind::Debug => {
+                    flatten_panicvals! {fmtarg;
+                        "CapacityError",
+                        open: cfmt::OpenBrace,
+                            "additional: ", usize => self.additional, cfmt::COMMA_SEP,
+                            "remaining: ", usize => self.remaining, cfmt::COMMA_TERM,
+                        close: cfmt::CloseBrace,
+                    }
+                }
+                _ => const_panic::utils::flatten_panicvals(&[&[
+                    PanicVal::write_str("could not push `"),
+                    PanicVal::from_usize(self.additional, FmtArg::DEBUG),
+                    PanicVal::write_str("` bytes to a string builder with `"),
+                    PanicVal::from_usize(self.remaining, FmtArg::DEBUG),
+                    PanicVal::write_str("` bytes of remaining capacity"),
+                ]]),
+            }
+        }
+    }
+};
+
+#[track_caller]
+const fn unwrap_capacity(res: Result<(), CapacityError>) {
+    if let Err(e) = res {
+        const_panic::concat_panic(&[&e.to_panicvals(const_panic::FmtArg::DISPLAY)])
+    }
+}
+
+#[cold]
+#[track_caller]
+const fn truncate_boundary_panic(new_len: usize) -> ! {
+    use const_panic::{FmtArg, PanicVal};
+
+    const_panic::concat_panic(&[&[
+        PanicVal::write_str("new length `"),
+        PanicVal::from_usize(new_len, FmtArg::DEBUG),
+        PanicVal::write_str("` is not on a char boundary"),
+    ]])
+}
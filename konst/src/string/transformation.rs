@@ -0,0 +1,326 @@
+use crate::string::{__SepArg, ArrayStr, Pattern, PatternNorm, writer::Writer};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_replace {
+    ($string:expr, $pattern:expr, $replacement:expr $(,)?) => {{
+        // the arguments are evaluated outside the block with the `LEN`/`CONC`/`STR`
+        // constants, so that they can't refer to those constants.
+        const __STR81608BFNA5: &$crate::__::str = $string;
+
+        const __REPLACEMENT81608BFNA5: $crate::string::__SepArg =
+            $crate::string::__MakeSepArg($replacement).conv();
+
+        // the pattern can be of any type, so it's passed through functions
+        const fn __len_81608BFNA5() -> $crate::__::usize {
+            $crate::string::__replace_len(__STR81608BFNA5, $pattern, __REPLACEMENT81608BFNA5)
+        }
+
+        const fn __conc_81608BFNA5<const N: $crate::__::usize>() -> $crate::string::ArrayStr<N> {
+            $crate::string::__replace(__STR81608BFNA5, $pattern, __REPLACEMENT81608BFNA5)
+        }
+
+        {
+            const LEN: $crate::__::usize = __len_81608BFNA5();
+
+            const CONC: &$crate::string::ArrayStr<LEN> = &__conc_81608BFNA5::<LEN>();
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __replace_len<'a, P>(string: &str, pattern: P, replacement: __SepArg) -> usize
+where
+    P: Pattern<'a>,
+{
+    let mut out = Writer::<0>::new();
+    write_replaced(&mut out, string, pattern, replacement);
+    out.len
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __replace<'a, P, const N: usize>(
+    string: &str,
+    pattern: P,
+    replacement: __SepArg,
+) -> ArrayStr<N>
+where
+    P: Pattern<'a>,
+{
+    let mut out = Writer::<N>::new();
+    write_replaced(&mut out, string, pattern, replacement);
+    out.into_array_str()
+}
+
+const fn write_replaced<'a, P, const N: usize>(
+    out: &mut Writer<N>,
+    string: &str,
+    pattern: P,
+    replacement: __SepArg,
+) where
+    P: Pattern<'a>,
+{
+    let pattern = PatternNorm::new(pattern);
+    let bytes = string.as_bytes();
+
+    // like `str::replace`, an empty pattern matches at every char boundary
+    if pattern.is_empty() {
+        crate::for_range! {i in 0..bytes.len() =>
+            if crate::string::byte_is_char_boundary!(bytes[i]) {
+                write_sep_arg(out, replacement);
+            }
+            out.write_byte(bytes[i]);
+        }
+        write_sep_arg(out, replacement);
+        return;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(len) = pattern.prefix_len(bytes.split_at(i).1) {
+            write_sep_arg(out, replacement);
+            i += len;
+        } else {
+            let next = crate::string::__find_next_char_boundary(bytes, i);
+            crate::for_range! {j in i..next =>
+                out.write_byte(bytes[j]);
+            }
+            i = next;
+        }
+    }
+}
+
+const fn write_sep_arg<const N: usize>(out: &mut Writer<N>, arg: __SepArg) {
+    match arg {
+        __SepArg::Char(c) => out.write_char(c),
+        __SepArg::Str(s) => out.write_str(s),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_repeat {
+    ($string:expr, $times:expr $(,)?) => {{
+        // the arguments are evaluated outside the block with the `LEN`/`CONC`/`STR`
+        // constants, so that they can't refer to those constants.
+        const __STR81608BFNA5: $crate::string::__SepArg =
+            $crate::string::__MakeSepArg($string).conv();
+
+        const __TIMES81608BFNA5: $crate::__::usize = $times;
+
+        {
+            const LEN: $crate::__::usize =
+                $crate::string::__repeat_len(__STR81608BFNA5, __TIMES81608BFNA5);
+
+            const CONC: &$crate::string::ArrayStr<LEN> =
+                &$crate::string::__repeat(__STR81608BFNA5, __TIMES81608BFNA5);
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __repeat_len(string: __SepArg, times: usize) -> usize {
+    match string.len().checked_mul(times) {
+        Some(len) => len,
+        None => panic!("the length of the repeated string overflows `usize`"),
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __repeat<const N: usize>(string: __SepArg, times: usize) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    crate::for_range! {_ in 0..times =>
+        write_sep_arg(&mut out, string);
+    }
+    out.into_array_str()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_case {
+    ($function:ident, $string:expr $(,)?) => {{
+        // the argument is evaluated outside the block with the `CONC`/`STR` constants,
+        // so that it can't refer to those constants.
+        const __STR81608BFNA5: &$crate::__::str = $string;
+
+        {
+            const CONC: &$crate::string::ArrayStr<{ __STR81608BFNA5.len() }> =
+                &$crate::string::$function(__STR81608BFNA5);
+
+            const STR: &$crate::__::str = CONC.as_str();
+
+            STR
+        }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_upper {
+    ($string:expr $(,)?) => {
+        $crate::__string_to_case!(__to_upper, $string)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __string_to_lower {
+    ($string:expr $(,)?) => {
+        $crate::__string_to_case!(__to_lower, $string)
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __to_upper<const N: usize>(string: &str) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    let bytes = string.as_bytes();
+    crate::for_range! {i in 0..bytes.len() =>
+        out.write_byte(bytes[i].to_ascii_uppercase());
+    }
+    out.into_array_str()
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn __to_lower<const N: usize>(string: &str) -> ArrayStr<N> {
+    let mut out = Writer::<N>::new();
+    let bytes = string.as_bytes();
+    crate::for_range! {i in 0..bytes.len() =>
+        out.write_byte(bytes[i].to_ascii_lowercase());
+    }
+    out.into_array_str()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Macro equivalent of [`str::replace`], which takes constants as arguments.
+///
+/// This acts like a compile-time-evaluated version of this function:
+/// ```rust
+/// # use konst::string::Pattern;
+/// # enum StrOrChar {}
+/// pub const fn replace(
+///     string: &'static str,
+///     pattern: impl Pattern<'static>,
+///     replacement: StrOrChar,
+/// ) -> &'static str
+/// # { "" }
+/// ```
+///
+/// Where `StrOrChar` is either a `&'static str` or `char`
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const CRATE: &str = "my-crate-name";
+/// const MODULE: &str = string::replace!(CRATE, '-', '_');
+/// assert_eq!(MODULE, "my_crate_name");
+///
+/// assert_eq!(string::replace!("foo bar foo", "foo", "qux"), "qux bar qux");
+/// assert_eq!(string::replace!("aaaa", "aa", 'b'), "bb");
+/// assert_eq!(string::replace!("foo", "bar", "baz"), "foo");
+///
+/// // any `Pattern` can be replaced
+/// assert_eq!(string::replace!("a-b_c d", ['-', '_', ' '], ""), "abcd");
+///
+/// // like `str::replace`, empty patterns match at every char boundary
+/// assert_eq!(string::replace!("ñ个", "", "|"), "|ñ|个|");
+///
+/// ```
+#[doc(inline)]
+pub use crate::__string_replace as replace;
+
+/// Macro equivalent of [`str::repeat`], which takes constants as arguments.
+///
+/// This acts like a compile-time-evaluated version of this function:
+/// ```rust
+/// # enum StrOrChar {}
+/// pub const fn repeat(string: StrOrChar, times: usize) -> &'static str
+/// # { "" }
+/// ```
+///
+/// Where `StrOrChar` is either a `&'static str` or `char`
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const WIDTH: usize = 8;
+/// const LINE: &str = string::repeat!('-', WIDTH);
+/// assert_eq!(LINE, "--------");
+///
+/// assert_eq!(string::repeat!("ab", 3), "ababab");
+/// assert_eq!(string::repeat!("个", 2), "个个");
+/// assert_eq!(string::repeat!("foo", 0), "");
+///
+/// ```
+#[doc(inline)]
+pub use crate::__string_repeat as repeat;
+
+/// Macro equivalent of [`str::to_ascii_uppercase`], which takes a constant as an argument.
+///
+/// Only ASCII letters are converted, all other chars are left unchanged.
+///
+/// This acts like a compile-time-evaluated version of this function:
+/// ```rust
+/// pub const fn to_upper(string: &'static str) -> &'static str
+/// # { "" }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const NAME: &str = "log_level";
+/// const ENV_VAR: &str = string::to_upper!(string::str_concat!(&["app_", NAME]));
+/// assert_eq!(ENV_VAR, "APP_LOG_LEVEL");
+///
+/// assert_eq!(string::to_upper!("ñandú"), "ñANDú");
+///
+/// ```
+#[doc(inline)]
+pub use crate::__string_to_upper as to_upper;
+
+/// Macro equivalent of [`str::to_ascii_lowercase`], which takes a constant as an argument.
+///
+/// Only ASCII letters are converted, all other chars are left unchanged.
+///
+/// This acts like a compile-time-evaluated version of this function:
+/// ```rust
+/// pub const fn to_lower(string: &'static str) -> &'static str
+/// # { "" }
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use konst::string;
+///
+/// const TYPE_NAME: &str = "HttpServer";
+/// assert_eq!(string::to_lower!(TYPE_NAME), "httpserver");
+///
+/// assert_eq!(string::to_lower!("ÑANDÚ Bird"), "ÑandÚ bird");
+///
+/// ```
+#[doc(inline)]
+pub use crate::__string_to_lower as to_lower;
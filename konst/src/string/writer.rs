@@ -0,0 +1,98 @@
+use crate::{string::ArrayStr, utils::wrong_length_panic};
+
+/// Writes a string into a `[u8; N]` buffer,
+/// while counting the length of everything that's written.
+///
+/// Bytes past `N` are not stored,
+/// so that a `Writer<0>` can be used to compute the length of a string,
+/// before writing it into a `Writer` of that length.
+pub(crate) struct Writer<const N: usize> {
+    pub(crate) buffer: [u8; N],
+    pub(crate) len: usize,
+    pub(crate) char_count: usize,
+}
+
+impl<const N: usize> Writer<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+            char_count: 0,
+        }
+    }
+
+    pub(crate) const fn write_byte(&mut self, byte: u8) {
+        if self.len < N {
+            self.buffer[self.len] = byte;
+        }
+        if crate::string::byte_is_char_boundary!(byte) {
+            self.char_count += 1;
+        }
+        self.len += 1;
+    }
+
+    pub(crate) const fn write_str(&mut self, string: &str) {
+        self.write_bytes(string.as_bytes());
+    }
+
+    // `bytes` must be valid UTF-8 when all the writes are combined
+    pub(crate) const fn write_bytes(&mut self, bytes: &[u8]) {
+        crate::for_range! {i in 0..bytes.len() =>
+            self.write_byte(bytes[i]);
+        }
+    }
+
+    pub(crate) const fn write_char(&mut self, c: char) {
+        self.write_str(crate::chr::encode_utf8(c).as_str());
+    }
+
+    pub(crate) const fn write_repeated(&mut self, c: char, times: usize) {
+        crate::for_range! {_ in 0..times =>
+            self.write_char(c);
+        }
+    }
+
+    // panics if the length of the written string isn't `N`
+    #[track_caller]
+    pub(crate) const fn into_array_str(self) -> ArrayStr<N> {
+        if self.len != N {
+            wrong_length_panic(self.len, N)
+        }
+
+        ArrayStr(self.buffer)
+    }
+}
+
+/// The digits of an unsigned integer in some radix, from most to least significant.
+pub(crate) struct Digits {
+    // enough digits for a u128 in binary
+    digits: [u8; 128],
+    start: usize,
+}
+
+impl Digits {
+    pub(crate) const fn new(mut magnitude: u128, radix: u32, uppercase: bool) -> Self {
+        let mut digits = [0u8; 128];
+        let mut start = digits.len();
+        let radix = radix as u128;
+
+        loop {
+            start -= 1;
+            digits[start] = match (magnitude % radix) as u8 {
+                d @ 0..=9 => b'0' + d,
+                d if uppercase => b'A' + d - 10,
+                d => b'a' + d - 10,
+            };
+            magnitude /= radix;
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        Self { digits, start }
+    }
+
+    pub(crate) const fn as_bytes(&self) -> &[u8] {
+        self.digits.split_at(self.start).1
+    }
+}
@@ -1 +1,25 @@
+use const_panic::{FmtArg, PanicVal};
 
+#[cold]
+#[track_caller]
+pub(crate) const fn wrong_array_length_panic(found: usize, expected: usize) -> ! {
+    const_panic::concat_panic(&[&[
+        PanicVal::write_str("expected the array length to be `"),
+        PanicVal::from_usize(expected, FmtArg::DEBUG),
+        PanicVal::write_str("`, found `"),
+        PanicVal::from_usize(found, FmtArg::DEBUG),
+        PanicVal::write_str("`"),
+    ]])
+}
+
+#[cold]
+#[track_caller]
+pub(crate) const fn wrong_length_panic(len: usize, expected: usize) -> ! {
+    const_panic::concat_panic(&[&[
+        PanicVal::write_str("expected the string length to be `"),
+        PanicVal::from_usize(expected, FmtArg::DEBUG),
+        PanicVal::write_str("`, found `"),
+        PanicVal::from_usize(len, FmtArg::DEBUG),
+        PanicVal::write_str("`"),
+    ]])
+}
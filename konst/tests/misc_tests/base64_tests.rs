@@ -0,0 +1,196 @@
+use konst::base64::{self, Config, DecodeErrorKind};
+
+use const_panic::{ArrayString, FmtArg};
+
+use super::test_utils::must_panic;
+
+const CONFIGS: [Config; 4] = [
+    Config::STANDARD,
+    Config::STANDARD_NO_PAD,
+    Config::URL_SAFE,
+    Config::URL_SAFE_NO_PAD,
+];
+
+// (decoded, standard padded encoding)
+const CASES: &[(&[u8], &str)] = &[
+    (b"", ""),
+    (b"f", "Zg=="),
+    (b"fo", "Zm8="),
+    (b"foo", "Zm9v"),
+    (b"foob", "Zm9vYg=="),
+    (b"fooba", "Zm9vYmE="),
+    (b"foobar", "Zm9vYmFy"),
+    (&[0xFB, 0xFF, 0xBF, 0x00], "+/+/AA=="),
+    (&[0xFF; 5], "//////8="),
+];
+
+fn expected_encoding(std_padded: &str, config: Config) -> String {
+    let mut out = std_padded.to_string();
+    if config.is_url_safe() {
+        out = out.replace('+', "-").replace('/', "_");
+    }
+    if !config.is_padded() {
+        out = out.trim_end_matches('=').to_string();
+    }
+    out
+}
+
+#[test]
+fn encode_test() {
+    fn encode_dyn(bytes: &[u8], config: Config) -> String {
+        macro_rules! with_len {
+            ($($len:literal)*) => {
+                match base64::encoded_len(bytes.len(), config) {
+                    $($len => base64::encode::<$len>(bytes, config).as_str().to_string(),)*
+                    _ => unreachable!(),
+                }
+            };
+        }
+        with_len! {0 2 3 4 6 7 8 10 11 12}
+    }
+
+    for &(decoded, encoded) in CASES {
+        for config in CONFIGS {
+            let expected = expected_encoding(encoded, config);
+            assert_eq!(
+                base64::encoded_len(decoded.len(), config),
+                expected.len(),
+                "{decoded:?} {config:?}",
+            );
+            assert_eq!(encode_dyn(decoded, config), expected, "{config:?}");
+        }
+    }
+}
+
+#[test]
+fn encode_wrong_length_test() {
+    must_panic(file_span!(), || {
+        base64::encode::<3>(b"foo", Config::STANDARD)
+    })
+    .unwrap();
+}
+
+fn decode_dyn(encoded: &str, config: Config) -> Result<Vec<u8>, base64::DecodeError> {
+    macro_rules! with_len {
+        ($($len:literal)*) => {
+            match base64::decoded_len(encoded) {
+                $($len => base64::decode::<$len>(encoded, config).map(|x| x.to_vec()),)*
+                _ => unreachable!(),
+            }
+        };
+    }
+    with_len! {0 1 2 3 4 5 6}
+}
+
+#[test]
+fn decode_test() {
+    for &(decoded, encoded) in CASES {
+        for config in CONFIGS {
+            for padded in [true, false] {
+                let encoded = expected_encoding(encoded, config);
+                let encoded = if padded {
+                    encoded
+                } else {
+                    encoded.trim_end_matches('=').to_string()
+                };
+
+                assert_eq!(base64::decoded_len(&encoded), decoded.len());
+                assert_eq!(decode_dyn(&encoded, config).unwrap(), decoded);
+            }
+        }
+    }
+}
+
+#[test]
+fn decode_err_test() {
+    for (encoded, config, kind, offset) in [
+        (
+            "Zm9v*A==",
+            Config::STANDARD,
+            DecodeErrorKind::InvalidByte,
+            4,
+        ),
+        (
+            "Zm9v+A==",
+            Config::URL_SAFE,
+            DecodeErrorKind::InvalidByte,
+            4,
+        ),
+        (
+            "Zm9v-A==",
+            Config::STANDARD,
+            DecodeErrorKind::InvalidByte,
+            4,
+        ),
+        ("Zm=v", Config::STANDARD, DecodeErrorKind::InvalidByte, 2),
+        ("Z===", Config::STANDARD, DecodeErrorKind::InvalidByte, 1),
+        ("Zm9vY", Config::STANDARD, DecodeErrorKind::InvalidLength, 5),
+        (
+            "Zm9vY=",
+            Config::STANDARD,
+            DecodeErrorKind::InvalidPadding,
+            5,
+        ),
+        (
+            "Zm9vYg=",
+            Config::STANDARD,
+            DecodeErrorKind::InvalidPadding,
+            6,
+        ),
+        (
+            "Zm9vYmE==",
+            Config::STANDARD,
+            DecodeErrorKind::InvalidPadding,
+            7,
+        ),
+        ("Zh==", Config::STANDARD, DecodeErrorKind::TrailingBits, 1),
+        ("Zm9=", Config::STANDARD, DecodeErrorKind::TrailingBits, 2),
+    ] {
+        let err = decode_dyn(encoded, config).unwrap_err();
+
+        assert_eq!(err.kind(), kind, "{encoded:?}");
+        assert_eq!(err.offset(), offset, "{encoded:?}");
+    }
+
+    let err = base64::decode::<2>("Zm9v", Config::STANDARD).unwrap_err();
+    assert_eq!(err.kind(), DecodeErrorKind::ArrayLength);
+    assert_eq!(err.offset(), 4);
+}
+
+#[test]
+fn decode_error_fmt_test() {
+    type Buff = ArrayString<256>;
+
+    let err = base64::decode::<4>("Zm9v*A==", Config::STANDARD).unwrap_err();
+
+    assert_eq!(
+        Buff::from_panicvals(&err.to_panicvals(FmtArg::DEBUG)).unwrap(),
+        *format!("{err:?}"),
+    );
+    assert_eq!(
+        Buff::from_panicvals(&err.to_panicvals(FmtArg::ALT_DEBUG)).unwrap(),
+        *format!("{err:#?}"),
+    );
+    assert_eq!(
+        Buff::from_panicvals(&err.to_panicvals(FmtArg::DISPLAY)).unwrap(),
+        *format!("{err}"),
+    );
+    assert_eq!(err.to_string(), "invalid base64 byte at offset 4");
+}
+
+#[test]
+fn macros_test() {
+    const DECODED: [u8; 6] = base64::decode!("Zm9vYmFy", Config::STANDARD);
+    assert_eq!(&DECODED, b"foobar");
+
+    const DECODED_URL: [u8; 4] = base64::decode!("-_-_AA", Config::URL_SAFE);
+    assert_eq!(DECODED_URL, [0xFB, 0xFF, 0xBF, 0x00]);
+
+    const EMPTY: [u8; 0] = base64::decode!("", Config::STANDARD);
+    assert_eq!(EMPTY, []);
+
+    const ENCODED: &str = base64::encode!(&[0xFB, 0xFF, 0xBF, 0x00], Config::URL_SAFE_NO_PAD);
+    assert_eq!(ENCODED, "-_-_AA");
+
+    assert_eq!(base64::encode!(b"fooba", Config::STANDARD), "Zm9vYmE=");
+}
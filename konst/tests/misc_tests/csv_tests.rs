@@ -0,0 +1,176 @@
+use konst::{
+    csv::{self, Field, Record},
+    iter,
+};
+
+use super::test_utils::must_panic;
+
+fn record_strs(csv: &str) -> Vec<Vec<&str>> {
+    let mut records = csv::records(csv);
+    let mut out = Vec::new();
+    while let Some(record) = records.next() {
+        let mut fields = record.fields();
+        let mut row = Vec::new();
+        while let Some(field) = fields.next() {
+            row.push(field.raw());
+        }
+        out.push(row);
+    }
+    out
+}
+
+#[test]
+fn records_test() {
+    for (csv, expected) in [
+        ("", vec![]),
+        ("\n", vec![vec![""]]),
+        ("a", vec![vec!["a"]]),
+        ("a,b,c", vec![vec!["a", "b", "c"]]),
+        ("a,b\nc,d\n", vec![vec!["a", "b"], vec!["c", "d"]]),
+        ("a,b\r\nc,d\r\n", vec![vec!["a", "b"], vec!["c", "d"]]),
+        ("a,b\r\nc,d", vec![vec!["a", "b"], vec!["c", "d"]]),
+        ("a\n\nb", vec![vec!["a"], vec![""], vec!["b"]]),
+        (",", vec![vec!["", ""]]),
+        (",,\n", vec![vec!["", "", ""]]),
+        ("\"\"", vec![vec!["\"\""]]),
+        ("\"a,b\",c", vec![vec!["\"a,b\"", "c"]]),
+        (
+            "\"a\r\nb\",c\r\nd",
+            vec![vec!["\"a\r\nb\"", "c"], vec!["d"]],
+        ),
+        ("\"a\"\"b\",\"\"\"\"", vec![vec!["\"a\"\"b\"", "\"\"\"\""]]),
+        ("x,\"\"\n", vec![vec!["x", "\"\""]]),
+        ("a b, c ", vec![vec!["a b", " c "]]),
+        ("ñ,字", vec![vec!["ñ", "字"]]),
+    ] {
+        assert_eq!(record_strs(csv), expected, "{csv:?}");
+    }
+}
+
+#[test]
+fn remainder_test() {
+    let mut records = csv::records("a,\"b\nc\"\nd,e");
+    let record = records.next().unwrap();
+    assert_eq!(record.as_str(), "a,\"b\nc\"");
+    assert_eq!(records.remainder(), "d,e");
+
+    let mut fields = record.fields();
+    assert_eq!(fields.remainder(), "a,\"b\nc\"");
+    assert_eq!(fields.next().unwrap().raw(), "a");
+    assert_eq!(fields.remainder(), "\"b\nc\"");
+    assert_eq!(fields.next().unwrap().raw(), "\"b\nc\"");
+    assert_eq!(fields.remainder(), "");
+    assert_eq!(fields.next(), None);
+
+    let mut copy = records.copy();
+    assert_eq!(copy.next().unwrap().as_str(), "d,e");
+    assert!(copy.next().is_none());
+    assert_eq!(records.remainder(), "d,e");
+}
+
+#[test]
+fn field_test() {
+    fn field(csv: &str) -> Field<'_> {
+        csv::records(csv).next().unwrap().field(0).unwrap()
+    }
+
+    for (csv, quoted, content, unescaped) in [
+        ("abc", false, "abc", "abc"),
+        ("\"\"", true, "", ""),
+        ("\"abc\"", true, "abc", "abc"),
+        ("\"a,b\"", true, "a,b", "a,b"),
+        ("\"\"\"\"", true, "\"\"", "\""),
+        ("\"a\"\"b\"\"\"", true, "a\"\"b\"\"", "a\"b\""),
+        ("\"ñ\"\"\n\"", true, "ñ\"\"\n", "ñ\"\n"),
+    ] {
+        let field = field(csv);
+        assert_eq!(field.raw(), csv);
+        assert_eq!(field.is_quoted(), quoted, "{csv:?}");
+        assert_eq!(field.content(), content, "{csv:?}");
+        assert_eq!(field.unescaped_len(), unescaped.len(), "{csv:?}");
+        assert!(field.eq_str(unescaped), "{csv:?}");
+        assert!(!field.eq_str("x"), "{csv:?}");
+
+        macro_rules! unescape_dyn {
+            ($($len:literal)*) => (
+                match unescaped.len() {
+                    $($len => field.unescape::<$len>().as_str().to_string(),)*
+                    _ => unreachable!(),
+                }
+            )
+        }
+        assert_eq!(unescape_dyn!(0 1 2 3 4 5 6), unescaped, "{csv:?}");
+    }
+
+    assert!(!field("\"ab\"").eq_str("a"));
+    assert!(!field("\"a\"").eq_str("ab"));
+    assert!(!field("\"a\"\"\"").eq_str("a\"\""));
+
+    must_panic(file_span!(), || field("\"a\"\"\"").unescape::<3>()).unwrap();
+    must_panic(file_span!(), || field("\"a\"\"\"").unescape::<1>()).unwrap();
+}
+
+#[test]
+fn header_test() {
+    const CSV: &str = "id,\"full name\",\"a\"\"b\"\nfoo,bar,baz\n";
+    const HEADER: Record<'_> = csv::records(CSV).next().unwrap();
+
+    assert_eq!(HEADER.field_index("id"), Some(0));
+    assert_eq!(HEADER.field_index("full name"), Some(1));
+    assert_eq!(HEADER.field_index("a\"b"), Some(2));
+    assert_eq!(HEADER.field_index("\"id\""), None);
+    assert_eq!(HEADER.field_index("full"), None);
+
+    assert_eq!(HEADER.field(0).unwrap().raw(), "id");
+    assert_eq!(HEADER.field(2).unwrap().raw(), "\"a\"\"b\"");
+    assert_eq!(HEADER.field(3), None);
+}
+
+#[test]
+fn collect_const_test() {
+    const CSV: &str = "\
+        name,amount\r\n\
+        \"Smith, J\",10\r\n\
+        \"Doe\r\nJ\",20\r\n\
+    ";
+
+    const NAMES: [&str; 2] = iter::collect_const!(&str =>
+        csv::records(CSV),
+            skip(1),
+            map(|record| record.field(0).unwrap().content()),
+    );
+    assert_eq!(NAMES, ["Smith, J", "Doe\r\nJ"]);
+
+    const AMOUNTS: [u32; 2] = iter::collect_const!(u32 =>
+        csv::records(CSV),
+            skip(1),
+            map(|record| record.field(1).unwrap().raw()),
+            map(|s| konst::result::unwrap!(u32::from_str_radix(s, 10))),
+    );
+    assert_eq!(AMOUNTS, [10, 20]);
+
+    const FIELDS: [&str; 2] = iter::collect_const!(&str =>
+        csv::records(CSV).next().unwrap(),
+            map(|field| field.raw()),
+    );
+    assert_eq!(FIELDS, ["name", "amount"]);
+}
+
+#[test]
+fn malformed_test() {
+    fn iterate_all(csv: &str) {
+        let _ = record_strs(csv);
+    }
+
+    for csv in [
+        "\"",
+        "a,\"b",
+        "a,\"b\"\"\nc",
+        "\"a\"b",
+        "\"a\" ,b",
+        "a\"b",
+        "a, \"b\"",
+    ] {
+        must_panic(file_span!(), || iterate_all(csv)).unwrap();
+    }
+}
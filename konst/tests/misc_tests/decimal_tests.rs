@@ -0,0 +1,339 @@
+use konst::decimal::{Decimal, RoundingMode};
+
+use super::test_utils::must_panic;
+
+const MODES: [RoundingMode; 7] = [
+    RoundingMode::TowardZero,
+    RoundingMode::AwayFromZero,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+    RoundingMode::HalfTowardZero,
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::HalfEven,
+];
+
+// reference implementation of rounded division, for values that don't overflow
+fn div_round(num: i128, den: i128, mode: RoundingMode) -> i128 {
+    let quot = num / den;
+    let rem = num % den;
+    if rem == 0 {
+        return quot;
+    }
+    let away = if (num < 0) != (den < 0) { -1 } else { 1 };
+    let twice_rem = (rem * 2).abs();
+    let den_abs = den.abs();
+
+    let round_away = match mode {
+        RoundingMode::TowardZero => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::Floor => away == -1,
+        RoundingMode::Ceiling => away == 1,
+        RoundingMode::HalfTowardZero => twice_rem > den_abs,
+        RoundingMode::HalfAwayFromZero => twice_rem >= den_abs,
+        RoundingMode::HalfEven => twice_rem > den_abs || (twice_rem == den_abs && quot % 2 != 0),
+    };
+
+    if round_away { quot + away } else { quot }
+}
+
+#[test]
+fn rounding_modes_test() {
+    use RoundingMode as RM;
+
+    // (value in tenths, [TowardZero, AwayFromZero, Floor, Ceiling, HalfTowardZero, HalfAwayFromZero, HalfEven])
+    for (tenths, expected) in [
+        (25, [2, 3, 2, 3, 2, 3, 2]),
+        (35, [3, 4, 3, 4, 3, 4, 4]),
+        (21, [2, 3, 2, 3, 2, 2, 2]),
+        (29, [2, 3, 2, 3, 3, 3, 3]),
+        (20, [2, 2, 2, 2, 2, 2, 2]),
+        (-25, [-2, -3, -3, -2, -2, -3, -2]),
+        (-35, [-3, -4, -4, -3, -3, -4, -4]),
+        (-21, [-2, -3, -3, -2, -2, -2, -2]),
+        (-29, [-2, -3, -3, -2, -3, -3, -3]),
+        (0, [0, 0, 0, 0, 0, 0, 0]),
+    ] {
+        let num = Decimal::<1>::from_mantissa(tenths);
+        for (mode, expected) in MODES.into_iter().zip(expected) {
+            assert_eq!(num.to_int(mode), expected, "{num} {mode:?}");
+            assert_eq!(
+                num.round_dp(0, mode).unwrap(),
+                Decimal::from_int(expected).unwrap(),
+                "{num} {mode:?}"
+            );
+            assert_eq!(
+                num.rescale::<0>(mode).unwrap().mantissa(),
+                expected,
+                "{num} {mode:?}"
+            );
+        }
+    }
+
+    let min = Decimal::<0>::MIN;
+    assert_eq!(min.to_int(RM::Floor), i128::MIN);
+    assert_eq!(Decimal::<2>::MIN.to_int(RM::Floor), i128::MIN / 100 - 1);
+    assert_eq!(Decimal::<2>::MAX.to_int(RM::Ceiling), i128::MAX / 100 + 1);
+    assert_eq!(Decimal::<2>::MAX.round_dp(1, RM::Ceiling), None);
+    assert_eq!(
+        Decimal::<2>::MAX.round_dp(1, RM::Floor).unwrap().mantissa(),
+        i128::MAX / 10 * 10
+    );
+    assert_eq!(Decimal::<2>::MAX.round_dp(5, RM::Floor), Some(Decimal::MAX));
+}
+
+#[test]
+fn add_sub_neg_test() {
+    let a = Decimal::<3>::from_mantissa(1500);
+    let b = Decimal::<3>::from_mantissa(-250);
+
+    assert_eq!(a.checked_add(b).unwrap().mantissa(), 1250);
+    assert_eq!(a.checked_sub(b).unwrap().mantissa(), 1750);
+    assert_eq!(b.checked_neg().unwrap().mantissa(), 250);
+    assert_eq!(b.checked_abs().unwrap().mantissa(), 250);
+
+    assert_eq!(Decimal::<3>::MAX.checked_add(a), None);
+    assert_eq!(Decimal::<3>::MIN.checked_sub(a), None);
+    assert_eq!(Decimal::<3>::MIN.checked_neg(), None);
+    assert_eq!(Decimal::<3>::MIN.checked_abs(), None);
+
+    assert!(b.is_negative());
+    assert!(!a.is_negative());
+    assert!(Decimal::<3>::ZERO.is_zero());
+    assert_eq!(Decimal::<3>::ONE.mantissa(), 1000);
+    assert_eq!(Decimal::<3>::SCALE, 3);
+}
+
+#[test]
+fn mul_div_test() {
+    let values: &[i128] = &[0, 1, -1, 3, -7, 10, 99, -125, 1000, 12345, -99999, 7777777];
+
+    for &l in values {
+        for &r in values {
+            let ld = Decimal::<2>::from_mantissa(l);
+            let rd = Decimal::<2>::from_mantissa(r);
+            for mode in MODES {
+                assert_eq!(
+                    ld.checked_mul(rd, mode).unwrap().mantissa(),
+                    div_round(l * r, 100, mode),
+                    "{ld} * {rd} {mode:?}",
+                );
+
+                if r != 0 {
+                    assert_eq!(
+                        ld.checked_div(rd, mode).unwrap().mantissa(),
+                        div_round(l * 100, r, mode),
+                        "{ld} / {rd} {mode:?}",
+                    );
+                } else {
+                    assert_eq!(ld.checked_div(rd, mode), None);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn mul_div_large_test() {
+    use RoundingMode as RM;
+
+    // the intermediate products of these don't fit in an i128
+    let big = Decimal::<18>::from_int(10i128.pow(19)).unwrap();
+    let half = Decimal::<18>::from_mantissa(5 * 10i128.pow(17));
+    let two = Decimal::<18>::from_int(2).unwrap();
+
+    assert_eq!(
+        big.checked_mul(half, RM::TowardZero),
+        Decimal::from_int(5 * 10i128.pow(18))
+    );
+    assert_eq!(
+        big.checked_div(two, RM::TowardZero),
+        Decimal::from_int(5 * 10i128.pow(18))
+    );
+    assert_eq!(big.checked_mul(big, RM::TowardZero), None);
+    assert_eq!(
+        big.checked_div(half, RM::TowardZero),
+        Decimal::from_int(2 * 10i128.pow(19))
+    );
+    assert_eq!(
+        big.checked_div(Decimal::from_mantissa(1), RM::TowardZero),
+        None
+    );
+
+    assert_eq!(
+        Decimal::<0>::MAX.checked_mul(Decimal::ONE, RM::Floor),
+        Some(Decimal::MAX)
+    );
+    assert_eq!(
+        Decimal::<0>::MIN.checked_mul(Decimal::ONE, RM::Floor),
+        Some(Decimal::MIN)
+    );
+    assert_eq!(
+        Decimal::<0>::MIN.checked_mul(Decimal::from_int(-1).unwrap(), RM::Floor),
+        None
+    );
+    assert_eq!(
+        Decimal::<0>::MIN.checked_div(Decimal::from_int(-1).unwrap(), RM::Floor),
+        None
+    );
+    assert_eq!(
+        Decimal::<38>::MAX.checked_mul(Decimal::<38>::MAX, RM::TowardZero),
+        None
+    );
+    assert_eq!(
+        Decimal::<38>::MAX.checked_mul(Decimal::<38>::ONE, RM::TowardZero),
+        Some(Decimal::MAX)
+    );
+}
+
+#[test]
+fn rescale_test() {
+    use RoundingMode as RM;
+
+    let num = Decimal::<2>::from_mantissa(-12345);
+
+    assert_eq!(num.rescale::<2>(RM::Floor), Some(num));
+    assert_eq!(num.rescale::<4>(RM::Floor).unwrap().mantissa(), -1234500);
+    assert_eq!(num.rescale::<1>(RM::Floor).unwrap().mantissa(), -1235);
+    assert_eq!(num.rescale::<1>(RM::Ceiling).unwrap().mantissa(), -1234);
+    assert_eq!(num.rescale::<0>(RM::HalfEven).unwrap().mantissa(), -123);
+    assert_eq!(num.rescale::<38>(RM::HalfEven), None);
+    assert_eq!(
+        Decimal::<38>::ONE
+            .rescale::<0>(RM::Floor)
+            .unwrap()
+            .mantissa(),
+        1
+    );
+}
+
+#[test]
+fn to_array_str_test() {
+    fn render<const S: u32>(mantissa: i128) -> String {
+        let num = Decimal::<S>::from_mantissa(mantissa);
+        macro_rules! with_lens {
+            ($($len:literal)*) => (
+                match num.to_array_str_len() {
+                    $($len => num.to_array_str::<$len>().as_str().to_string(),)*
+                    _ => unreachable!(),
+                }
+            )
+        }
+        let string = with_lens!(
+            1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20
+            21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42
+        );
+        assert_eq!(string, num.to_string());
+        assert_eq!(string, format!("{num:?}"));
+        string
+    }
+
+    assert_eq!(render::<0>(0), "0");
+    assert_eq!(render::<0>(-7), "-7");
+    assert_eq!(render::<0>(i128::MAX), i128::MAX.to_string());
+    assert_eq!(render::<0>(i128::MIN), i128::MIN.to_string());
+    assert_eq!(render::<2>(0), "0.00");
+    assert_eq!(render::<2>(5), "0.05");
+    assert_eq!(render::<2>(-5), "-0.05");
+    assert_eq!(render::<2>(-12345), "-123.45");
+    assert_eq!(render::<3>(1000), "1.000");
+    assert_eq!(
+        render::<38>(i128::MIN),
+        "-1.70141183460469231731687303715884105728"
+    );
+    assert_eq!(
+        render::<38>(i128::MAX),
+        "1.70141183460469231731687303715884105727"
+    );
+
+    must_panic(file_span!(), || {
+        Decimal::<2>::from_mantissa(5).to_array_str::<5>()
+    })
+    .unwrap();
+    must_panic(file_span!(), || {
+        Decimal::<2>::from_mantissa(5).to_array_str::<3>()
+    })
+    .unwrap();
+}
+
+#[cfg(feature = "cmp")]
+#[test]
+fn const_cmp_test() {
+    use core::cmp::Ordering;
+    use konst::cmp::{const_cmp, const_eq};
+
+    const A: Decimal<2> = Decimal::from_mantissa(-150);
+    const B: Decimal<2> = Decimal::from_mantissa(25);
+
+    const _: () = assert!(const_eq!(A, A));
+    const _: () = assert!(!const_eq!(A, B));
+    const _: () = assert!(matches!(const_cmp!(A, B), Ordering::Less));
+    const _: () = assert!(matches!(const_cmp!(B, A), Ordering::Greater));
+    const _: () = assert!(matches!(const_cmp!(B, B), Ordering::Equal));
+
+    assert_eq!(A.const_cmp(&B), A.cmp(&B));
+}
+
+#[cfg(feature = "parsing")]
+#[test]
+fn parse_test() {
+    use konst::parsing::{ErrorKind, Parser, parse_type};
+
+    for (string, mantissa, rem) in [
+        ("0", 0, ""),
+        ("-0", 0, ""),
+        ("+12", 1200, ""),
+        ("12.3", 1230, ""),
+        ("-12.34", -1234, ""),
+        ("12.340000", 1234, ""),
+        ("007.50x", 750, "x"),
+        ("1.5.", 150, "."),
+        ("1 ", 100, " "),
+        ("-1701411834604692317316873037158841057.28", i128::MIN, ""),
+        ("1701411834604692317316873037158841057.27", i128::MAX, ""),
+    ] {
+        let mut parser = Parser::new(string);
+        let num = parse_type!(parser, Decimal<2>).unwrap();
+        assert_eq!(num.mantissa(), mantissa, "{string:?}");
+        assert_eq!(parser.remainder(), rem, "{string:?}");
+    }
+
+    for (string, offset) in [
+        ("", 0),
+        ("-", 1),
+        ("+", 1),
+        (".5", 0),
+        ("-.5", 1),
+        ("1.", 2),
+        ("1.x", 2),
+        ("1.234", 4),
+        ("1.2301", 5),
+        ("1701411834604692317316873037158841057.28", 0),
+        ("-1701411834604692317316873037158841057.29", 0),
+        ("99999999999999999999999999999999999999999", 0),
+        ("9999999999999999999999999999999999999.99", 0),
+    ] {
+        let mut parser = Parser::with_start_offset(string, 5);
+        let err = parse_type!(parser, Decimal<2>).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseDecimal, "{string:?}");
+        assert_eq!(err.offset(), 5 + offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+
+    const PARSED: Decimal<4> =
+        konst::result::unwrap!(parse_type!(Parser::new("-3.1416"), Decimal<4>));
+    assert_eq!(PARSED.mantissa(), -31416);
+
+    assert_eq!(
+        parse_type!(Parser::new("42"), Decimal<0>)
+            .unwrap()
+            .mantissa(),
+        42
+    );
+    assert_eq!(
+        parse_type!(Parser::new("42.000"), Decimal<0>)
+            .unwrap()
+            .mantissa(),
+        42
+    );
+    assert!(parse_type!(Parser::new("42.1"), Decimal<0>).is_err());
+}
@@ -1,12 +1,32 @@
 mod parser_error_fmt_tests;
 
+mod bool_parsing_tests;
+
+mod byte_string_tests;
+
+mod datetime_tests;
+
+mod env_parse_tests;
+
+mod error_accumulator_tests;
+
+mod hex_parsing_tests;
+
+mod include_parse_tests;
+
+mod int_literal_tests;
+
 mod primitive_parsing_tests;
 
+mod range_parsing_tests;
+
 mod trim_and_strip_and_find;
 
 mod split_methods_tests;
 
 mod other_method_tests;
 
+mod uri_tests;
+
 #[cfg(feature = "parsing_proc")]
 mod parser_method_macro_tests;
@@ -0,0 +1,126 @@
+use konst::parsing::{BoolParser, EnvFlag, ErrorKind, ParseDirection, Parser, parse_type};
+
+#[test]
+fn parse_bool_with_strict_test() {
+    for (string, expected, rem) in [
+        ("true", true, ""),
+        ("false", false, ""),
+        ("truefoo", true, "foo"),
+        ("false,", false, ","),
+    ] {
+        let mut parser = Parser::new(string);
+        assert_eq!(parser.parse_bool_with(&BoolParser::STRICT), Ok(expected));
+        assert_eq!(parser.remainder(), rem);
+        assert_eq!(Parser::new(string).parse_bool(), Ok(expected));
+    }
+
+    for string in ["", "True", "FALSE", "yes", "1", " true"] {
+        let mut parser = Parser::with_start_offset(string, 10);
+        let err = parser.parse_bool_with(&BoolParser::STRICT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseBool, "{string:?}");
+        assert_eq!(err.offset(), 10, "{string:?}");
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_bool_with_lenient_test() {
+    for (string, expected, rem) in [
+        ("true", true, ""),
+        ("TRUE", true, ""),
+        ("False", false, ""),
+        ("yes", true, ""),
+        ("YeS", true, ""),
+        ("no", false, ""),
+        ("NO", false, ""),
+        ("on", true, ""),
+        ("On", true, ""),
+        ("off", false, ""),
+        ("OFF", false, ""),
+        ("oFf;", false, ";"),
+        ("1", true, ""),
+        ("0", false, ""),
+        ("10", true, "0"),
+        ("yes please", true, " please"),
+    ] {
+        let mut parser = Parser::new(string);
+        assert_eq!(
+            parser.parse_bool_with(&BoolParser::LENIENT),
+            Ok(expected),
+            "{string:?}"
+        );
+        assert_eq!(parser.remainder(), rem, "{string:?}");
+    }
+
+    for string in ["", "o", "of", "ye", "2", "-1", "enabled", " yes"] {
+        let mut parser = Parser::new(string);
+        let err = parser.parse_bool_with(&BoolParser::LENIENT).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseBool, "{string:?}");
+        assert_eq!(err.offset(), 0, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_bool_with_custom_table_test() {
+    const TABLE: &[(&str, bool)] = &[("", true), ("y", true), ("yep", true), ("nope", false)];
+
+    let sensitive = BoolParser::new(TABLE);
+    assert!(!sensitive.is_case_insensitive());
+    assert_eq!(sensitive.table(), TABLE);
+
+    let insensitive = sensitive.ignore_case(true);
+    assert!(insensitive.is_case_insensitive());
+
+    for (string, sensitive_res, insensitive_res) in [
+        ("yep", Ok((true, "")), Ok((true, ""))),
+        ("yes", Ok((true, "es")), Ok((true, "es"))),
+        ("YEP", Err(0), Ok((true, ""))),
+        ("yEp", Ok((true, "Ep")), Ok((true, ""))),
+        ("nope!", Ok((false, "!")), Ok((false, "!"))),
+        ("Nope", Err(0), Ok((false, ""))),
+        ("nop", Err(0), Err(0)),
+        ("", Err(0), Err(0)),
+    ] {
+        for (config, expected) in [(sensitive, sensitive_res), (insensitive, insensitive_res)] {
+            let mut parser = Parser::new(string);
+            let res = parser
+                .parse_bool_with(&config)
+                .map(|b| (b, parser.remainder()))
+                .map_err(|e| {
+                    assert_eq!(e.kind(), ErrorKind::ParseBool);
+                    e.offset()
+                });
+            assert_eq!(res, expected, "{string:?} {config:?}");
+        }
+    }
+
+    // non-ASCII keywords only match case-sensitively
+    let mut parser = Parser::new("SÍ");
+    let config = BoolParser::new(&[("sí", true)]).ignore_case(true);
+    assert!(parser.parse_bool_with(&config).is_err());
+    assert_eq!(Parser::new("SíX").parse_bool_with(&config), Ok(true));
+}
+
+#[test]
+fn env_flag_test() {
+    for (string, expected) in [
+        ("1", true),
+        ("0", false),
+        ("TRUE", true),
+        ("off", false),
+        ("Yes", true),
+    ] {
+        let mut parser = Parser::new(string);
+        assert_eq!(parse_type!(parser, EnvFlag), Ok(EnvFlag(expected)));
+        assert!(parser.is_empty());
+        assert_eq!(bool::from(EnvFlag(expected)), expected);
+    }
+
+    let mut parser = Parser::with_start_offset("maybe", 3);
+    let err = parse_type!(parser, EnvFlag).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseBool);
+    assert_eq!(err.offset(), 3);
+    assert_eq!(parser.remainder(), "maybe");
+}
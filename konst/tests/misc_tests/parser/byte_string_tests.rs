@@ -0,0 +1,91 @@
+use konst::parsing::{ByteString, ErrorKind, ParseDirection, Parser, byte_string};
+
+use crate::misc_tests::test_utils::must_panic;
+
+fn decode_dyn(lit: &ByteString<'_>) -> Vec<u8> {
+    macro_rules! with_lens {
+        ($($len:literal)*) => (
+            match lit.len() {
+                $($len => lit.decode::<$len>().to_vec(),)*
+                _ => unreachable!(),
+            }
+        )
+    }
+
+    with_lens!(0 1 2 3 4 5 6 7 8 9 10 11 12)
+}
+
+#[test]
+fn parse_byte_string_test() {
+    for (string, contents, decoded, rem) in [
+        (r#"b"""#, "", &b""[..], ""),
+        (r#"b"abc""#, "abc", b"abc", ""),
+        (r#"b"abc"def"#, "abc", b"abc", "def"),
+        (r#"b"\x7fELF\x02""#, r"\x7fELF\x02", b"\x7fELF\x02", ""),
+        (r#"b"\xFF\x00\xaB""#, r"\xFF\x00\xaB", b"\xFF\x00\xAB", ""),
+        (r#"b"\n\r\t\0""#, r"\n\r\t\0", b"\n\r\t\0", ""),
+        (r#"b"\\\"\'" "#, r#"\\\"\'"#, b"\\\"'", " "),
+        (r#"b"'""#, "'", b"'", ""),
+        ("b\"a\nb\"", "a\nb", b"a\nb", ""),
+        (r#"b"x" b"y""#, "x", b"x", r#" b"y""#),
+    ] {
+        let mut parser = Parser::new(string);
+        let lit = parser.parse_byte_string().unwrap();
+        assert_eq!(lit.contents(), contents, "{string:?}");
+        assert_eq!(lit.len(), decoded.len(), "{string:?}");
+        assert_eq!(lit.is_empty(), decoded.is_empty(), "{string:?}");
+        assert_eq!(decode_dyn(&lit), decoded, "{string:?}");
+        assert_eq!(parser.remainder(), rem, "{string:?}");
+    }
+}
+
+#[test]
+fn parse_byte_string_error_test() {
+    for (string, offset) in [
+        ("", 0),
+        ("b", 0),
+        ("\"abc\"", 0),
+        ("B\"abc\"", 0),
+        (" b\"abc\"", 0),
+        ("b'a'", 0),
+        ("b\"", 2),
+        ("b\"abc", 5),
+        (r#"b"abc\""#, 7),
+        (r#"b"\"#, 2),
+        (r#"b"a\q""#, 3),
+        (r#"b"a\x""#, 3),
+        (r#"b"a\x1""#, 3),
+        (r#"b"a\x1g""#, 3),
+        (r#"b"a\xg1""#, 3),
+        (r#"b"a\u{41}""#, 3),
+        (r#"b"a\N""#, 3),
+        ("b\"añ\"", 3),
+    ] {
+        let mut parser = Parser::with_start_offset(string, 100);
+        let err = parser.parse_byte_string().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseByteString, "{string:?}");
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(err.offset(), 100 + offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn decode_wrong_length_test() {
+    let lit = Parser::new(r#"b"a\x00b""#).parse_byte_string().unwrap();
+
+    assert_eq!(lit.decode::<3>(), [b'a', 0, b'b']);
+    must_panic(file_span!(), || lit.decode::<2>()).unwrap();
+    must_panic(file_span!(), || lit.decode::<4>()).unwrap();
+}
+
+#[test]
+fn byte_string_macro_test() {
+    const MAGIC: [u8; 5] = byte_string!(r#"b"\x7fELF\x02""#);
+    assert_eq!(MAGIC, *b"\x7fELF\x02");
+
+    const SPEC: &str = "b\"\\x89PNG\\r\\n\\x1a\\n\"";
+    assert_eq!(byte_string!(SPEC), *b"\x89PNG\r\n\x1a\n");
+
+    assert_eq!(byte_string!(r#"b"""#), [0u8; 0]);
+}
@@ -0,0 +1,211 @@
+use konst::parsing::{
+    ErrorKind, ParseDirection, Parser,
+    datetime::{Date, DateTime, Time, UtcOffset, days_in_month, is_leap_year},
+    parse_type,
+};
+
+#[test]
+fn leap_year_test() {
+    for year in 0..=9999u16 {
+        let expected = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        assert_eq!(is_leap_year(year), expected, "{year}");
+        assert_eq!(days_in_month(year, 2), Some(if expected { 29 } else { 28 }));
+    }
+}
+
+#[test]
+fn days_since_unix_epoch_test() {
+    // walks through every day in every year, checking that they're consecutive
+    let mut expected = Date::new(0, 1, 1).unwrap().days_since_unix_epoch();
+    assert_eq!(expected, -719528);
+
+    for year in 0..=9999u16 {
+        for month in 1..=12 {
+            for day in 1..=days_in_month(year, month).unwrap() {
+                let date = Date::new(year, month, day).unwrap();
+                assert_eq!(date.days_since_unix_epoch(), expected, "{date:?}");
+                assert_eq!(date.unix_timestamp(), expected * 86400);
+                expected += 1;
+            }
+            let next_day = days_in_month(year, month).unwrap() + 1;
+            assert_eq!(Date::new(year, month, next_day), None);
+        }
+    }
+
+    assert_eq!(Date::new(1970, 1, 1).unwrap().days_since_unix_epoch(), 0);
+}
+
+#[test]
+fn parse_date_test() {
+    for (string, (year, month, day), rem) in [
+        ("0000-01-01", (0, 1, 1), ""),
+        ("9999-12-31", (9999, 12, 31), ""),
+        ("2024-02-29T", (2024, 2, 29), "T"),
+        ("2000-02-29", (2000, 2, 29), ""),
+        ("2023-04-30", (2023, 4, 30), ""),
+    ] {
+        let mut parser = Parser::new(string);
+        parser.skip_back(0);
+        let date = parse_type!(parser, Date).unwrap();
+        assert_eq!(date, Date::new(year, month, day).unwrap());
+        assert_eq!(parser.remainder(), rem);
+    }
+
+    for (string, offset) in [
+        ("", 0),
+        ("202", 0),
+        ("2a23-01-01", 0),
+        ("2023/01-01", 4),
+        ("2023-1-01", 5),
+        ("2023-00-01", 5),
+        ("2023-13-01", 5),
+        ("2023-01_01", 7),
+        ("2023-01-00", 8),
+        ("2023-01-32", 8),
+        ("2023-02-29", 8),
+        ("1900-02-29", 8),
+        ("2023-04-31", 8),
+        ("2023-04-3", 8),
+    ] {
+        let mut parser = Parser::with_start_offset(string, 10);
+        parser.skip_back(0);
+        let err = parse_type!(parser, Date).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseDateTime, "{string:?}");
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(err.offset(), 10 + offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_time_test() {
+    for (string, (hour, minute, second, nanos), rem) in [
+        ("00:00:00", (0, 0, 0, 0), ""),
+        ("23:59:59", (23, 59, 59, 0), ""),
+        ("12:34:56.7", (12, 34, 56, 700_000_000), ""),
+        ("12:34:56.123456789Z", (12, 34, 56, 123_456_789), "Z"),
+        ("12:34:56.000001+", (12, 34, 56, 1_000), "+"),
+        ("12:34:56 .5", (12, 34, 56, 0), " .5"),
+    ] {
+        let mut parser = Parser::new(string);
+        let time = parse_type!(parser, Time).unwrap();
+        assert_eq!(time, Time::new(hour, minute, second, nanos).unwrap());
+        assert_eq!(parser.remainder(), rem);
+    }
+
+    assert_eq!(
+        Time::new(1, 2, 3, 0).unwrap().seconds_since_midnight(),
+        3723
+    );
+
+    for (string, offset) in [
+        ("", 0),
+        ("24:00:00", 0),
+        ("1:00:00", 0),
+        ("00-00:00", 2),
+        ("00:60:00", 3),
+        ("00:00:60", 6),
+        ("00:00:0", 6),
+        ("00:00:00.", 9),
+        ("00:00:00.a", 9),
+        ("00:00:00.1234567890", 9),
+    ] {
+        let mut parser = Parser::new(string);
+        let err = parse_type!(parser, Time).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseDateTime, "{string:?}");
+        assert_eq!(err.offset(), offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_offset_test() {
+    for (string, minutes) in [
+        ("Z", 0),
+        ("z", 0),
+        ("+00:00", 0),
+        ("-00:00", 0),
+        ("+23:59", 1439),
+        ("-23:59", -1439),
+        ("+05:45", 345),
+    ] {
+        let offset = parse_type!(Parser::new(string), UtcOffset).unwrap();
+        assert_eq!(offset.minutes(), minutes);
+        assert_eq!(UtcOffset::from_minutes(minutes), Some(offset));
+    }
+
+    assert_eq!(UtcOffset::from_minutes(1440), None);
+    assert_eq!(UtcOffset::from_minutes(-1440), None);
+
+    for (string, offset) in [
+        ("", 0),
+        ("05:00", 0),
+        ("+24:00", 1),
+        ("+0500", 3),
+        ("+05:60", 4),
+    ] {
+        let mut parser = Parser::new(string);
+        let err = parse_type!(parser, UtcOffset).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseDateTime, "{string:?}");
+        assert_eq!(err.offset(), offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_datetime_test() {
+    for (string, unix_timestamp) in [
+        ("1970-01-01T00:00:00Z", 0),
+        ("1970-01-01t00:00:00z", 0),
+        ("1970-01-01 00:00:00+00:00", 0),
+        ("1969-12-31T19:00:00-05:00", 0),
+        ("2001-09-09T01:46:40Z", 1_000_000_000),
+        ("2038-01-19T03:14:08Z", 1 << 31),
+        ("1901-12-13T20:45:52Z", -(1 << 31)),
+        ("2024-02-29T23:59:59.999999999+14:00", 1709200799),
+        ("0000-01-01T00:00:00Z", -62167219200),
+        ("9999-12-31T23:59:59Z", 253402300799),
+    ] {
+        let mut parser = Parser::new(string);
+        let datetime = parse_type!(parser, DateTime).unwrap();
+        assert_eq!(datetime.unix_timestamp(), unix_timestamp, "{string:?}");
+        assert!(parser.is_empty());
+    }
+
+    {
+        let datetime = parse_type!(Parser::new("2024-06-15T08:30:05.25-03:30"), DateTime).unwrap();
+        assert_eq!(datetime.date(), Date::new(2024, 6, 15).unwrap());
+        assert_eq!(datetime.time(), Time::new(8, 30, 5, 250_000_000).unwrap());
+        assert_eq!(datetime.offset(), UtcOffset::from_minutes(-210).unwrap());
+        assert_eq!(
+            datetime,
+            DateTime::new(datetime.date(), datetime.time(), datetime.offset())
+        );
+    }
+
+    for (string, offset) in [
+        ("", 0),
+        ("2023-02-29T00:00:00Z", 8),
+        ("2023-02-28_00:00:00Z", 10),
+        ("2023-02-28T24:00:00Z", 11),
+        ("2023-02-28T00:00:00", 19),
+        ("2023-02-28T00:00:00.Z", 20),
+        ("2023-02-28T00:00:00+1:00", 20),
+    ] {
+        let mut parser = Parser::new(string);
+        let err = parse_type!(parser, DateTime).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseDateTime, "{string:?}");
+        assert_eq!(err.offset(), offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn const_parse_test() {
+    const DT: DateTime = {
+        let mut parser = Parser::new("2015-10-21T16:29:00-07:00");
+        konst::result::unwrap!(parse_type!(parser, DateTime))
+    };
+
+    assert_eq!(DT.unix_timestamp(), 1445470140);
+}
@@ -0,0 +1,52 @@
+use konst::parsing::{EnvFlag, HasParser, ParseError, Parser};
+
+#[test]
+fn env_parse_set_test() {
+    const MAJOR: u64 = konst::env_parse!(u64, "CARGO_PKG_VERSION_MAJOR");
+    const MINOR: u32 = konst::env_parse!(u32, "CARGO_PKG_VERSION_MINOR", default = 1000);
+
+    assert_eq!(
+        MAJOR,
+        env!("CARGO_PKG_VERSION_MAJOR").parse::<u64>().unwrap()
+    );
+    assert_eq!(
+        MINOR,
+        env!("CARGO_PKG_VERSION_MINOR").parse::<u32>().unwrap()
+    );
+
+    const NAME: PkgName = konst::env_parse!(PkgName, "CARGO_PKG_NAME",);
+    assert_eq!(NAME, PkgName::Konst);
+}
+
+#[test]
+fn env_parse_default_test() {
+    const PORT: u16 = konst::env_parse!(u16, "KONST_TEST_UNSET_PORT", default = 8080);
+    const FLAG: EnvFlag =
+        konst::env_parse!(EnvFlag, "KONST_TEST_UNSET_FLAG", default = EnvFlag(true),);
+    const NAME: PkgName =
+        konst::env_parse!(PkgName, "KONST_TEST_UNSET_NAME", default = PkgName::Other);
+
+    assert_eq!(PORT, 8080);
+    assert_eq!(FLAG, EnvFlag(true));
+    assert_eq!(NAME, PkgName::Other);
+}
+
+#[derive(Debug, PartialEq)]
+enum PkgName {
+    Konst,
+    Other,
+}
+
+impl HasParser for PkgName {
+    type Parser = Self;
+}
+
+impl PkgName {
+    const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        if parser.strip_prefix("konst").is_ok() {
+            Ok(PkgName::Konst)
+        } else {
+            Err(parser.to_other_error(&"expected `konst`"))
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use konst::parsing::{ErrorKind, ParseErrors, Parser};
+
+use crate::misc_tests::test_utils::must_panic;
+
+fn panic_message<const CAP: usize>(errors: &ParseErrors<'_, CAP>) -> String {
+    let err = must_panic(file_span!(), || errors.panic_if_any()).unwrap();
+    err.downcast_ref::<String>().unwrap().clone()
+}
+
+const fn parse_lines<const CAP: usize>(input: &str) -> (ParseErrors<'_, CAP>, u32) {
+    let mut errors = ParseErrors::new();
+    let mut parser = Parser::new(input);
+    let mut sum = 0;
+
+    while !parser.is_empty() {
+        match parser.parse_u32() {
+            Ok(x) => {
+                sum += x;
+                _ = parser.skip_past('\n');
+            }
+            Err(e) => errors.recover(&mut parser, e, '\n'),
+        }
+    }
+
+    (errors, sum)
+}
+
+#[test]
+fn accumulate_test() {
+    let (errors, sum) = parse_lines::<4>("1\n2\n3");
+    assert!(errors.is_empty());
+    assert!(!errors.has_errors());
+    assert!(!errors.is_full());
+    assert_eq!(errors.len(), 0);
+    assert_eq!(errors.total(), 0);
+    assert_eq!(errors.as_slice(), &[]);
+    assert_eq!(sum, 6);
+    errors.panic_if_any();
+
+    let (errors, sum) = parse_lines::<4>("1\nfoo\n3\nbar\n");
+    assert!(!errors.is_empty());
+    assert!(errors.has_errors());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.total(), 2);
+    assert_eq!(sum, 4);
+    assert_eq!(
+        errors
+            .as_slice()
+            .iter()
+            .map(|e| (e.kind(), e.offset()))
+            .collect::<Vec<_>>(),
+        [(ErrorKind::ParseInteger, 2), (ErrorKind::ParseInteger, 8)],
+    );
+
+    let (errors, sum) = parse_lines::<2>("a\nb\n5\nc\nd");
+    assert!(errors.is_full());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors.total(), 4);
+    assert_eq!(sum, 5);
+    assert_eq!(
+        errors
+            .as_slice()
+            .iter()
+            .map(|e| e.offset())
+            .collect::<Vec<_>>(),
+        [0, 2],
+    );
+
+    let (errors, _) = parse_lines::<0>("a\nb");
+    assert!(errors.is_full());
+    assert_eq!(errors.len(), 0);
+    assert_eq!(errors.total(), 2);
+    assert!(errors.is_empty());
+    assert!(errors.has_errors());
+}
+
+#[test]
+fn record_test() {
+    let mut errors = ParseErrors::<'_, 3>::default();
+
+    assert_eq!(errors.record(Parser::new("10").parse_u8()), Some(10));
+    assert!(errors.is_empty());
+    assert!(!errors.has_errors());
+
+    assert_eq!(errors.record(Parser::new("256").parse_u8()), None);
+    assert_eq!(errors.record(Parser::new("tru").parse_bool()), None);
+    assert_eq!(errors.total(), 2);
+    assert_eq!(errors.as_slice()[0].kind(), ErrorKind::ParseInteger);
+    assert_eq!(errors.as_slice()[1].kind(), ErrorKind::ParseBool);
+
+    errors.push(Parser::new("").to_error(ErrorKind::Find));
+    errors.push(Parser::new("").to_error(ErrorKind::Strip));
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors.total(), 4);
+    assert_eq!(errors.as_slice()[2].kind(), ErrorKind::Find);
+}
+
+#[test]
+fn skip_past_test() {
+    let mut parser = Parser::with_start_offset("ab,,cd", 10);
+
+    parser.skip_past(',');
+    assert_eq!(parser.remainder(), ",cd");
+    assert_eq!(parser.start_offset(), 13);
+
+    parser.skip_past(",");
+    assert_eq!(parser.remainder(), "cd");
+    assert_eq!(parser.start_offset(), 14);
+
+    parser.skip_past(",");
+    assert_eq!(parser.remainder(), "");
+    assert_eq!(parser.start_offset(), 16);
+    assert_eq!(parser.end_offset(), 16);
+}
+
+#[test]
+fn panic_message_test() {
+    let (errors, _) = parse_lines::<4>("1\nfoo\n3");
+    assert_eq!(
+        panic_message(&errors),
+        "found 1 parse error:\n  \
+         - error from the start at the 2 byte offset while parsing an integer",
+    );
+
+    let (errors, _) = parse_lines::<4>("x\n2\ny");
+    assert_eq!(
+        panic_message(&errors),
+        "found 2 parse errors:\n  \
+         - error from the start at the 0 byte offset while parsing an integer\n  \
+         - error from the start at the 4 byte offset while parsing an integer",
+    );
+
+    let (errors, _) = parse_lines::<1>("x\ny\nz");
+    assert_eq!(
+        panic_message(&errors),
+        "found 3 parse errors:\n  \
+         - error from the start at the 0 byte offset while parsing an integer\n  \
+         ... and 2 more",
+    );
+
+    let (errors, _) = parse_lines::<0>("x\ny");
+    assert_eq!(panic_message(&errors), "found 2 parse errors:");
+}
@@ -0,0 +1,141 @@
+use konst::parsing::{ErrorKind, ParseDirection, Parser};
+
+#[test]
+fn parse_hex_array_test() {
+    for (string, rem) in [
+        ("0123456789abcdef", ""),
+        ("0x0123456789ABCDEF", ""),
+        ("0X0123456789aBcDeF;", ";"),
+        ("0123456789abcdef0", "0"),
+    ] {
+        let mut parser = Parser::new(string);
+        parser.skip_back(0);
+        assert_eq!(
+            parser.parse_hex_array::<8>().unwrap(),
+            [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef],
+        );
+        assert_eq!(parser.remainder(), rem);
+        assert_eq!(parser.parse_direction(), ParseDirection::FromStart);
+    }
+
+    assert_eq!(Parser::new("").parse_hex_array::<0>().unwrap(), []);
+    assert_eq!(Parser::new("0x").parse_hex_array::<0>().unwrap(), []);
+}
+
+#[test]
+fn parse_hex_array_err_test() {
+    for (string, offset) in [
+        ("", 0),
+        ("0x", 2),
+        ("0", 1),
+        ("001", 3),
+        ("00112", 5),
+        ("g0112233", 0),
+        ("0g112233", 1),
+        ("0x0g112233", 3),
+        ("001122 3", 6),
+        ("0011223G", 7),
+        ("00112233", 8),
+        ("0011223ñ", 7),
+    ] {
+        let mut parser = Parser::with_start_offset(string, 100);
+        let err = parser.parse_hex_array::<5>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseHex, "{string:?}");
+        assert_eq!(err.offset(), 100 + offset, "{string:?}");
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(parser.remainder(), string);
+        assert_eq!(parser.start_offset(), 100);
+    }
+}
+
+#[test]
+fn parse_hex_array_separated_test() {
+    for (string, rem) in [
+        ("de:ad:be:ef", ""),
+        ("0xDE:AD:BE:EF:", ":"),
+        ("de:ad:be:efff", "ff"),
+    ] {
+        let mut parser = Parser::new(string);
+        assert_eq!(
+            parser.parse_hex_array_separated::<4, _>(':').unwrap(),
+            [0xde, 0xad, 0xbe, 0xef],
+        );
+        assert_eq!(parser.remainder(), rem);
+    }
+
+    {
+        let mut parser = Parser::new("de, ad, be, ef");
+        assert_eq!(
+            parser.parse_hex_array_separated::<4, _>(", ").unwrap(),
+            [0xde, 0xad, 0xbe, 0xef],
+        );
+        assert!(parser.is_empty());
+    }
+
+    for (string, offset) in [
+        ("", 0),
+        ("de:", 3),
+        ("de;ad:be", 2),
+        ("de:ad:b", 7),
+        ("de:ad::be", 6),
+        ("dead:be", 2),
+        ("0xde:ax:be", 6),
+    ] {
+        let mut parser = Parser::new(string);
+        let err = parser.parse_hex_array_separated::<3, _>(':').unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseHex, "{string:?}");
+        assert_eq!(err.offset(), offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn parse_uuid_test() {
+    const EXPECTED: [u8; 16] = [
+        0x12, 0x3e, 0x45, 0x67, 0xe8, 0x9b, 0x12, 0xd3, 0xa4, 0x56, 0x42, 0x66, 0x14, 0x17, 0x40,
+        0x00,
+    ];
+
+    for (string, rem) in [
+        ("123e4567-e89b-12d3-a456-426614174000", ""),
+        ("123E4567-E89B-12D3-A456-426614174000", ""),
+        ("123e4567-e89b-12d3-a456-426614174000}", "}"),
+    ] {
+        let mut parser = Parser::new(string);
+        assert_eq!(parser.parse_uuid().unwrap(), EXPECTED);
+        assert_eq!(parser.remainder(), rem);
+    }
+
+    for (string, offset) in [
+        ("", 0),
+        ("0x123e4567-e89b-12d3-a456-426614174000", 1),
+        ("123e4567e89b-12d3-a456-426614174000", 8),
+        ("123e4567-e89b_12d3-a456-426614174000", 13),
+        ("123e4567-e89b-12d3-a456426614174000", 23),
+        ("123e4567-e89b-12d3-a456-42661417400", 35),
+        ("123e4567-e89b-12d3-a456-42661417400z", 35),
+        ("123e4567-e89b-12d3-a4 6-426614174000", 21),
+        ("123e456-7e89b-12d3-a456-426614174000", 7),
+    ] {
+        let mut parser = Parser::new(string);
+        let err = parser.parse_uuid().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseUuid, "{string:?}");
+        assert_eq!(err.offset(), offset, "{string:?}");
+        assert_eq!(parser.remainder(), string);
+    }
+}
+
+#[test]
+fn hex_macros_test() {
+    const KEY: [u8; 4] = konst::parsing::hex_array!("0xc0ffee00");
+    assert_eq!(KEY, [0xc0, 0xff, 0xee, 0x00]);
+
+    const MAC: [u8; 3] = konst::parsing::hex_array!("c0 ff ee", ' ');
+    assert_eq!(MAC, [0xc0, 0xff, 0xee]);
+
+    const EMPTY: [u8; 0] = konst::parsing::hex_array!("");
+    assert_eq!(EMPTY, []);
+
+    const ID: [u8; 16] = konst::parsing::uuid!("00000000-0000-0000-0000-0000000000FF");
+    assert_eq!(ID, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff]);
+}
@@ -0,0 +1,29 @@
+use konst::parsing::{EnvFlag, HasParser, ParseError, Parser, include_parse};
+
+#[test]
+fn include_parse_test() {
+    const VERSION: Version = include_parse!(Version, "version.txt");
+    assert_eq!(VERSION, Version(1, 84, 0));
+
+    // `flag.txt` ends with "\r\n\n", trailing whitespace is ignored
+    const FLAG: EnvFlag = include_parse!(EnvFlag, "./flag.txt",);
+    assert_eq!(FLAG, EnvFlag(true));
+}
+
+#[derive(Debug, PartialEq)]
+struct Version(u16, u16, u16);
+
+impl HasParser for Version {
+    type Parser = Self;
+}
+
+impl Version {
+    const fn parse_with<'p>(parser: &mut Parser<'p>) -> Result<Self, ParseError<'p>> {
+        let major = konst::try_!(parser.parse_u16());
+        konst::try_!(parser.strip_prefix('.'));
+        let minor = konst::try_!(parser.parse_u16());
+        konst::try_!(parser.strip_prefix('.'));
+        let patch = konst::try_!(parser.parse_u16());
+        Ok(Version(major, minor, patch))
+    }
+}
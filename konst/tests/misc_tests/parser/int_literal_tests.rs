@@ -0,0 +1,178 @@
+use konst::parsing::{ErrorKind, IntLiteral, IntSuffix, ParseDirection, Parser, parse_type};
+
+type Parsed<'a> = (Option<i128>, Option<u128>, u32, Option<IntSuffix>, &'a str);
+
+fn parse(string: &str) -> Result<Parsed<'_>, usize> {
+    let mut parser = Parser::with_start_offset(string, 0);
+    match parser.parse_int_literal() {
+        Ok(lit) => Ok((
+            lit.to_i128(),
+            lit.to_u128(),
+            lit.radix(),
+            lit.suffix(),
+            parser.remainder(),
+        )),
+        Err(e) => {
+            assert_eq!(e.kind(), ErrorKind::ParseInteger, "{string:?}");
+            assert_eq!(e.error_direction(), ParseDirection::FromStart);
+            assert_eq!(parser.remainder(), string);
+            Err(e.offset())
+        }
+    }
+}
+
+#[test]
+fn parse_int_literal_test() {
+    use IntSuffix as S;
+
+    for (string, expected) in [
+        ("0", (Some(0), Some(0), 10, None, "")),
+        ("007", (Some(7), Some(7), 10, None, "")),
+        ("1_000u32", (Some(1000), Some(1000), 10, Some(S::U32), "")),
+        ("1_000_", (Some(1000), Some(1000), 10, None, "")),
+        ("255_u8", (Some(255), Some(255), 10, Some(S::U8), "")),
+        ("255u8+1", (Some(255), Some(255), 10, Some(S::U8), "+1")),
+        ("0xFFi8", (Some(-1), None, 16, Some(S::I8), "")),
+        ("0x80i8", (Some(-128), None, 16, Some(S::I8), "")),
+        ("0x7Fi8", (Some(127), Some(127), 16, Some(S::I8), "")),
+        ("0xffu8", (Some(255), Some(255), 16, Some(S::U8), "")),
+        (
+            "0x_dead_BEEF",
+            (Some(0xdead_beef), Some(0xdead_beef), 16, None, ""),
+        ),
+        ("0o777u16", (Some(0o777), Some(0o777), 8, Some(S::U16), "")),
+        ("0b1010_1010", (Some(0xAA), Some(0xAA), 2, None, "")),
+        ("0b1111_1111_i8", (Some(-1), None, 2, Some(S::I8), "")),
+        ("-1", (Some(-1), None, 10, None, "")),
+        ("-0", (Some(0), Some(0), 10, None, "")),
+        ("-0u8", (Some(0), Some(0), 10, Some(S::U8), "")),
+        ("-128i8", (Some(-128), None, 10, Some(S::I8), "")),
+        ("-0x80i8", (Some(-128), None, 16, Some(S::I8), "")),
+        ("127i8", (Some(127), Some(127), 10, Some(S::I8), "")),
+        ("65535u16", (Some(65535), Some(65535), 10, Some(S::U16), "")),
+        ("-32768i16", (Some(-32768), None, 10, Some(S::I16), "")),
+        (
+            "4294967295u32",
+            (Some(4294967295), Some(4294967295), 10, Some(S::U32), ""),
+        ),
+        (
+            "-2147483648i32",
+            (Some(-2147483648), None, 10, Some(S::I32), ""),
+        ),
+        (
+            "0xFFFF_FFFF_FFFF_FFFFi64",
+            (Some(-1), None, 16, Some(S::I64), ""),
+        ),
+        (
+            "18446744073709551615u64",
+            (
+                Some(u64::MAX.into()),
+                Some(u64::MAX.into()),
+                10,
+                Some(S::U64),
+                "",
+            ),
+        ),
+        (
+            "340282366920938463463374607431768211455",
+            (None, Some(u128::MAX), 10, None, ""),
+        ),
+        (
+            "340282366920938463463374607431768211455u128",
+            (None, Some(u128::MAX), 10, Some(S::U128), ""),
+        ),
+        (
+            "-170141183460469231731687303715884105728",
+            (Some(i128::MIN), None, 10, None, ""),
+        ),
+        (
+            "0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFFi128",
+            (Some(-1), None, 16, Some(S::I128), ""),
+        ),
+        ("3usize", (Some(3), Some(3), 10, Some(S::Usize), "")),
+        ("-3isize", (Some(-3), None, 10, Some(S::Isize), "")),
+        ("12 u8", (Some(12), Some(12), 10, None, " u8")),
+        ("12.5", (Some(12), Some(12), 10, None, ".5")),
+    ] {
+        assert_eq!(parse(string), Ok(expected), "{string:?}");
+    }
+}
+
+#[test]
+fn parse_int_literal_error_test() {
+    for (string, offset) in [
+        ("", 0),
+        ("-", 0),
+        ("_1", 0),
+        ("-_1", 0),
+        ("u8", 0),
+        ("0x", 0),
+        ("0x_", 0),
+        ("0xu8", 0),
+        ("0b", 0),
+        ("0b102", 4),
+        ("0o78", 3),
+        ("12ab", 2),
+        ("10f32", 2),
+        ("1u7", 1),
+        ("1u8x", 1),
+        ("1_u", 2),
+        ("0X1", 1),
+        ("256u8", 0),
+        ("-1u8", 0),
+        ("128i8", 0),
+        ("-129i8", 0),
+        ("0x100i8", 0),
+        ("-0xFFi8", 0),
+        ("65536u16", 0),
+        ("32768i16", 0),
+        ("4294967296u32", 0),
+        ("340282366920938463463374607431768211456", 0),
+        ("-170141183460469231731687303715884105729", 0),
+        ("170141183460469231731687303715884105728i128", 0),
+    ] {
+        assert_eq!(parse(string), Err(offset), "{string:?}");
+    }
+
+    let mut parser = Parser::with_start_offset("0b12", 10);
+    assert_eq!(parser.parse_int_literal().unwrap_err().offset(), 13);
+}
+
+#[test]
+fn int_suffix_test() {
+    use IntSuffix as S;
+
+    for (suffix, string, signed, bits) in [
+        (S::U8, "u8", false, 8),
+        (S::U16, "u16", false, 16),
+        (S::U32, "u32", false, 32),
+        (S::U64, "u64", false, 64),
+        (S::U128, "u128", false, 128),
+        (S::Usize, "usize", false, usize::BITS),
+        (S::I8, "i8", true, 8),
+        (S::I16, "i16", true, 16),
+        (S::I32, "i32", true, 32),
+        (S::I64, "i64", true, 64),
+        (S::I128, "i128", true, 128),
+        (S::Isize, "isize", true, usize::BITS),
+    ] {
+        assert_eq!(suffix.as_str(), string);
+        assert_eq!(suffix.is_signed(), signed);
+        assert_eq!(suffix.bits(), bits);
+
+        let lit = Parser::new(&format!("1{string}"))
+            .parse_int_literal()
+            .unwrap();
+        assert_eq!(lit.suffix(), Some(suffix));
+    }
+}
+
+#[test]
+fn int_literal_parse_type_test() {
+    let mut parser = Parser::new("-0x10i32,");
+    let lit: IntLiteral = parse_type!(parser, IntLiteral).unwrap();
+    assert!(lit.is_negative());
+    assert_eq!(lit.magnitude(), 16);
+    assert_eq!(lit.to_i128(), Some(-16));
+    assert_eq!(parser.remainder(), ",");
+}
@@ -30,6 +30,12 @@ fn parser_error_fmt_equiv() {
     for error_kind in [
         ErrorKind::ParseInteger,
         ErrorKind::ParseBool,
+        ErrorKind::ParseHex,
+        ErrorKind::ParseUuid,
+        ErrorKind::ParseDateTime,
+        ErrorKind::ParseUri,
+        ErrorKind::ParseDecimal,
+        ErrorKind::ParseByteString,
         ErrorKind::Find,
         ErrorKind::Strip,
         ErrorKind::SplitExhausted,
@@ -84,3 +90,147 @@ fn parser_error_fmt_equiv() {
         }
     }
 }
+
+#[test]
+fn expected_one_of_fmt_test() {
+    const MANY: &[&str] = &[
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q",
+    ];
+
+    let mut parser = Parser::with_start_offset("foo", 3);
+    parser.trim_start();
+
+    for (expected, suffix) in [
+        (&[][..], ""),
+        (&["red"][..], r#": expected "red""#),
+        (
+            &["red", "a\"b", ""][..],
+            r#": expected one of "red", "a\"b", """#,
+        ),
+        (
+            &MANY[..16],
+            r#": expected one of "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p""#,
+        ),
+        (
+            MANY,
+            r#": expected one of "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", ..."#,
+        ),
+    ] {
+        let pe = konst::parsing::ParseError::expected_one_of(&parser, ErrorKind::Find, expected);
+        assert_eq!(pe.expected(), expected);
+        assert_eq!(pe.kind(), ErrorKind::Find);
+        assert_eq!(pe.offset(), 3);
+
+        assert_eq!(
+            pe.to_string(),
+            format!(
+                "error from the start at the 3 byte offset \
+                 while trying to find and skip a pattern{suffix}"
+            ),
+        );
+
+        for fmtarg in [
+            FmtArg::DEBUG,
+            FmtArg::ALT_DEBUG,
+            FmtArg::DISPLAY,
+            FmtArg::ALT_DISPLAY,
+        ] {
+            let expected = match (fmtarg.fmt_kind, fmtarg.is_alternate) {
+                (const_panic::fmt::FmtKind::Debug, false) => format!("{pe:?}"),
+                (const_panic::fmt::FmtKind::Debug, true) => format!("{pe:#?}"),
+                (_, false) => format!("{pe}"),
+                (_, true) => format!("{pe:#}"),
+            };
+            assert_eq!(
+                ArrayString::<1024>::from_panicvals(&pe.to_panicvals(fmtarg)).unwrap(),
+                *expected,
+            );
+        }
+    }
+
+    assert_eq!(
+        Parser::new("").to_error(ErrorKind::Strip).expected(),
+        [""; 0]
+    );
+}
+
+#[test]
+fn suggestion_fmt_test() {
+    let parser = Parser::new("colour");
+    let plain = parser.to_error(ErrorKind::Strip);
+
+    assert_eq!(plain.suggestion(), None);
+    assert_eq!(plain.copy().with_suggestion(&"").suggestion(), None);
+
+    for (pe, suffix) in [
+        (
+            plain.copy().with_suggestion(&"color"),
+            r#"; did you mean "color"?"#,
+        ),
+        (
+            konst::parsing::ParseError::expected_one_of(
+                &parser,
+                ErrorKind::Strip,
+                &["color", "size"],
+            )
+            .with_suggestion(&"color"),
+            r#": expected one of "color", "size"; did you mean "color"?"#,
+        ),
+        (
+            plain
+                .copy()
+                .with_suggestion(&"a\"b")
+                .with_suggestion(&"c\"d"),
+            r#"; did you mean "c\"d"?"#,
+        ),
+        (
+            plain.copy().with_suggestion(&"color").with_suggestion(&""),
+            "",
+        ),
+    ] {
+        assert_eq!(pe.kind(), ErrorKind::Strip);
+        assert_eq!(
+            pe.to_string(),
+            format!(
+                "error from the start at the 0 byte offset while trying to strip a pattern{suffix}"
+            ),
+        );
+
+        for fmtarg in [
+            FmtArg::DEBUG,
+            FmtArg::ALT_DEBUG,
+            FmtArg::DISPLAY,
+            FmtArg::ALT_DISPLAY,
+        ] {
+            let expected = match (fmtarg.fmt_kind, fmtarg.is_alternate) {
+                (const_panic::fmt::FmtKind::Debug, false) => format!("{pe:?}"),
+                (const_panic::fmt::FmtKind::Debug, true) => format!("{pe:#?}"),
+                (_, false) => format!("{pe}"),
+                (_, true) => format!("{pe:#}"),
+            };
+            assert_eq!(
+                ArrayString::<1024>::from_panicvals(&pe.to_panicvals(fmtarg)).unwrap(),
+                *expected,
+            );
+        }
+    }
+}
+
+#[test]
+fn suggestion_with_other_error_test() {
+    let pe = Parser::new("colour")
+        .to_other_error(&"unknown key")
+        .with_suggestion(&"color");
+
+    assert_eq!(pe.suggestion(), Some("color"));
+    assert_eq!(
+        pe.to_string(),
+        r#"error from the start at the 0 byte offset: unknown key; did you mean "color"?"#,
+    );
+}
+
+#[test]
+fn parse_error_size_test() {
+    // the parts that only some errors have are mostly behind references
+    assert!(core::mem::size_of::<konst::parsing::ParseError<'_>>() <= 48);
+}
@@ -237,3 +237,128 @@ fn find_skip_test() {
     wooa_pat("wooa-that-", "-that-", 3);
     wooa_pat("woo-that-", "woo-that-", 8);
 }
+
+#[test]
+fn expected_error_test() {
+    use konst::parsing::{ErrorKind, ParseDirection, ParseError};
+
+    #[derive(Debug, PartialEq)]
+    enum Color {
+        Red,
+        Blue,
+    }
+
+    const fn strip_prefix<'p>(parser: &mut Parser<'p>) -> Result<Color, ParseError<'p>> {
+        parser_method! {parser, strip_prefix;
+            "red" | "Red" => Ok(Color::Red),
+            concat!("bl", "ue") => Ok(Color::Blue)
+        }
+    }
+    const fn strip_suffix<'p>(parser: &mut Parser<'p>) -> Result<Color, ParseError<'p>> {
+        parser_method! {parser, strip_suffix;
+            "red" | "Red" => Ok(Color::Red),
+            stringify!(blue) => {
+                Ok(Color::Blue)
+            }
+        }
+    }
+    fn find_skip<'p>(parser: &mut Parser<'p>) -> Result<u32, ParseError<'p>> {
+        parser_method! {*parser, find_skip;
+            "\"" => Ok(0),
+            r"\" => Ok(1),
+        }
+    }
+    fn rfind_skip<'p>(parser: &mut Parser<'p>) -> Result<u32, ParseError<'p>> {
+        parser_method! {*parser, rfind_skip; "x" => Ok(0),}
+    }
+
+    const RED: &[&str] = &["red", "Red", "blue"];
+
+    {
+        let mut parser = Parser::new("Redblue");
+        assert_eq!(strip_prefix(&mut parser), Ok(Color::Red));
+        assert_eq!(strip_prefix(&mut parser), Ok(Color::Blue));
+
+        let mut parser = Parser::with_start_offset("purple", 4);
+        let err = strip_prefix(&mut parser).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Strip);
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(err.offset(), 4);
+        assert_eq!(err.expected(), RED);
+        assert_eq!(parser.remainder(), "purple");
+        assert_eq!(
+            err.to_string(),
+            "error from the start at the 4 byte offset while trying to strip a pattern: \
+             expected one of \"red\", \"Red\", \"blue\"",
+        );
+    }
+    {
+        let mut parser = Parser::new("redblue");
+        assert_eq!(strip_suffix(&mut parser), Ok(Color::Blue));
+        assert_eq!(strip_suffix(&mut parser), Ok(Color::Red));
+
+        let mut parser = Parser::with_start_offset("purple", 4);
+        let err = strip_suffix(&mut parser).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Strip);
+        assert_eq!(err.error_direction(), ParseDirection::FromEnd);
+        assert_eq!(err.offset(), 10);
+        assert_eq!(err.expected(), RED);
+        assert_eq!(parser.remainder(), "purple");
+    }
+    {
+        let mut parser = Parser::new(r#"a"b\c"#);
+        assert_eq!(find_skip(&mut parser), Ok(0));
+        assert_eq!(find_skip(&mut parser), Ok(1));
+        assert_eq!(parser.remainder(), "c");
+
+        let err = find_skip(&mut parser).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Find);
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+        assert_eq!(err.offset(), 4);
+        assert_eq!(err.expected(), ["\"", "\\"]);
+        assert_eq!(parser.remainder(), "c");
+        assert_eq!(
+            err.to_string(),
+            r#"error from the start at the 4 byte offset while trying to find and skip a pattern: expected one of "\"", "\\""#,
+        );
+    }
+    {
+        let mut parser = Parser::new("axbb");
+        assert_eq!(rfind_skip(&mut parser), Ok(0));
+        assert_eq!(parser.remainder(), "a");
+
+        let err = rfind_skip(&mut parser).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Find);
+        assert_eq!(err.error_direction(), ParseDirection::FromEnd);
+        assert_eq!(err.offset(), 1);
+        assert_eq!(err.expected(), ["x"]);
+        assert_eq!(
+            err.to_string(),
+            r#"error from the end at the 1 byte offset while trying to find and skip a pattern: expected "x""#,
+        );
+    }
+}
+
+#[test]
+fn expected_error_evaluates_place_once_test() {
+    use konst::parsing::ErrorKind;
+
+    let mut parser = Parser::new("foo");
+    let mut evaluated = 0;
+
+    let res: Result<u32, _> = parser_method! {
+        *{
+            evaluated += 1;
+            &mut parser
+        },
+        strip_prefix;
+        "bar" => Ok(0),
+    };
+
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Strip);
+    assert_eq!(evaluated, 1);
+    assert_eq!(parser.remainder(), "foo");
+
+    let res: Result<u32, _> = parser_method! {Parser::new("bar"), strip_prefix; "bar" => Ok(0)};
+    assert_eq!(res, Ok(0));
+}
@@ -0,0 +1,268 @@
+use konst::parsing::{
+    ErrorKind, OutOfRange, ParseDirection, ParseError, Parser, RangeInt, parse_type_in,
+};
+
+use const_panic::{ArrayString, FmtArg};
+
+use core::ops::Bound;
+
+fn out_of_range(err: &ParseError<'_>) -> OutOfRange {
+    assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    err.out_of_range().unwrap()
+}
+
+fn assert_fmt_equiv(err: &ParseError<'_>) {
+    for fmtarg in [
+        FmtArg::DEBUG,
+        FmtArg::ALT_DEBUG,
+        FmtArg::DISPLAY,
+        FmtArg::ALT_DISPLAY,
+    ] {
+        let expected = match (fmtarg.fmt_kind, fmtarg.is_alternate) {
+            (const_panic::fmt::FmtKind::Debug, false) => format!("{err:?}"),
+            (const_panic::fmt::FmtKind::Debug, true) => format!("{err:#?}"),
+            (_, false) => format!("{err}"),
+            (_, true) => format!("{err:#}"),
+        };
+        assert_eq!(
+            ArrayString::<1024>::from_panicvals(&err.to_panicvals(fmtarg)).unwrap(),
+            *expected,
+        );
+    }
+
+    let kind = err.kind();
+    for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+        let expected = if fmtarg.is_alternate {
+            format!("{kind:#?}")
+        } else {
+            format!("{kind:?}")
+        };
+        assert_eq!(
+            ArrayString::<1024>::from_panicvals(&kind.to_panicvals(fmtarg)).unwrap(),
+            *expected,
+        );
+    }
+}
+
+#[test]
+fn parse_type_in_ok_test() {
+    let mut parser = Parser::new("3,-3,255,0");
+
+    assert_eq!(parse_type_in!(parser, u8, 1..5), Ok(3));
+    assert_eq!(parser.remainder(), ",-3,255,0");
+    _ = parser.strip_prefix(',');
+
+    assert_eq!(parse_type_in!(parser, i8, -3..=-3), Ok(-3));
+    _ = parser.strip_prefix(',');
+
+    assert_eq!(parse_type_in!(parser, u8, 255..), Ok(255));
+    _ = parser.strip_prefix(',');
+
+    assert_eq!(parse_type_in!(parser, u32, ..1), Ok(0));
+    assert_eq!(parser.remainder(), "");
+
+    assert_eq!(parse_type_in!(Parser::new("10"), u64, ..=10), Ok(10));
+    assert_eq!(
+        parse_type_in!(
+            Parser::new("340282366920938463463374607431768211455"),
+            u128,
+            1..
+        ),
+        Ok(u128::MAX),
+    );
+    assert_eq!(
+        parse_type_in!(
+            Parser::new("-170141183460469231731687303715884105728"),
+            i128,
+            ..0
+        ),
+        Ok(i128::MIN),
+    );
+
+    const PORT: u16 =
+        konst::result::unwrap!(parse_type_in!(Parser::new("8080"), u16, 1024..=49151));
+    assert_eq!(PORT, 8080);
+}
+
+#[test]
+fn parse_type_in_out_of_range_test() {
+    let int = RangeInt::from_i128;
+
+    for (string, err, expected) in [
+        (
+            "5",
+            parse_type_in!(Parser::new("5"), u8, 1..5).unwrap_err(),
+            (
+                int(5),
+                Some(int(1)),
+                Bound::Excluded(int(5)),
+                "5 is outside of 1..5",
+            ),
+        ),
+        (
+            "0",
+            parse_type_in!(Parser::new("0"), u8, 1..=5).unwrap_err(),
+            (
+                int(0),
+                Some(int(1)),
+                Bound::Included(int(5)),
+                "0 is outside of 1..=5",
+            ),
+        ),
+        (
+            "-4",
+            parse_type_in!(Parser::new("-4"), i8, -3..).unwrap_err(),
+            (
+                int(-4),
+                Some(int(-3)),
+                Bound::Unbounded,
+                "-4 is outside of -3..",
+            ),
+        ),
+        (
+            "-1",
+            parse_type_in!(Parser::new("-1"), i64, ..-1).unwrap_err(),
+            (
+                int(-1),
+                None,
+                Bound::Excluded(int(-1)),
+                "-1 is outside of ..-1",
+            ),
+        ),
+        (
+            "11",
+            parse_type_in!(Parser::new("11"), usize, ..=10).unwrap_err(),
+            (
+                int(11),
+                None,
+                Bound::Included(int(10)),
+                "11 is outside of ..=10",
+            ),
+        ),
+        (
+            "-170141183460469231731687303715884105728",
+            parse_type_in!(
+                Parser::new("-170141183460469231731687303715884105728"),
+                i128,
+                (i128::MIN + 1)..
+            )
+            .unwrap_err(),
+            (
+                int(i128::MIN),
+                Some(int(i128::MIN + 1)),
+                Bound::Unbounded,
+                "-170141183460469231731687303715884105728 is outside of \
+                 -170141183460469231731687303715884105727..",
+            ),
+        ),
+        (
+            "340282366920938463463374607431768211455",
+            parse_type_in!(
+                Parser::new("340282366920938463463374607431768211455"),
+                u128,
+                0..u128::MAX
+            )
+            .unwrap_err(),
+            (
+                RangeInt::from_u128(u128::MAX),
+                Some(int(0)),
+                Bound::Excluded(RangeInt::from_u128(u128::MAX)),
+                "340282366920938463463374607431768211455 is outside of \
+                 0..340282366920938463463374607431768211455",
+            ),
+        ),
+    ] {
+        let oor = out_of_range(&err);
+        let (value, start, end, message) = expected;
+
+        assert_eq!(oor.value(), value, "{string:?}");
+        assert_eq!(oor.start(), start, "{string:?}");
+        assert_eq!(oor.end(), end, "{string:?}");
+        assert_eq!(oor.to_string(), message);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "error from the start at the 0 byte offset \
+                 while parsing a value in a range: {message}"
+            ),
+        );
+        assert_eq!(err.offset(), 0);
+        assert_eq!(err.error_direction(), ParseDirection::FromStart);
+
+        assert_fmt_equiv(&err);
+    }
+}
+
+#[test]
+fn parse_type_in_parser_state_test() {
+    // errors leave the parser unmodified
+    let mut parser = Parser::new("foo 100 bar ");
+    parser.trim_end();
+    _ = parser.strip_prefix("foo ");
+
+    let err = parse_type_in!(parser, u32, ..100).unwrap_err();
+    assert_eq!(err.offset(), 4);
+    assert_eq!(err.error_direction(), ParseDirection::FromStart);
+    assert_eq!(parser.remainder(), "100 bar");
+    assert_eq!(out_of_range(&err).value(), RangeInt::from_u128(100));
+
+    // errors from parsing the type are returned unchanged
+    let mut parser = Parser::new("bar");
+    let err = parse_type_in!(parser, u32, ..100).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ParseInteger);
+    assert_eq!(err.out_of_range(), None);
+    assert_eq!(parser.remainder(), "bar");
+
+    // out of range errors don't store a suggestion
+    let err = parse_type_in!(Parser::new("100"), u32, ..100).unwrap_err();
+    assert_eq!(err.copy().with_suggestion(&"99"), err);
+}
+
+#[test]
+fn out_of_range_is_stored_outside_of_kind_test() {
+    assert_eq!(core::mem::size_of::<ErrorKind>(), 1);
+}
+
+#[test]
+fn range_int_test() {
+    for (int, i128_, u128_, string) in [
+        (RangeInt::from_i128(0), Some(0), Some(0), "0"),
+        (RangeInt::from_u128(0), Some(0), Some(0), "0"),
+        (RangeInt::from_i128(-1), Some(-1), None, "-1"),
+        (
+            RangeInt::from_i128(i128::MIN),
+            Some(i128::MIN),
+            None,
+            "-170141183460469231731687303715884105728",
+        ),
+        (
+            RangeInt::from_i128(i128::MAX),
+            Some(i128::MAX),
+            Some(i128::MAX as u128),
+            "170141183460469231731687303715884105727",
+        ),
+        (
+            RangeInt::from_u128(i128::MAX as u128 + 1),
+            None,
+            Some(i128::MAX as u128 + 1),
+            "170141183460469231731687303715884105728",
+        ),
+    ] {
+        assert_eq!(int.to_i128(), i128_);
+        assert_eq!(int.to_u128(), u128_);
+        assert_eq!(int.is_negative(), u128_.is_none());
+        assert_eq!(int.to_string(), string);
+        assert_eq!(format!("{int:?}"), string);
+        assert_eq!(
+            ArrayString::<64>::from_panicvals(&int.to_panicvals(FmtArg::DEBUG)).unwrap(),
+            *string,
+        );
+    }
+
+    assert_eq!(RangeInt::from_i128(-7).magnitude(), 7);
+    assert_eq!(RangeInt::from_i128(i128::MIN).magnitude(), 1 << 127);
+    assert_eq!(RangeInt::from_u128(u128::MAX).magnitude(), u128::MAX);
+
+    assert_eq!(RangeInt::from_i128(7), RangeInt::from_u128(7));
+    assert_ne!(RangeInt::from_i128(-7), RangeInt::from_u128(7));
+}
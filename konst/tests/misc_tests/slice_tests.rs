@@ -13,6 +13,11 @@ use konst::slice::cmp::{cmp_option_slice_bytes, eq_option_slice_bytes};
 
 mod bytes_fns_tests;
 
+#[cfg(feature = "iter")]
+mod bytes_matches_tests;
+
+mod bytes_search_tests;
+
 mod slice_concatenation_tests;
 
 mod slice_split_off_tests;
@@ -237,6 +237,21 @@ fn bytes_rfind_test() {
     assert_eq!(bytes_rfind(b"foo bar baz", &'q'), None);
 }
 
+// a partial match that overlaps the match used to make these searches return None
+#[test]
+fn bytes_find_overlapping_partial_match_test() {
+    assert_eq!(bytes_find(b"aaab", b"aab"), Some(1));
+    assert_eq!(bytes_find(b"lawlawn", b"lawn"), Some(3));
+    assert_eq!(bytes_find(b"abababc", b"ababc"), Some(2));
+
+    assert_eq!(bytes_rfind(b"baaa", b"baa"), Some(0));
+    assert_eq!(bytes_rfind(b"nwalwal", b"nwal"), Some(0));
+    assert_eq!(bytes_rfind(b"cbababa", b"cbaba"), Some(0));
+
+    assert_eq!(konst::string::find("aaab", "aab"), Some(1));
+    assert_eq!(konst::string::rfind("baaa", "baa"), Some(0));
+}
+
 #[test]
 fn bytes_find_keep_test() {
     assert_eq!(
@@ -0,0 +1,125 @@
+use konst::{iter::collect_const, slice};
+
+const SLICES: &[&[u8]] = &[
+    b"",
+    b"a",
+    b"aa",
+    b"aaa",
+    b"aaaaa",
+    b"foo-bar-baz",
+    b"foo--bar---",
+    b"-",
+    b"lolololfoo",
+    "ñ个ñ个ñ".as_bytes(),
+];
+
+// the std str methods are used as the reference implementation,
+// since there's no equivalent for byte slices.
+fn check<const N: usize, P>(pattern: &P, std_pattern: &str)
+where
+    P: ?Sized + slice::BytesPattern<N>,
+{
+    for &bytes in SLICES {
+        let s = std::str::from_utf8(bytes).unwrap();
+
+        let expected: Vec<(usize, &[u8])> = s
+            .match_indices(std_pattern)
+            .map(|(i, x)| (i, x.as_bytes()))
+            .collect();
+
+        let mut iter = slice::bytes_match_indices(bytes, pattern);
+        let mut found = Vec::new();
+        while let Some(x) = iter.next() {
+            found.push(x);
+        }
+        assert_eq!(found, expected, "{s:?}");
+
+        let mut iter = slice::bytes_matches(bytes, pattern);
+        let mut found = Vec::new();
+        while let Some(x) = iter.next() {
+            found.push(x);
+            assert_eq!(iter.copy().remainder(), iter.remainder());
+        }
+        assert_eq!(
+            found,
+            expected.iter().map(|x| x.1).collect::<Vec<_>>(),
+            "{s:?}"
+        );
+
+        assert_eq!(
+            slice::bytes_count_matches(bytes, pattern),
+            expected.len(),
+            "{s:?}"
+        );
+
+        let expected: Vec<(usize, &[u8])> = s
+            .rmatch_indices(std_pattern)
+            .map(|(i, x)| (i, x.as_bytes()))
+            .collect();
+
+        let mut iter = slice::bytes_rmatch_indices(bytes, pattern);
+        let mut found = Vec::new();
+        while let Some(x) = iter.next() {
+            found.push(x);
+        }
+        assert_eq!(found, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn bytes_matches_test() {
+    for pattern in [
+        "a",
+        "aa",
+        "-",
+        "--",
+        "lol",
+        "lolol",
+        "ñ个",
+        "个ñ",
+        "not found",
+    ] {
+        check(pattern, pattern);
+        check(pattern.as_bytes(), pattern);
+    }
+
+    check(&'a', "a");
+    check(&'个', "个");
+    check(b"aa", "aa");
+    check(b"lol", "lol");
+}
+
+#[test]
+fn bytes_empty_pattern_test() {
+    for &bytes in SLICES {
+        let expected: Vec<(usize, &[u8])> = (0..=bytes.len()).map(|i| (i, &b""[..])).collect();
+
+        let mut iter = slice::bytes_match_indices(bytes, b"");
+        let mut found = Vec::new();
+        while let Some(x) = iter.next() {
+            found.push(x);
+        }
+        assert_eq!(found, expected, "{bytes:?}");
+
+        let mut iter = slice::bytes_rmatch_indices(bytes, "");
+        let mut found = Vec::new();
+        while let Some(x) = iter.next() {
+            found.push(x);
+        }
+        found.reverse();
+        assert_eq!(found, expected, "{bytes:?}");
+
+        assert_eq!(slice::bytes_count_matches(bytes, b""), bytes.len() + 1);
+    }
+}
+
+#[test]
+fn const_bytes_matches_test() {
+    const DATA: &[u8] = b"foo,1\nbar,2\nbaz,3\n";
+    const RECORDS: usize = slice::bytes_count_matches(DATA, &'\n');
+    assert_eq!(RECORDS, 3);
+
+    const FOUND: [(usize, &[u8]); RECORDS] =
+        collect_const!((usize, &[u8]) => slice::bytes_rmatch_indices(DATA, b","));
+    assert_eq!(FOUND, [(15, &b","[..]), (9, b","), (3, b",")]);
+}
@@ -0,0 +1,188 @@
+use konst::{slice, string};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+fn naive_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn naive_rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+#[track_caller]
+fn check_search(haystack: &[u8], needle: &[u8]) {
+    let found = naive_find(haystack, needle);
+    let rfound = naive_rfind(haystack, needle);
+    let ctx = || format!("haystack: {haystack:?}\nneedle: {needle:?}");
+
+    assert_eq!(slice::bytes_find(haystack, needle), found, "{}", ctx());
+    assert_eq!(slice::bytes_rfind(haystack, needle), rfound, "{}", ctx());
+    assert_eq!(
+        slice::bytes_contain(haystack, needle),
+        found.is_some(),
+        "{}",
+        ctx()
+    );
+    assert_eq!(
+        slice::bytes_find_skip(haystack, needle),
+        found.map(|i| &haystack[i + needle.len()..]),
+        "{}",
+        ctx()
+    );
+    assert_eq!(
+        slice::bytes_find_keep(haystack, needle),
+        found.map(|i| &haystack[i..]),
+        "{}",
+        ctx()
+    );
+    assert_eq!(
+        slice::bytes_rfind_skip(haystack, needle),
+        rfound.map(|i| &haystack[..i]),
+        "{}",
+        ctx()
+    );
+    assert_eq!(
+        slice::bytes_rfind_keep(haystack, needle),
+        rfound.map(|i| &haystack[..i + needle.len()]),
+        "{}",
+        ctx()
+    );
+
+    if let (Ok(haystack), Ok(needle)) = (std::str::from_utf8(haystack), std::str::from_utf8(needle))
+    {
+        assert_eq!(string::find(haystack, needle), found, "{}", ctx());
+        assert_eq!(string::rfind(haystack, needle), rfound, "{}", ctx());
+    }
+}
+
+#[test]
+fn exhaustive_small_alphabet_test() {
+    // every haystack and needle made of `a` and `b` up to some length,
+    // which covers the periodic and non-periodic needle cases of two-way search.
+    fn strings(max_len: u32) -> impl Iterator<Item = Vec<u8>> {
+        (0..=max_len).flat_map(|len| {
+            (0..1u32 << len).map(move |bits| {
+                (0..len)
+                    .map(|i| if bits >> i & 1 == 0 { b'a' } else { b'b' })
+                    .collect()
+            })
+        })
+    }
+
+    let needles: Vec<Vec<u8>> = strings(5).filter(|x| !x.is_empty()).collect();
+    for haystack in strings(9) {
+        for needle in &needles {
+            check_search(&haystack, needle);
+        }
+    }
+}
+
+#[test]
+fn random_search_test() {
+    let mut rng = SmallRng::seed_from_u64(0x05EA_4C4E_50FB_17E5);
+
+    let alphabets: &[&[u8]] = &[
+        b"ab",
+        b"abc",
+        b"abcdefgh",
+        &[0x00, 0x01, 0x7F, 0x80, 0xFE, 0xFF],
+        "ñ个 ".as_bytes(),
+    ];
+
+    for _ in 0..20000 {
+        let alphabet = alphabets[rng.gen_range(0..alphabets.len())];
+        let hay_len = rng.gen_range(0..100);
+        let haystack: Vec<u8> = (0..hay_len)
+            .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+            .collect();
+
+        let needle: Vec<u8> = if hay_len != 0 && rng.r#gen() {
+            // a needle that's in the haystack
+            let start = rng.gen_range(0..hay_len);
+            let end = rng.gen_range(start + 1..=hay_len.min(start + 20));
+            haystack[start..end].to_vec()
+        } else {
+            let needle_len = rng.gen_range(1..20);
+            (0..needle_len)
+                .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+                .collect()
+        };
+
+        check_search(&haystack, &needle);
+    }
+}
+
+#[test]
+fn single_byte_search_test() {
+    // the bytes that could cause false positives in word-at-a-time search
+    fn neighbors(byte: u8) -> [u8; 6] {
+        [
+            byte ^ 1,
+            byte ^ 0x80,
+            byte ^ 0xFF,
+            byte.wrapping_add(1),
+            byte.wrapping_sub(1),
+            byte.wrapping_add(0x80),
+        ]
+    }
+
+    let mut rng = SmallRng::seed_from_u64(0x0DD5_EED5);
+
+    for byte in 0..=255u8 {
+        let neighbors = neighbors(byte);
+
+        for len in 0..40 {
+            let mut haystack: Vec<u8> = (0..len)
+                .map(|_| neighbors[rng.gen_range(0..neighbors.len())])
+                .collect();
+            check_search(&haystack, &[byte]);
+
+            for _ in 0..3 {
+                if len != 0 {
+                    haystack[rng.gen_range(0..len)] = byte;
+                    check_search(&haystack, &[byte]);
+                }
+            }
+        }
+    }
+}
+
+// Tests that searching large haystacks in constants doesn't hit
+// the default const evaluation step limit (the `long_running_const_eval` lint),
+// which a naive search that tries the needle at every position
+// does hit with the periodic needle.
+#[test]
+fn large_const_search_test() {
+    const LEN: usize = 1 << 17;
+
+    const HAYSTACK: &[u8; LEN] = &{
+        let mut arr = [b'a'; LEN];
+        arr[3] = b'c';
+        arr[LEN - 4] = b'b';
+        arr
+    };
+
+    const HAYSTACK_STR: &str = match core::str::from_utf8(HAYSTACK) {
+        Ok(x) => x,
+        Err(_) => panic!(),
+    };
+
+    const FOUND_BYTE: Option<usize> = slice::bytes_find(HAYSTACK, &'b');
+    assert_eq!(FOUND_BYTE, Some(LEN - 4));
+
+    const RFOUND_BYTE: Option<usize> = slice::bytes_rfind(HAYSTACK, &'c');
+    assert_eq!(RFOUND_BYTE, Some(3));
+
+    // periodic needle in a periodic haystack, the worst case for naive search
+    const FOUND_PERIODIC: Option<usize> = string::find(HAYSTACK_STR, "aaaaaaaaaaaaaaab");
+    assert_eq!(FOUND_PERIODIC, Some(LEN - 4 - 15));
+
+    const RFOUND_PERIODIC: Option<usize> = slice::bytes_rfind(HAYSTACK, b"baaaaaaaaaaaa");
+    assert_eq!(RFOUND_PERIODIC, None);
+
+    // needle with bytes that aren't in the haystack, which is skipped over
+    const NOT_FOUND: Option<usize> = string::find(HAYSTACK_STR, "not found");
+    assert_eq!(NOT_FOUND, None);
+}
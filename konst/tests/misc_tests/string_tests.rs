@@ -10,8 +10,29 @@ use super::test_utils::must_panic;
 #[cfg(feature = "iter")]
 mod string_chars_tests;
 
+mod string_builder;
+
+mod string_case_conversion;
+
+mod string_char_patterns;
+
 mod string_concatenation;
 
+mod string_edit_distance;
+
+#[cfg(feature = "iter")]
+mod string_lines_and_whitespace;
+
+#[cfg(feature = "iter")]
+mod string_matches;
+
+#[cfg(feature = "konst_proc_macros")]
+mod string_formatting;
+
+mod string_number_to_str;
+
+mod string_transformation;
+
 #[cfg(feature = "iter")]
 mod string_splitting;
 
@@ -0,0 +1,214 @@
+use konst::string::{ArrayStr, CapacityError, StrBuilder};
+
+use crate::misc_tests::test_utils::must_panic;
+
+#[test]
+fn push_str_char_test() {
+    let mut builder = StrBuilder::<10>::new();
+    assert!(builder.is_empty());
+    assert_eq!(builder.capacity(), 10);
+
+    builder.push_str("foo");
+    builder.push_char('ñ');
+    builder.push_str("");
+    builder.push_char('个');
+    assert_eq!(builder.as_str(), "fooñ个");
+    assert_eq!(builder.as_bytes(), "fooñ个".as_bytes());
+    assert_eq!(builder.len(), 8);
+    assert_eq!(builder.remaining_capacity(), 2);
+    assert!(!builder.is_empty());
+
+    assert_eq!(
+        builder.try_push_char('个'),
+        Err(StrBuilder::<2>::new().try_push_str("个").unwrap_err())
+    );
+    assert_eq!(builder.try_push_str("bar").unwrap_err().additional(), 3);
+    assert_eq!(builder.as_str(), "fooñ个");
+
+    builder.push_str("ab");
+    assert_eq!(builder.as_str(), "fooñ个ab");
+    assert_eq!(builder.remaining_capacity(), 0);
+    assert!(builder.try_push_str("").is_ok());
+
+    must_panic(file_span!(), || builder.clone().push_str("c")).unwrap();
+    must_panic(file_span!(), || builder.clone().push_char('c')).unwrap();
+
+    builder.clear();
+    assert_eq!(builder.as_str(), "");
+}
+
+#[test]
+fn push_int_test() {
+    for n in [
+        0u64,
+        1,
+        7,
+        8,
+        9,
+        10,
+        15,
+        16,
+        255,
+        1000,
+        u32::MAX as u64,
+        u64::MAX,
+    ] {
+        for width in [0, 1, 3, 8, 30] {
+            let mut builder = StrBuilder::<256>::new();
+            builder.push_u64(n, 10, width);
+            builder.push_char(' ');
+            builder.push_u64(n, 16, width);
+            builder.push_char(' ');
+            builder.push_u64(n, 2, width);
+            builder.push_char(' ');
+            builder.push_u64(n, 8, width);
+
+            assert_eq!(
+                builder.as_str(),
+                format!("{n:0width$} {n:0width$x} {n:0width$b} {n:0width$o}")
+            );
+        }
+    }
+
+    for n in [0i64, 1, -1, 15, -15, 1000, -1000, i64::MAX, i64::MIN] {
+        for width in [0, 1, 3, 8, 30] {
+            let mut builder = StrBuilder::<128>::new();
+            builder.push_i64(n, 10, width);
+            assert_eq!(builder.as_str(), format!("{n:0width$}"));
+        }
+    }
+
+    {
+        let mut builder = StrBuilder::<128>::new();
+        builder.push_u64(35, 36, 0);
+        builder.push_i64(-71, 36, 4);
+        builder.push_u64(u64::MAX, 36, 0);
+        assert_eq!(builder.as_str(), "z-01z3w5e11264sgsf");
+    }
+
+    {
+        let mut builder = StrBuilder::<4>::new();
+        assert_eq!(
+            builder.try_push_i64(-1000, 10, 0).unwrap_err().additional(),
+            5
+        );
+        assert_eq!(builder.try_push_u64(1, 10, 5).unwrap_err().additional(), 5);
+        assert_eq!(builder.as_str(), "");
+        builder.push_i64(-100, 10, 4);
+        assert_eq!(builder.as_str(), "-100");
+        must_panic(file_span!(), || builder.clone().push_u64(0, 10, 0)).unwrap();
+    }
+
+    for radix in [0, 1, 37] {
+        must_panic(file_span!(), || {
+            StrBuilder::<8>::new().push_u64(0, radix, 0)
+        })
+        .unwrap();
+        must_panic(file_span!(), || {
+            StrBuilder::<8>::new().try_push_i64(0, radix, 0)
+        })
+        .unwrap();
+    }
+}
+
+#[test]
+fn truncate_pop_test() {
+    let mut builder = StrBuilder::<16>::new();
+    builder.push_str("aé个\u{100000}");
+
+    for i in [2, 4, 5, 7, 8, 9] {
+        must_panic(file_span!(), || builder.clone().truncate(i)).unwrap();
+    }
+
+    {
+        let mut builder = builder;
+        builder.truncate(100);
+        assert_eq!(builder.as_str(), "aé个\u{100000}");
+        builder.truncate(6);
+        assert_eq!(builder.as_str(), "aé个");
+        builder.truncate(0);
+        assert_eq!(builder.as_str(), "");
+    }
+
+    assert_eq!(builder.pop(), Some('\u{100000}'));
+    assert_eq!(builder.pop(), Some('个'));
+    assert_eq!(builder.pop(), Some('é'));
+    assert_eq!(builder.pop(), Some('a'));
+    assert_eq!(builder.pop(), None);
+    assert_eq!(builder.as_str(), "");
+}
+
+#[test]
+fn eq_hash_ignore_unused_bytes_test() {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    fn hash_of(builder: &StrBuilder<8>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        builder.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut popped = StrBuilder::<8>::new();
+    popped.push_str("ab");
+    assert_eq!(popped.pop(), Some('b'));
+
+    let mut pushed = StrBuilder::<8>::new();
+    pushed.push_str("a");
+
+    assert_eq!(popped, pushed);
+    assert_eq!(hash_of(&popped), hash_of(&pushed));
+
+    pushed.push_str("c");
+    assert_ne!(popped, pushed);
+}
+
+#[test]
+fn to_array_str_test() {
+    const BUILDER: &StrBuilder<16> = &{
+        let mut builder = StrBuilder::new();
+        builder.push_str("hello");
+        builder.push_char(' ');
+        builder.push_i64(-5, 10, 3);
+        _ = builder.pop();
+        builder
+    };
+
+    const ARR: ArrayStr<{ BUILDER.len() }> = BUILDER.to_array_str();
+    assert_eq!(ARR.as_str(), "hello -0");
+
+    must_panic(file_span!(), || BUILDER.to_array_str::<7>()).unwrap();
+    must_panic(file_span!(), || BUILDER.to_array_str::<9>()).unwrap();
+
+    assert_eq!(format!("{BUILDER:?}"), r#""hello -0""#);
+    assert_eq!(format!("{BUILDER}"), "hello -0");
+    assert_eq!(StrBuilder::<4>::default(), StrBuilder::new());
+}
+
+#[test]
+fn capacity_error_fmt_test() {
+    let err: CapacityError = StrBuilder::<2>::new().try_push_str("foo").unwrap_err();
+
+    assert_eq!(err.additional(), 3);
+    assert_eq!(err.remaining(), 2);
+
+    let display =
+        "could not push `3` bytes to a string builder with `2` bytes of remaining capacity";
+    assert_eq!(err.to_string(), display);
+    assert_eq!(
+        const_panic::ArrayString::<200>::from_panicvals(
+            &err.to_panicvals(const_panic::FmtArg::DISPLAY)
+        )
+        .unwrap(),
+        display,
+    );
+    assert_eq!(
+        const_panic::ArrayString::<200>::from_panicvals(
+            &err.to_panicvals(const_panic::FmtArg::DEBUG)
+        )
+        .unwrap(),
+        *format!("{err:?}"),
+    );
+}
@@ -0,0 +1,68 @@
+use konst::string;
+
+macro_rules! assert_cases {
+    ($(
+        $input:literal =>
+            $snake:literal $camel:literal $pascal:literal $kebab:literal $screaming:literal
+    )*) => ($({
+        assert_eq!(string::to_snake_case!($input), $snake, "{:?}", $input);
+        assert_eq!(string::to_camel_case!($input), $camel, "{:?}", $input);
+        assert_eq!(string::to_pascal_case!($input), $pascal, "{:?}", $input);
+        assert_eq!(string::to_kebab_case!($input), $kebab, "{:?}", $input);
+        assert_eq!(string::to_screaming_snake_case!($input), $screaming, "{:?}", $input);
+    })*)
+}
+
+#[test]
+fn case_conversion_test() {
+    assert_cases! {
+        "" => "" "" "" "" ""
+        "_-_" => "" "" "" "" ""
+        "a" => "a" "a" "A" "a" "A"
+        "A" => "a" "a" "A" "a" "A"
+        "foo" => "foo" "foo" "Foo" "foo" "FOO"
+        "foo_bar" => "foo_bar" "fooBar" "FooBar" "foo-bar" "FOO_BAR"
+        "__foo__bar__" => "foo_bar" "fooBar" "FooBar" "foo-bar" "FOO_BAR"
+        "foo-bar baz.qux" => "foo_bar_baz_qux" "fooBarBazQux" "FooBarBazQux" "foo-bar-baz-qux"
+            "FOO_BAR_BAZ_QUX"
+        "fooBar" => "foo_bar" "fooBar" "FooBar" "foo-bar" "FOO_BAR"
+        "FooBar" => "foo_bar" "fooBar" "FooBar" "foo-bar" "FOO_BAR"
+        "FOO_BAR" => "foo_bar" "fooBar" "FooBar" "foo-bar" "FOO_BAR"
+        "HTTPServer" => "http_server" "httpServer" "HttpServer" "http-server" "HTTP_SERVER"
+        "parseHTTPRequest" => "parse_http_request" "parseHttpRequest" "ParseHttpRequest"
+            "parse-http-request" "PARSE_HTTP_REQUEST"
+        "ABc" => "a_bc" "aBc" "ABc" "a-bc" "A_BC"
+        "Base64Encoder" => "base64_encoder" "base64Encoder" "Base64Encoder" "base64-encoder"
+            "BASE64_ENCODER"
+        "ipv4_addr" => "ipv4_addr" "ipv4Addr" "Ipv4Addr" "ipv4-addr" "IPV4_ADDR"
+        "HTTP2Server" => "http2_server" "http2Server" "Http2Server" "http2-server"
+            "HTTP2_SERVER"
+        "123_456" => "123_456" "123456" "123456" "123-456" "123_456"
+        "ñandúBird" => "ñandú_bird" "ñandúBird" "ñandúBird" "ñandú-bird" "ñANDú_BIRD"
+        "fooÑandú" => "fooÑandú" "fooÑandú" "FooÑandú" "fooÑandú" "FOOÑANDú"
+        "个_foo" => "个_foo" "个Foo" "个Foo" "个-foo" "个_FOO"
+    }
+}
+
+#[test]
+fn case_conversion_const_test() {
+    const TYPE_NAME: &str = "MyApp";
+    const FIELD_NAME: &str = "max_connections";
+
+    const ENV_VAR: &str =
+        string::to_screaming_snake_case!(string::str_concat!(&[TYPE_NAME, "_", FIELD_NAME]));
+    assert_eq!(ENV_VAR, "MY_APP_MAX_CONNECTIONS");
+
+    const HEADER: &str = string::to_kebab_case!(FIELD_NAME);
+    assert_eq!(HEADER, "max-connections");
+}
+
+#[test]
+fn case_conversion_args_named_like_macro_internals_test() {
+    // the macros declare constants with these names internally
+    const STR: &str = "fooBar";
+    const LEN: &str = "foo_bar";
+
+    assert_eq!(string::to_snake_case!(STR), "foo_bar");
+    assert_eq!(string::to_pascal_case!(LEN), "FooBar");
+}
@@ -0,0 +1,284 @@
+use konst::string::{self, CharPredicate};
+
+#[cfg(feature = "parsing")]
+use konst::parsing::Parser;
+
+const STRINGS: &[&str] = &[
+    "",
+    " ",
+    "foo",
+    "foo bar",
+    " foo,bar\tbaz ",
+    ",,foo,,",
+    "ñ个ñ个",
+    "a1b22c333",
+    "123",
+    "\u{A0}foo\u{3000}bar\u{85}",
+    "--ñ-个--",
+];
+
+// asserts that the konst functions behave like the std methods,
+// with `$kpat` as the konst pattern and `$spat` as the equivalent std pattern.
+macro_rules! assert_like_std {
+    ($kpat:expr, $spat:expr) => {
+        for &s in STRINGS {
+            assert_eq!(string::find(s, $kpat), s.find($spat), "{s:?}");
+            assert_eq!(string::rfind(s, $kpat), s.rfind($spat), "{s:?}");
+            assert_eq!(string::contains(s, $kpat), s.contains($spat), "{s:?}");
+            assert_eq!(string::rcontains(s, $kpat), s.contains($spat), "{s:?}");
+            assert_eq!(string::starts_with(s, $kpat), s.starts_with($spat), "{s:?}");
+            assert_eq!(string::ends_with(s, $kpat), s.ends_with($spat), "{s:?}");
+            assert_eq!(string::strip_prefix(s, $kpat), s.strip_prefix($spat), "{s:?}");
+            assert_eq!(string::strip_suffix(s, $kpat), s.strip_suffix($spat), "{s:?}");
+            assert_eq!(string::trim_matches(s, $kpat), s.trim_matches($spat), "{s:?}");
+            assert_eq!(
+                string::trim_start_matches(s, $kpat),
+                s.trim_start_matches($spat),
+                "{s:?}"
+            );
+            assert_eq!(
+                string::trim_end_matches(s, $kpat),
+                s.trim_end_matches($spat),
+                "{s:?}"
+            );
+            assert_eq!(
+                string::find_skip(s, $kpat),
+                s.find($spat).map(|i| &s[i + s[i..].chars().next().unwrap().len_utf8()..]),
+                "{s:?}"
+            );
+            assert_eq!(
+                string::find_keep(s, $kpat),
+                s.find($spat).map(|i| &s[i..]),
+                "{s:?}"
+            );
+            assert_eq!(
+                string::rfind_skip(s, $kpat),
+                s.rfind($spat).map(|i| &s[..i]),
+                "{s:?}"
+            );
+            assert_eq!(
+                string::rfind_keep(s, $kpat),
+                s.rfind($spat)
+                    .map(|i| &s[..i + s[i..].chars().next().unwrap().len_utf8()]),
+                "{s:?}"
+            );
+
+            #[cfg(feature = "iter")]
+            {
+                use konst::iter::collect_const;
+
+                assert_eq!(
+                    string::split_once(s, $kpat),
+                    s.split_once($spat),
+                    "{s:?}"
+                );
+                assert_eq!(
+                    string::rsplit_once(s, $kpat),
+                    s.rsplit_once($spat),
+                    "{s:?}"
+                );
+
+                let split: Vec<&str> = s.split($spat).collect();
+                assert_eq!(string::split(s, $kpat).collect_vec(), split, "{s:?}");
+
+                let rsplit: Vec<&str> = s.rsplit($spat).collect();
+                assert_eq!(string::rsplit(s, $kpat).collect_vec(), rsplit, "{s:?}");
+
+                let splitn: Vec<&str> = s.splitn(2, $spat).collect();
+                assert_eq!(string::splitn(s, 2, $kpat).collect_vec(), splitn, "{s:?}");
+
+                let split_term: Vec<&str> = s.split_terminator($spat).collect();
+                assert_eq!(
+                    string::split_terminator(s, $kpat).collect_vec(),
+                    split_term,
+                    "{s:?}"
+                );
+
+                _ = collect_const!(&str => string::split("a b", $kpat));
+            }
+
+            #[cfg(feature = "parsing")]
+            {
+                let mut parser = Parser::new(s);
+                _ = parser.trim_start_matches($kpat);
+                assert_eq!(parser.remainder(), s.trim_start_matches($spat), "{s:?}");
+                assert_eq!(
+                    parser.start_offset(),
+                    s.len() - s.trim_start_matches($spat).len()
+                );
+
+                let mut parser = Parser::new(s);
+                _ = parser.trim_end_matches($kpat);
+                assert_eq!(parser.remainder(), s.trim_end_matches($spat), "{s:?}");
+
+                let mut parser = Parser::new(s);
+                let before = parser.split($kpat).unwrap();
+                let (std_before, std_after) = s.split_once($spat).unwrap_or((s, ""));
+                assert_eq!(before, std_before, "{s:?}");
+                assert_eq!(parser.remainder(), std_after, "{s:?}");
+
+                let mut parser = Parser::new(s);
+                let found = parser.find_skip($kpat);
+                assert_eq!(found.is_ok(), s.contains($spat), "{s:?}");
+                if found.is_ok() {
+                    assert_eq!(Some(parser.remainder()), string::find_skip(s, $kpat));
+                }
+
+                let mut parser = Parser::new(s);
+                let stripped = parser.strip_prefix($kpat);
+                assert_eq!(stripped.is_ok(), s.starts_with($spat), "{s:?}");
+            }
+        }
+    };
+}
+
+trait CollectVec<'a> {
+    fn collect_vec(self) -> Vec<&'a str>;
+}
+
+macro_rules! impl_collect_vec {
+    ($($iter:ident)*) => ($(
+        #[cfg(feature = "iter")]
+        impl<'a, 'p, P: string::Pattern<'p>> CollectVec<'a> for string::$iter<'a, 'p, P> {
+            fn collect_vec(mut self) -> Vec<&'a str> {
+                let mut out = Vec::new();
+                while let Some(x) = self.next() {
+                    out.push(x);
+                }
+                out
+            }
+        }
+    )*)
+}
+
+impl_collect_vec! {Split RSplit SplitN SplitTerminator}
+
+#[test]
+fn char_slice_pattern_test() {
+    assert_like_std! {&[' ', ',', '\t'] as &[char], &[' ', ',', '\t'] as &[char]}
+    assert_like_std! {&['个', 'ñ', '-'] as &[char], &['个', 'ñ', '-'] as &[char]}
+    assert_like_std! {&[] as &[char], &[] as &[char]}
+}
+
+#[test]
+fn char_array_pattern_test() {
+    assert_like_std! {[' ', ',', '\t'], [' ', ',', '\t']}
+    assert_like_std! {['个', 'ñ', '-'], ['个', 'ñ', '-']}
+    assert_like_std! {['a'], ['a']}
+    assert_like_std! {[], []}
+
+    assert_like_std! {&[' ', ',', '\t'], &[' ', ',', '\t']}
+    assert_like_std! {&['个', 'ñ', '-'], &['个', 'ñ', '-']}
+}
+
+#[test]
+fn predicate_pattern_test() {
+    assert_like_std! {CharPredicate::ASCII_DIGIT, |c: char| c.is_ascii_digit()}
+    assert_like_std! {CharPredicate::WHITESPACE, char::is_whitespace}
+    assert_like_std! {CharPredicate::ASCII_WHITESPACE, |c: char| c.is_ascii_whitespace()}
+    assert_like_std! {CharPredicate::ASCII_PUNCTUATION, |c: char| c.is_ascii_punctuation()}
+    assert_like_std! {CharPredicate::ASCII.negate(), |c: char| !c.is_ascii()}
+    assert_like_std! {CharPredicate::ANY, |_| true}
+    assert_like_std! {CharPredicate::NONE, |_| false}
+}
+
+#[test]
+fn char_predicate_test() {
+    type Case = (CharPredicate, fn(char) -> bool);
+
+    let cases: &[Case] = &[
+        (CharPredicate::ANY, |_| true),
+        (CharPredicate::NONE, |_| false),
+        (CharPredicate::ASCII, |c| c.is_ascii()),
+        (CharPredicate::ASCII_DIGIT, |c| c.is_ascii_digit()),
+        (CharPredicate::ASCII_HEXDIGIT, |c| c.is_ascii_hexdigit()),
+        (CharPredicate::ASCII_LOWERCASE, |c| c.is_ascii_lowercase()),
+        (CharPredicate::ASCII_UPPERCASE, |c| c.is_ascii_uppercase()),
+        (CharPredicate::ASCII_ALPHABETIC, |c| c.is_ascii_alphabetic()),
+        (CharPredicate::ASCII_ALPHANUMERIC, |c| {
+            c.is_ascii_alphanumeric()
+        }),
+        (CharPredicate::ASCII_PUNCTUATION, |c| {
+            c.is_ascii_punctuation()
+        }),
+        (CharPredicate::ASCII_GRAPHIC, |c| c.is_ascii_graphic()),
+        (CharPredicate::ASCII_WHITESPACE, |c| c.is_ascii_whitespace()),
+        (CharPredicate::ASCII_CONTROL, |c| c.is_ascii_control()),
+        (CharPredicate::WHITESPACE, |c| c.is_whitespace()),
+        (CharPredicate::ASCII_DIGIT.negate(), |c| !c.is_ascii_digit()),
+        (
+            CharPredicate::ASCII_DIGIT.or(CharPredicate::WHITESPACE),
+            |c| c.is_ascii_digit() || c.is_whitespace(),
+        ),
+        (
+            CharPredicate::ASCII_HEXDIGIT.and(CharPredicate::ASCII_ALPHABETIC),
+            |c| c.is_ascii_hexdigit() && c.is_ascii_alphabetic(),
+        ),
+        (
+            CharPredicate::ASCII_LOWERCASE
+                .or(CharPredicate::WHITESPACE)
+                .negate(),
+            |c| !(c.is_ascii_lowercase() || c.is_whitespace()),
+        ),
+    ];
+
+    let chars = (0..0x3100).chain([0xFEFF, 0xFFFF, 0x10FFFF]);
+
+    for (pred, func) in cases {
+        for c in chars.clone().filter_map(char::from_u32) {
+            assert_eq!(pred.matches(c), func(c), "{pred:?} {c:?}");
+        }
+    }
+
+    assert_eq!(CharPredicate::ANY, CharPredicate::NONE.negate());
+    assert_eq!(
+        CharPredicate::ASCII_DIGIT.negate().negate(),
+        CharPredicate::ASCII_DIGIT
+    );
+}
+
+#[test]
+fn char_pattern_const_test() {
+    const SEPS: &[char] = &[' ', ','];
+    const TRIMMED: &str = string::trim_matches(" ,foo, ", SEPS);
+    assert_eq!(TRIMMED, "foo");
+
+    const FOUND: Option<usize> = string::find("abc123", CharPredicate::ASCII_DIGIT);
+    assert_eq!(FOUND, Some(3));
+
+    const STRIPPED: Option<&str> = string::strip_prefix("\tfoo", [' ', '\t']);
+    assert_eq!(STRIPPED, Some("foo"));
+
+    const REPLACED: &str = string::replace!("a-b_c", ['-', '_'], ' ');
+    assert_eq!(REPLACED, "a b c");
+
+    const REPLACED_DIGITS: &str = string::replace!("a1b22c", CharPredicate::ASCII_DIGIT, "#");
+    assert_eq!(REPLACED_DIGITS, "a#b##c");
+}
+
+#[test]
+fn overlapping_str_pattern_test() {
+    for (s, pat) in [("aaab", "aab"), ("lolololfoo", "lolol"), ("abababc", "abc")] {
+        assert_eq!(string::find(s, pat), s.find(pat), "{s:?} {pat:?}");
+        assert_eq!(string::rfind(s, pat), s.rfind(pat), "{s:?} {pat:?}");
+        assert_eq!(
+            string::find_skip(s, pat),
+            s.find(pat).map(|i| &s[i + pat.len()..]),
+            "{s:?} {pat:?}"
+        );
+    }
+}
+
+#[test]
+fn empty_str_pattern_rfind_test() {
+    for s in ["", "a", "abc", "ñ个"] {
+        assert_eq!(
+            string::rfind(s, ""),
+            konst::slice::bytes_rfind(s.as_bytes(), b""),
+            "{s:?}"
+        );
+    }
+
+    assert_eq!(string::rfind("", ""), Some(0));
+    assert_eq!(string::rfind("abc", ""), Some(2));
+}
@@ -0,0 +1,117 @@
+use konst::string::{closest_match, edit_distance};
+
+use crate::misc_tests::test_utils::must_panic;
+
+// The optimal string alignment distance, using a full matrix
+fn reference_edit_distance(left: &str, right: &str) -> usize {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+
+    let mut matrix = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, dist) in matrix[0].iter_mut().enumerate() {
+        *dist = j;
+    }
+
+    for i in 1..=left.len() {
+        for j in 1..=right.len() {
+            let cost = (left[i - 1] != right[j - 1]) as usize;
+            let mut dist = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && left[i - 1] == right[j - 2] && left[i - 2] == right[j - 1] {
+                dist = dist.min(matrix[i - 2][j - 2] + 1);
+            }
+
+            matrix[i][j] = dist;
+        }
+    }
+
+    matrix[left.len()][right.len()]
+}
+
+#[test]
+fn edit_distance_test() {
+    for (left, right, expected) in [
+        ("", "", 0),
+        ("", "abc", 3),
+        ("abc", "abc", 0),
+        ("color", "colour", 1),
+        ("color", "clor", 1),
+        ("color", "cloor", 1),
+        ("color", "Color", 1),
+        ("ab", "ba", 1),
+        ("abc", "ca", 3),
+        ("kitten", "sitting", 3),
+        ("flaw", "lawn", 2),
+        ("ñandú", "nandu", 2),
+        ("个人", "人个", 1),
+        ("个人b", "个b", 1),
+        ("size", "shape", 3),
+    ] {
+        assert_eq!(edit_distance(left, right), expected, "{left:?} {right:?}");
+        assert_eq!(edit_distance(right, left), expected, "{right:?} {left:?}");
+    }
+}
+
+#[test]
+fn edit_distance_reference_test() {
+    let strings = [
+        "", "a", "b", "ab", "ba", "aab", "aba", "baa", "abcd", "acbd", "bdca", "dcba", "abab",
+        "ñ个", "个ñ", "ñ个ñ", "ñañ", "a个b人", "人b个a",
+    ];
+
+    for left in strings {
+        for right in strings {
+            assert_eq!(
+                edit_distance(left, right),
+                reference_edit_distance(left, right),
+                "{left:?} {right:?}",
+            );
+        }
+    }
+}
+
+#[test]
+fn edit_distance_long_test() {
+    let long = "a".repeat(256);
+    let longer = "a".repeat(300);
+    let longer_b = "b".repeat(300);
+
+    assert_eq!(edit_distance(&long, &longer), 44);
+    assert_eq!(edit_distance(&longer_b, &long), 300);
+    assert_eq!(edit_distance(&"个".repeat(256), &"个".repeat(1000)), 744);
+
+    must_panic(file_span!(), || edit_distance(&"a".repeat(257), &longer)).unwrap();
+    must_panic(file_span!(), || edit_distance(&longer, &longer_b)).unwrap();
+}
+
+#[test]
+fn closest_match_test() {
+    const KEYS: &[&str] = &["color", "size", "shape", "sized"];
+
+    assert_eq!(closest_match("color", KEYS, 0), Some(&"color"));
+    assert_eq!(closest_match("colour", KEYS, 0), None);
+    assert_eq!(closest_match("colour", KEYS, 1), Some(&"color"));
+    assert_eq!(closest_match("sise", KEYS, 2), Some(&"size"));
+    assert_eq!(closest_match("sizeed", KEYS, 2), Some(&"sized"));
+    assert_eq!(closest_match("shaep", KEYS, 2), Some(&"shape"));
+    assert_eq!(closest_match("weight", KEYS, 2), None);
+    assert_eq!(closest_match("weight", KEYS, usize::MAX), Some(&"size"));
+    assert_eq!(closest_match("", KEYS, 4), Some(&"size"));
+    assert_eq!(closest_match("color", &[], usize::MAX), None);
+
+    // the first of the equally close candidates is returned
+    assert_eq!(closest_match("sizes", KEYS, 2), Some(&"size"));
+    assert_eq!(
+        closest_match("sizes", &["sized", "size"], 2),
+        Some(&"sized")
+    );
+
+    const CLOSEST: Option<&&str> = closest_match("shap", KEYS, 1);
+    assert_eq!(CLOSEST, Some(&"shape"));
+}
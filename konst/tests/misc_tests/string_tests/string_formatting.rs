@@ -0,0 +1,139 @@
+use konst::{
+    base64::{self, Config},
+    string::{self, ArrayStr},
+};
+
+// compares the output of `string::format` with that of `std::format`
+macro_rules! assert_fmt {
+    ($($args:tt)*) => {
+        assert_eq!(string::format!($($args)*), format!($($args)*));
+    };
+}
+
+// runs the code with `VAL` being constants of every integer type
+macro_rules! for_each_int {
+    (@inner $code:tt $(($ty:ty, $val:expr))*) => {$({
+        const VAL: $ty = $val;
+        for_each_int!(@code $code)
+    })*};
+    (@code [$($code:tt)*]) => {{ $($code)* }};
+    ($($code:tt)*) => {
+        for_each_int! {
+            @inner [$($code)*]
+            (u8, 0) (u8, 255) (u16, 1000) (u32, u32::MAX) (u64, u64::MAX)
+            (u128, 12345) (u128, u128::MAX) (usize, usize::MAX)
+            (i8, 0) (i8, -1) (i8, i8::MIN) (i8, 127) (i16, -1000) (i32, i32::MIN)
+            (i32, 100) (i64, i64::MIN) (i128, -12345) (i128, i128::MIN)
+            (i128, i128::MAX) (isize, -7)
+        }
+    };
+}
+
+const PORT: u16 = 80;
+const NAME: &str = "konst";
+
+#[test]
+fn format_arguments_test() {
+    assert_eq!(string::format!(""), "");
+    assert_fmt!("hello");
+    assert_fmt!("{{}} {{{}}}", 3);
+    assert_fmt!("{}-{}-{}", 1, 2, 3);
+    assert_fmt!("{} {:x} {:b} {}", -5, -1, 0x_F, 1_000);
+    assert_fmt!("{1}-{0}-{1}", 1, 2);
+    assert_fmt!("{}-{0}-{}", 1, 2);
+    assert_fmt!("{a}-{b}-{a}", a = 3, b = "foo");
+    assert_fmt!("{}-{a}", 5, a = 3,);
+    assert_fmt!("{PORT} {NAME} {PORT:?} {NAME:?}");
+    assert_fmt!("{NAME}", NAME = "shadowed");
+    assert_fmt!(concat!("{}", "-{}"), 1, 2);
+    assert_fmt!(r"{}\n", 1);
+}
+
+#[test]
+fn format_args_named_like_macro_internals_test() {
+    // the macro declares constants with these names internally
+    const LEN: usize = 7;
+    const CONC: &str = "conc";
+    const STR: &str = "str";
+
+    assert_fmt!("len={LEN} {CONC} {STR}");
+    assert_fmt!("{} {}", LEN, STR);
+}
+
+#[test]
+fn format_types_test() {
+    assert_fmt!(
+        "{} {} {} {} {} {} {} {} {} {} {} {}",
+        0u8,
+        u16::MAX,
+        u32::MAX,
+        u64::MAX,
+        u128::MAX,
+        usize::MAX,
+        i8::MIN,
+        i16::MIN,
+        i32::MIN,
+        i64::MIN,
+        i128::MIN,
+        isize::MIN,
+    );
+    assert_fmt!("{} {} {} {:?} {:?}", true, false, 'ñ', true, 'ñ');
+    assert_fmt!("{} {}", &5u8, &-5i8);
+    assert_fmt!("{} {}", "foo", &"bar");
+
+    {
+        const LEN: usize = base64::encoded_len(5, Config::STANDARD);
+        const S: ArrayStr<LEN> = base64::encode(b"hello", Config::STANDARD);
+        assert_eq!(
+            string::format!("{S}|{:?}|{:>10}", S, &S),
+            r#"aGVsbG8=|"aGVsbG8="|  aGVsbG8="#,
+        );
+    }
+}
+
+#[test]
+fn format_debug_test() {
+    assert_fmt!("{:?}", "");
+    assert_fmt!("{:?}", "\0\t\r\n\\\"'\x01\x1b\x7f ñ个");
+    assert_fmt!("{:?} {:?} {:?}", '\'', '"', '\n');
+    assert_fmt!("{:?} {:?} {:?}", '\x00', '\x1f', '\x7f');
+    assert_fmt!("{:?} {:?}", -10i32, 10u32);
+}
+
+#[test]
+fn format_radix_test() {
+    for_each_int! {
+        assert_fmt!("{:x} {:X} {:b} {:o}", VAL, VAL, VAL, VAL);
+        assert_fmt!("{:#x} {:#X} {:#b} {:#o}", VAL, VAL, VAL, VAL);
+        assert_fmt!("{:#010x}|{:+}|{:+#x}|{:08b}", VAL, VAL, VAL, VAL);
+    }
+}
+
+#[test]
+fn format_padding_test() {
+    for_each_int! {
+        assert_fmt!("[{:5}] [{:<5}] [{:^5}] [{:>5}]", VAL, VAL, VAL, VAL);
+        assert_fmt!("[{:*<6}] [{:-^6}] [{:_>6}]", VAL, VAL, VAL);
+        assert_fmt!("[{:06}] [{:<06}] [{:+06}] [{:#06x}]", VAL, VAL, VAL, VAL);
+        assert_fmt!("[{:1}] [{:01}] [{:x<1}]", VAL, VAL, VAL);
+    }
+
+    assert_fmt!("[{:5}] [{:<5}] [{:^5}] [{:>5}]", "ab", "ab", "ab", "ab");
+    assert_fmt!("[{:^6}] [{:^7}] [{:ñ^7}] [{:个>4}]", "ab", "ab", "ab", "ñ");
+    assert_fmt!("[{:5}] [{:^5}] [{:>5}]", 'ñ', true, false);
+    assert_fmt!("[{:05}] [{:+5}] [{:#5}]", "ab", "ab", 'c');
+    assert_fmt!("[{:8?}] [{:>8?}] [{:^5?}]", "a\n", "个", 'c');
+    assert_fmt!("[{:1}] [{:2}]", "abc", "个人");
+}
+
+#[test]
+fn format_is_const_test() {
+    const MIN: u16 = 1024;
+    const MAX: u16 = 49151;
+    const MSG: &str = string::format!("port {PORT} out of range {MIN}..={MAX}");
+
+    assert_eq!(MSG, "port 80 out of range 1024..=49151");
+
+    const LEN: usize = string::format!("{:>10}", NAME).len();
+    assert_eq!(LEN, 10);
+}
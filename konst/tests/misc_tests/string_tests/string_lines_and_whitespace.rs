@@ -0,0 +1,143 @@
+use konst::{
+    iter::{self, collect_const},
+    string,
+};
+
+const STRINGS: &[&str] = &[
+    "",
+    "\n",
+    "\r\n",
+    "\r",
+    "\n\n",
+    "\r\n\r\n",
+    "foo",
+    "foo\n",
+    "foo\r\n",
+    "foo\r",
+    "\nfoo",
+    "foo\nbar",
+    "foo\r\nbar\r\n",
+    "foo\rbar\n",
+    "foo\r\r\nbar",
+    "foo\n\nbar\n\n",
+    "  foo  bar\tbaz \n",
+    "\t\x0B\x0Cfoo\u{A0}bar\u{3000}baz\u{85}",
+    "ñ 个\n ñ\u{2028}个 ",
+    "a b",
+    " ",
+    "   ",
+];
+
+macro_rules! assert_iter_like_std {
+    ($kfn:ident, $sfn:ident) => {
+        for &s in STRINGS {
+            let expected: Vec<&str> = s.$sfn().collect();
+            let rev_expected: Vec<&str> = s.$sfn().rev().collect();
+
+            {
+                let mut iter = string::$kfn(s);
+                let mut found = Vec::new();
+                while let Some(x) = iter.next() {
+                    found.push(x);
+                }
+                assert_eq!(found, expected, "{s:?}");
+                assert_eq!(iter.remainder(), "");
+            }
+            {
+                let mut iter = string::$kfn(s).rev();
+                let mut found = Vec::new();
+                while let Some(x) = iter.next() {
+                    found.push(x);
+                }
+                assert_eq!(found, rev_expected, "{s:?}");
+            }
+
+            // alternating between both ends
+            for start_from_back in [false, true] {
+                let mut iter = string::$kfn(s);
+                let mut std_iter = s.$sfn();
+                let mut from_back = start_from_back;
+                loop {
+                    let (found, expected) = if from_back {
+                        (iter.next_back(), std_iter.next_back())
+                    } else {
+                        (iter.next(), std_iter.next())
+                    };
+                    assert_eq!(found, expected, "{s:?}");
+                    if found.is_none() {
+                        break;
+                    }
+                    from_back = !from_back;
+                }
+            }
+
+            // copy
+            {
+                let mut iter = string::$kfn(s);
+                _ = iter.next();
+                let mut copy = iter.copy();
+                assert_eq!(copy.next(), iter.next(), "{s:?}");
+                assert_eq!(copy.remainder(), iter.remainder(), "{s:?}");
+            }
+        }
+    };
+}
+
+#[test]
+fn lines_test() {
+    assert_iter_like_std! {lines, lines}
+}
+
+#[test]
+fn split_whitespace_test() {
+    assert_iter_like_std! {split_whitespace, split_whitespace}
+}
+
+#[test]
+fn split_ascii_whitespace_test() {
+    assert_iter_like_std! {split_ascii_whitespace, split_ascii_whitespace}
+}
+
+#[test]
+fn remainder_test() {
+    let mut iter = string::split_whitespace(" foo  bar ");
+    assert_eq!(iter.next(), Some("foo"));
+    assert_eq!(iter.remainder(), "  bar ");
+    assert_eq!(iter.next_back(), Some("bar"));
+    assert_eq!(iter.remainder(), "  ");
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remainder(), "");
+
+    let mut iter = string::lines("foo\r\nbar\r\n").rev();
+    assert_eq!(iter.next(), Some("bar"));
+    assert_eq!(iter.remainder(), "foo\r\n");
+}
+
+#[test]
+fn const_iter_test() {
+    const TABLE: &str = "
+        name   age
+        foo    3
+        bar    5
+    ";
+
+    const LINES: [&str; 5] = collect_const!(&str => string::lines(TABLE));
+    assert_eq!(LINES, TABLE.lines().collect::<Vec<_>>()[..]);
+
+    const WORDS: [&str; 6] = collect_const!(&str => string::split_whitespace(TABLE));
+    assert_eq!(WORDS, ["name", "age", "foo", "3", "bar", "5"]);
+
+    const REV_WORDS: [&str; 6] =
+        collect_const!(&str => string::split_ascii_whitespace(TABLE).rev());
+    assert_eq!(REV_WORDS, ["5", "bar", "3", "foo", "age", "name"]);
+
+    const LINE_COUNT: usize = iter::eval!(string::lines(TABLE), count());
+    assert_eq!(LINE_COUNT, 5);
+
+    const WORD_COUNT: usize = iter::eval!(
+        string::lines(TABLE),
+        map(|line| iter::eval!(string::split_whitespace(line), count())),
+        fold(0, |acc, n| acc + n),
+    );
+    assert_eq!(WORD_COUNT, 6);
+}
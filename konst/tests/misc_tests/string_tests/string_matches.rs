@@ -0,0 +1,103 @@
+use konst::{
+    iter::collect_const,
+    string::{self, CharPredicate},
+};
+
+const STRINGS: &[&str] = &[
+    "",
+    "a",
+    "aa",
+    "aaa",
+    "aaaaa",
+    "foo-bar-baz",
+    "foo--bar---",
+    "-",
+    "lolololfoo",
+    "ñ个ñ个ñ",
+    "1-2, 3 and 45",
+    "\u{A0}foo\u{3000}bar\u{85}",
+];
+
+macro_rules! assert_like_std {
+    ($kpat:expr, $spat:expr) => {
+        for &s in STRINGS {
+            {
+                let expected: Vec<&str> = s.matches($spat).collect();
+                let mut iter = string::matches(s, $kpat);
+                let mut found = Vec::new();
+                while let Some(x) = iter.next() {
+                    found.push(x);
+                }
+                assert_eq!(found, expected, "{s:?}");
+                assert_eq!(string::count_matches(s, $kpat), expected.len(), "{s:?}");
+            }
+            {
+                let expected: Vec<(usize, &str)> = s.match_indices($spat).collect();
+                let mut iter = string::match_indices(s, $kpat);
+                let mut found = Vec::new();
+                while let Some(x) = iter.next() {
+                    found.push(x);
+                    let copy = iter.copy();
+                    assert_eq!(copy.remainder(), iter.remainder());
+                }
+                assert_eq!(found, expected, "{s:?}");
+            }
+            {
+                let expected: Vec<(usize, &str)> = s.rmatch_indices($spat).collect();
+                let mut iter = string::rmatch_indices(s, $kpat);
+                let mut found = Vec::new();
+                while let Some(x) = iter.next() {
+                    found.push(x);
+                }
+                assert_eq!(found, expected, "{s:?}");
+            }
+        }
+    };
+}
+
+#[test]
+fn str_pattern_test() {
+    assert_like_std! {"", ""}
+    assert_like_std! {"a", "a"}
+    assert_like_std! {"aa", "aa"}
+    assert_like_std! {"-", "-"}
+    assert_like_std! {"--", "--"}
+    assert_like_std! {"lol", "lol"}
+    assert_like_std! {"lolol", "lolol"}
+    assert_like_std! {"ñ个", "ñ个"}
+    assert_like_std! {"个ñ", "个ñ"}
+    assert_like_std! {"foo-bar-baz", "foo-bar-baz"}
+    assert_like_std! {"not found", "not found"}
+}
+
+#[test]
+fn char_pattern_test() {
+    assert_like_std! {'a', 'a'}
+    assert_like_std! {'-', '-'}
+    assert_like_std! {'个', '个'}
+    assert_like_std! {'\u{3000}', '\u{3000}'}
+}
+
+#[test]
+fn char_set_pattern_test() {
+    assert_like_std! {['-', 'o', '个'], ['-', 'o', '个']}
+    assert_like_std! {&['a', 'ñ'] as &[char], &['a', 'ñ'] as &[char]}
+    assert_like_std! {CharPredicate::ASCII_DIGIT, |c: char| c.is_ascii_digit()}
+    assert_like_std! {CharPredicate::WHITESPACE, char::is_whitespace}
+    assert_like_std! {CharPredicate::ANY, |_| true}
+}
+
+#[test]
+fn const_matches_test() {
+    const CSV: &str = "foo,1\nbar,2\nbaz,3\n";
+    const RECORDS: usize = string::count_matches(CSV, '\n');
+    assert_eq!(RECORDS, 3);
+
+    const NUMS: [&str; RECORDS] =
+        collect_const!(&str => string::matches(CSV, CharPredicate::ASCII_DIGIT));
+    assert_eq!(NUMS, ["1", "2", "3"]);
+
+    const COMMAS: [(usize, &str); RECORDS] =
+        collect_const!((usize, &str) => string::rmatch_indices(CSV, ','));
+    assert_eq!(COMMAS, [(15, ","), (9, ","), (3, ",")]);
+}
@@ -0,0 +1,343 @@
+use konst::string::{self, Float, IntFmt, Integer};
+
+use crate::misc_tests::test_utils::must_panic;
+
+// evaluates `$expr` with `$n` as a constant equal to `$len`,
+// so that the length of a string can be passed as a const argument.
+macro_rules! dispatch_on_len {
+    ($len:expr, $n:ident => $expr:expr) => {
+        dispatch_on_len! {
+            @inner $len, $n => $expr;
+            1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20
+            21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38 39 40
+            41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60
+            61 62 63 64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80
+            81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96 97 98 99 100
+            101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120
+            121 122 123 124 125 126 127 128 129 130 131 132 133 134 135 136 137 138 139 140
+            141 142 143 144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159 160
+            161 162 163 164 165 166 167 168 169 170 171 172 173 174 175 176 177 178 179 180
+            181 182 183 184 185 186 187 188 189 190 191 192 193 194 195 196 197 198 199 200
+            201 202 203 204 205 206 207 208 209 210 211 212 213 214 215 216 217 218 219 220
+            221 222 223 224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239 240
+            241 242 243 244 245 246 247 248 249 250 251 252 253 254 255 256 257 258 259 260
+            261 262 263 264 265 266 267 268 269 270 271 272 273 274 275 276 277 278 279 280
+            281 282 283 284 285 286 287 288 289 290 291 292 293 294 295 296 297 298 299 300
+            301 302 303 304 305 306 307 308 309 310 311 312 313 314 315 316 317 318 319 320
+            321 322 323 324 325 326 327 328 329 330
+        }
+    };
+    (@inner $len:expr, $n:ident => $expr:expr; $($lit:literal)*) => {
+        match $len {
+            $($lit => {
+                const $n: usize = $lit;
+                $expr
+            })*
+            len => panic!("unexpected length: {len}"),
+        }
+    };
+}
+
+fn int_to_string<I: Integer>(int: I, fmt: IntFmt) -> String {
+    dispatch_on_len! {
+        string::int_to_str_len(int, fmt),
+        N => string::int_to_array_str::<I, N>(int, fmt).as_str().to_string()
+    }
+}
+
+// the reference implementation of integer formatting
+fn expected_int(negative: bool, mut magnitude: u128, radix: u32, uppercase: bool) -> String {
+    let mut digits = Vec::new();
+    loop {
+        let digit = char::from_digit((magnitude % radix as u128) as u32, radix).unwrap();
+        digits.push(if uppercase {
+            digit.to_ascii_uppercase()
+        } else {
+            digit
+        });
+        magnitude /= radix as u128;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if negative {
+        digits.push('-');
+    }
+    digits.iter().rev().collect()
+}
+
+macro_rules! test_int_type {
+    ($($ty:ident)*) => ($({
+        let mut values = vec![0, 1, 2, 9, 10, 15, 16, 35, 36, 100, $ty::MIN, $ty::MAX];
+        values.extend([$ty::MIN + 1, $ty::MAX - 1, $ty::MAX / 2, $ty::MIN / 2]);
+        values.extend((0..$ty::BITS).map(|shift| (1 as $ty).wrapping_shl(shift)));
+        values.extend((0..$ty::BITS).map(|shift| $ty::MAX.wrapping_shr(shift)));
+
+        for int in values {
+            assert_eq!(int_to_string(int, IntFmt::new()), int.to_string());
+
+            #[allow(unused_comparisons)]
+            let negative = int < 0;
+            let magnitude = (int as i128).unsigned_abs() as u128;
+            let magnitude = if negative { magnitude } else { int as u128 };
+
+            for radix in 2..=36 {
+                for uppercase in [false, true] {
+                    let fmt = IntFmt::new().radix(radix).uppercase(uppercase);
+                    assert_eq!(
+                        int_to_string(int, fmt),
+                        expected_int(negative, magnitude, radix, uppercase),
+                        "{int} {radix} {uppercase}",
+                    );
+                }
+            }
+        }
+    })*)
+}
+
+#[test]
+fn int_to_str_radix_test() {
+    test_int_type! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+    assert_eq!(int_to_string(255u8, IntFmt::new().radix(16)), "ff");
+    assert_eq!(
+        int_to_string(255u8, IntFmt::new().radix(16).uppercase(true)),
+        "FF"
+    );
+    assert_eq!(int_to_string(-255i16, IntFmt::new().radix(16)), "-ff");
+    assert_eq!(int_to_string(-8i8, IntFmt::new().radix(2)), "-1000");
+    assert_eq!(int_to_string(35u8, IntFmt::new().radix(36)), "z");
+    assert_eq!(
+        int_to_string(u128::MAX, IntFmt::new().radix(2)),
+        "1".repeat(128)
+    );
+}
+
+#[test]
+fn int_to_str_options_test() {
+    let cases: &[(i64, IntFmt, &str)] = &[
+        (5, IntFmt::new().plus_sign(true), "+5"),
+        (0, IntFmt::new().plus_sign(true), "+0"),
+        (-5, IntFmt::new().plus_sign(true), "-5"),
+        (5, IntFmt::new().min_digits(3), "005"),
+        (-5, IntFmt::new().min_digits(3), "-005"),
+        (12345, IntFmt::new().min_digits(3), "12345"),
+        (1234567, IntFmt::new().grouping(3, ','), "1,234,567"),
+        (-1234567, IntFmt::new().grouping(3, ','), "-1,234,567"),
+        (123456, IntFmt::new().grouping(3, '_'), "123_456"),
+        (12, IntFmt::new().grouping(3, ','), "12"),
+        (0, IntFmt::new().grouping(1, ','), "0"),
+        (1234, IntFmt::new().grouping(1, '.'), "1.2.3.4"),
+        (1234567, IntFmt::new().grouping(3, '个'), "1个234个567"),
+        (
+            12,
+            IntFmt::new().min_digits(7).grouping(3, ','),
+            "0,000,012",
+        ),
+        (12, IntFmt::new().min_digits(6).grouping(3, ','), "000,012"),
+        (
+            0xABCD,
+            IntFmt::new().radix(16).min_digits(8).grouping(4, '_'),
+            "0000_abcd",
+        ),
+        (42, IntFmt::new().pad(5, ' '), "   42"),
+        (-42, IntFmt::new().pad(5, ' '), "  -42"),
+        (42, IntFmt::new().plus_sign(true).pad(5, '*'), "**+42"),
+        (42, IntFmt::new().pad(1, ' '), "42"),
+        (42, IntFmt::new().pad(4, 'ñ'), "ññ42"),
+        (1234, IntFmt::new().grouping(3, '个').pad(6, '-'), "-1个234"),
+        (
+            -1234,
+            IntFmt::new().min_digits(6).grouping(3, ',').pad(10, ' '),
+            "  -001,234",
+        ),
+    ];
+
+    for &(int, fmt, expected) in cases {
+        assert_eq!(int_to_string(int, fmt), expected, "{int} {fmt:?}");
+    }
+
+    assert_eq!(IntFmt::new(), IntFmt::default());
+}
+
+#[test]
+fn int_to_str_panics_test() {
+    must_panic(file_span!(), || IntFmt::new().radix(1)).unwrap();
+    must_panic(file_span!(), || IntFmt::new().radix(37)).unwrap();
+    _ = IntFmt::new().radix(2).radix(36);
+
+    must_panic(file_span!(), || {
+        string::int_to_array_str::<_, 2>(100u8, IntFmt::new())
+    })
+    .unwrap();
+    must_panic(file_span!(), || {
+        string::int_to_array_str::<_, 4>(100u8, IntFmt::new())
+    })
+    .unwrap();
+    assert_eq!(
+        string::int_to_array_str::<_, 3>(100u8, IntFmt::new()).as_str(),
+        "100"
+    );
+}
+
+#[test]
+fn from_int_macro_test() {
+    const HEX: IntFmt = IntFmt::new().radix(16).min_digits(4);
+    const NEG: i16 = -0x1F;
+
+    assert_eq!(string::from_int!(0u8), "0");
+    assert_eq!(string::from_int!(u128::MAX), u128::MAX.to_string());
+    assert_eq!(string::from_int!(i64::MIN), i64::MIN.to_string());
+    assert_eq!(string::from_int!(NEG, HEX), "-001f");
+    assert_eq!(string::from_int!(255usize, HEX,), "00ff");
+    assert_eq!(string::from_int!(-3), "-3");
+
+    const _: &str = string::from_int!(10u32);
+}
+
+#[test]
+fn from_int_macro_args_named_like_macro_internals_test() {
+    // the macro declares constants with these names internally
+    const LEN: usize = 7;
+    const CONC: IntFmt = IntFmt::new().min_digits(3);
+
+    assert_eq!(string::from_int!(LEN), "7");
+    assert_eq!(string::from_int!(LEN, CONC), "007");
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn float_to_string<F: Float>(float: F) -> String {
+    dispatch_on_len! {
+        string::float_to_str_len(float),
+        N => string::float_to_array_str::<F, N>(float).as_str().to_string()
+    }
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+#[test]
+fn float_to_str_special_test() {
+    let f64s = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        0.5,
+        1.5,
+        10.0,
+        1e15,
+        1e16,
+        1e17,
+        1e21,
+        1e22,
+        1e23,
+        123456.789,
+        0.3,
+        0.1 + 0.2,
+        1.0 / 3.0,
+        2.0f64.powi(53),
+        2.0f64.powi(53) + 2.0,
+        2.0f64.powi(-1074),
+        2.0f64.powi(-1022),
+        5e-324,
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::MIN,
+        f64::EPSILON,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        core::f64::consts::PI,
+        core::f64::consts::E,
+    ];
+    for float in f64s {
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+    }
+
+    let f32s = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        0.1 + 0.2,
+        1.0 / 3.0,
+        1e10,
+        16777216.0,
+        16777218.0,
+        1e-45,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::MIN,
+        f32::EPSILON,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        core::f32::consts::PI,
+    ];
+    for float in f32s {
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+    }
+}
+
+#[test]
+fn float_to_str_random_test() {
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+
+    for _ in 0..3000 {
+        let bits = rng.next();
+
+        let float = f64::from_bits(bits);
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+
+        let float = f32::from_bits(bits as u32);
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+
+        // floats with a small exponent, to test the common case
+        let float = f64::from_bits((bits & !(0x7FF << 52)) | ((1023 + (bits >> 58) - 32) << 52));
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+
+        // integers that are exactly representable
+        let float = (bits >> 11) as f64;
+        assert_eq!(float_to_string(float), float.to_string(), "{float:?}");
+    }
+}
+
+#[test]
+fn float_to_str_panics_test() {
+    must_panic(file_span!(), || string::float_to_array_str::<_, 2>(0.25f64)).unwrap();
+    must_panic(file_span!(), || string::float_to_array_str::<_, 5>(0.25f64)).unwrap();
+    assert_eq!(string::float_to_array_str::<_, 4>(0.25f64).as_str(), "0.25");
+}
+
+#[test]
+fn from_float_macro_test() {
+    const HALF: f32 = 0.5;
+
+    assert_eq!(string::from_float!(HALF), "0.5");
+    assert_eq!(string::from_float!(-HALF,), "-0.5");
+    assert_eq!(string::from_float!(f64::MAX), f64::MAX.to_string());
+    assert_eq!(string::from_float!(5e-324), 5e-324f64.to_string());
+    assert_eq!(string::from_float!(f32::NAN), "NaN");
+
+    const _: &str = string::from_float!(1.0f64);
+}
+
+#[test]
+fn from_float_macro_args_named_like_macro_internals_test() {
+    // the macro declares constants with these names internally
+    const STR: f64 = 1.5;
+
+    assert_eq!(string::from_float!(STR), "1.5");
+}
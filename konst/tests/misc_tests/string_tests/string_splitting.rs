@@ -334,10 +334,70 @@ fn all_perms_test() {
             test_iter!(rsplit, string, delim);
             test_iter!(split_terminator, string, delim);
             test_iter!(rsplit_terminator, string, delim);
+
+            for n in 0..6 {
+                assert_eq!(
+                    collect_const_iter!(konst::string::splitn(string, n, delim)),
+                    string.splitn(n, delim).collect::<Vec<_>>(),
+                    "failed at method: splitn string: {string:?} n: {n} delim: {delim:?}",
+                );
+                assert_eq!(
+                    collect_const_iter!(konst::string::rsplitn(string, n, delim)),
+                    string.rsplitn(n, delim).collect::<Vec<_>>(),
+                    "failed at method: rsplitn string: {string:?} n: {n} delim: {delim:?}",
+                );
+            }
         }
     }
 }
 
+#[test]
+fn splitn_remainder_test() {
+    {
+        let mut iter = string::splitn("key=value=with=equals", 3, '=');
+        assert_eq!(iter.remainder(), "key=value=with=equals");
+        assert_eq!(iter.next(), Some("key"));
+        assert_eq!(iter.remainder(), "value=with=equals");
+        assert_eq!(iter.copy().next(), Some("value"));
+        assert_eq!(iter.next(), Some("value"));
+        assert_eq!(iter.remainder(), "with=equals");
+        assert_eq!(iter.next(), Some("with=equals"));
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.remainder(), "");
+    }
+    {
+        let mut iter = string::splitn("foo=bar", 5, '=');
+        assert_eq!(iter.next(), Some("foo"));
+        assert_eq!(iter.remainder(), "bar");
+        assert_eq!(iter.next(), Some("bar"));
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.next(), None);
+    }
+    {
+        let mut iter = string::splitn("foo=bar", 0, '=');
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.next(), None);
+    }
+    {
+        let mut iter = string::rsplitn("key=value=with=equals", 3, "=");
+        assert_eq!(iter.remainder(), "key=value=with=equals");
+        assert_eq!(iter.next(), Some("equals"));
+        assert_eq!(iter.remainder(), "key=value=with");
+        assert_eq!(iter.copy().next(), Some("with"));
+        assert_eq!(iter.next(), Some("with"));
+        assert_eq!(iter.remainder(), "key=value");
+        assert_eq!(iter.next(), Some("key=value"));
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.next(), None);
+    }
+    {
+        let mut iter = string::rsplitn("foo", 0, "=");
+        assert_eq!(iter.remainder(), "");
+        assert_eq!(iter.next(), None);
+    }
+}
+
 #[test]
 fn methods_are_const() {
     const fn __(string: &str, delim: &str) {
@@ -349,6 +409,16 @@ fn methods_are_const() {
             let iter: string::RSplit<'_, '_, &str> = string::rsplit(string, delim);
             let _ = iter.copy().next();
         }
+        {
+            let iter: string::SplitN<'_, '_, &str> = string::splitn(string, 2, delim);
+            let _ = iter.copy().next();
+            let _ = iter.remainder();
+        }
+        {
+            let iter: string::RSplitN<'_, '_, &str> = string::rsplitn(string, 2, delim);
+            let _ = iter.copy().next();
+            let _ = iter.remainder();
+        }
         {
             let iter: string::SplitTerminator<'_, '_, &str> =
                 string::split_terminator(string, delim);
@@ -0,0 +1,118 @@
+use konst::string;
+
+macro_rules! assert_replace {
+    ($(($string:expr, $pattern:expr, $replacement:expr))*) => ($({
+        const STR: &str = string::replace!($string, $pattern, $replacement);
+        assert_eq!(
+            STR,
+            $string.replace($pattern, &$replacement.to_string()),
+            "{:?} {:?} {:?}",
+            $string,
+            $pattern,
+            $replacement,
+        );
+    })*)
+}
+
+#[test]
+fn replace_test() {
+    assert_replace! {
+        ("", "", "")
+        ("", "", "x")
+        ("", "a", "x")
+        ("", 'a', "x")
+        ("abc", "", "")
+        ("abc", "", "-")
+        ("ñ个", "", "-")
+        ("ñ个", "", '个')
+        ("foo bar foo", "foo", "qux")
+        ("foo bar foo", "foo", "")
+        ("foo bar foo", "foo", 'x')
+        ("foo bar foo", 'o', "0")
+        ("foo bar foo", 'o', 'ñ')
+        ("foo bar foo", ' ', "")
+        ("foo bar foo", "foo bar foo", "baz")
+        ("foo bar foo", "foo bar foo!", "baz")
+        ("aaaa", "aa", "b")
+        ("aaaaa", "aa", "b")
+        ("aaa", "a", "aa")
+        ("aaab", "aab", "x")
+        ("abababc", "abc", "x")
+        ("ñ个ñ个", "个", "ñ")
+        ("ñ个ñ个", 'ñ', "")
+        ("ñ个ñ个", "ñ个", "个ñ")
+    }
+
+    const CRATE_NAME: &str = "my-crate";
+    const MODULE: &str = string::replace!(CRATE_NAME, '-', '_');
+    assert_eq!(MODULE, "my_crate");
+}
+
+macro_rules! assert_repeat {
+    ($(($string:expr, $times:expr))*) => ($({
+        const STR: &str = string::repeat!($string, $times);
+        assert_eq!(STR, $string.to_string().repeat($times), "{:?} {:?}", $string, $times);
+    })*)
+}
+
+#[test]
+fn repeat_test() {
+    assert_repeat! {
+        ("", 0)
+        ("", 10)
+        ("a", 0)
+        ("a", 1)
+        ("a", 5)
+        ("ab", 3)
+        ("ñ个", 4)
+        ('a', 0)
+        ('a', 3)
+        ('ñ', 2)
+        ('个', 3)
+    }
+
+    const TIMES: usize = 3;
+    assert_eq!(string::repeat!("-=", TIMES), "-=-=-=");
+}
+
+macro_rules! assert_case {
+    ($($string:expr)*) => ($({
+        const UPPER: &str = string::to_upper!($string);
+        const LOWER: &str = string::to_lower!($string);
+        assert_eq!(UPPER, $string.to_ascii_uppercase(), "{:?}", $string);
+        assert_eq!(LOWER, $string.to_ascii_lowercase(), "{:?}", $string);
+    })*)
+}
+
+#[test]
+fn case_test() {
+    assert_case! {
+        ""
+        "a"
+        "A"
+        "hello world"
+        "HELLO WORLD"
+        "Hello World 123_!@"
+        "ñandú ÑANDÚ"
+        "个 z Z"
+        "αβγ ΑΒΓ"
+    }
+
+    const PREFIX: &str = "app";
+    const ENV_VAR: &str = string::to_upper!(string::str_concat!(&[PREFIX, "_log"]));
+    assert_eq!(ENV_VAR, "APP_LOG");
+}
+
+#[test]
+fn args_named_like_macro_internals_test() {
+    // the macros declare constants with these names internally
+    const STR: &str = "aba";
+    const LEN: usize = 2;
+    const CONC: &str = "b";
+
+    assert_eq!(string::replace!(STR, "a", "c"), "cbc");
+    assert_eq!(string::replace!("aba", CONC, STR), "aabaa");
+    assert_eq!(string::repeat!(STR, LEN), "abaaba");
+    assert_eq!(string::to_upper!(STR), "ABA");
+    assert_eq!(string::to_lower!(CONC), "b");
+}
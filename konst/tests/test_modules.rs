@@ -11,9 +11,16 @@ mod misc_tests {
 
     mod array_tests;
 
+    mod base64_tests;
+
     #[cfg(feature = "cmp")]
     mod cmp_tests;
 
+    #[cfg(feature = "iter")]
+    mod csv_tests;
+
+    mod decimal_tests;
+
     mod drop_flavor_tests;
 
     #[cfg(feature = "iter")]
@@ -0,0 +1,516 @@
+#[allow(unused_imports)]
+use crate::used_proc_macro::{
+    Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree,
+    token_stream::IntoIter as TSIterator,
+};
+
+use crate::{
+    Pattern,
+    utils::{Error, ident_token, paren, punct_token},
+};
+
+use std::iter;
+
+#[cfg(test)]
+mod formatting_tests;
+
+// parses the input of the `string::format` macro,
+// outputting an invocation of the `__string_format_inner` macro
+pub(crate) fn macro_impl(input_tokens: TokenStream) -> Result<TokenStream, (Error, TokenStream)> {
+    let mut iter = input_tokens.into_iter();
+
+    let krate = crate::unwrap_crate_token(iter.next());
+
+    let inputs = parse_inputs(&mut iter).map_err(|e| (e, krate.clone()))?;
+
+    let span = Span::call_site();
+    let mut out = TokenStream::new();
+
+    out.extend(krate);
+    out.extend(crate::utils::punct_joint_token2(':', ':', span));
+    out.extend(ident_token("__string_format_inner", span));
+    out.extend(punct_token('!', span));
+    out.extend(iter::once(crate::utils::brace(span, |out| {
+        out.extend(ident_token("args", span));
+        out.extend(iter::once(paren(span, |out| {
+            for (i, arg) in inputs.args.into_iter().enumerate() {
+                out.extend(iter::once(paren(span, |out| {
+                    out.extend(iter::once(TokenTree::from(Literal::usize_unsuffixed(i))));
+                    out.extend(iter::once(paren(arg.span, |out| out.extend(arg.tokens))));
+                })));
+            }
+        })));
+
+        out.extend(ident_token("pieces", span));
+        out.extend(iter::once(paren(span, |out| {
+            for piece in &inputs.pieces {
+                out.extend(iter::once(paren(span, |out| output_piece(piece, out))));
+            }
+        })));
+    })));
+
+    Ok(out)
+}
+
+fn output_piece(piece: &Piece, out: &mut TokenStream) {
+    let span = Span::call_site();
+
+    match piece {
+        Piece::Str(string) => {
+            out.extend(ident_token("str", span));
+            out.extend(iter::once(TokenTree::from(Literal::string(string))));
+        }
+        Piece::Arg { index, spec } => {
+            let FmtSpec {
+                fill,
+                align,
+                sign_plus,
+                alternate,
+                zero_pad,
+                width,
+                kind,
+            } = *spec;
+
+            out.extend(ident_token("arg", span));
+            out.extend(iter::once(TokenTree::from(Literal::usize_unsuffixed(
+                *index,
+            ))));
+            out.extend(iter::once(TokenTree::from(Literal::character(fill))));
+            out.extend(ident_token(align, span));
+            for flag in [sign_plus, alternate, zero_pad] {
+                out.extend(ident_token(if flag { "true" } else { "false" }, span));
+            }
+            out.extend(iter::once(TokenTree::from(Literal::usize_unsuffixed(
+                width,
+            ))));
+            out.extend(ident_token(kind, span));
+        }
+    }
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) enum Piece {
+    Str(String),
+    Arg { index: usize, spec: FmtSpec },
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Copy, Clone)]
+pub(crate) struct FmtSpec {
+    fill: char,
+    // the name of the `__FmtAlign` variant
+    align: &'static str,
+    sign_plus: bool,
+    alternate: bool,
+    zero_pad: bool,
+    width: usize,
+    // the name of the `__FmtKind` variant
+    kind: &'static str,
+}
+
+impl Default for FmtSpec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: "Unspecified",
+            sign_plus: false,
+            alternate: false,
+            zero_pad: false,
+            width: 0,
+            kind: "Display",
+        }
+    }
+}
+
+struct Inputs {
+    args: Vec<Arg>,
+    pieces: Vec<Piece>,
+}
+
+struct Arg {
+    tokens: TokenStream,
+    span: Span,
+}
+
+struct NamedArg {
+    name: String,
+    arg: Arg,
+    used: bool,
+}
+
+// an argument referenced from the format string
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) enum ArgRef<'a> {
+    Next,
+    Index(usize),
+    Name(&'a str),
+}
+
+fn parse_inputs(iter: &mut TSIterator) -> Result<Inputs, Error> {
+    let (format_str, str_span) = match crate::parsing_bstr::parse_lstr(iter)? {
+        Some(Pattern::String { string, span }) => (string, span),
+        None => {
+            return Err(Error::new(
+                Span::call_site(),
+                "expected a format string literal",
+            ));
+        }
+    };
+
+    let mut positional = Vec::<(Arg, bool)>::new();
+    let mut named = Vec::<NamedArg>::new();
+
+    for arg_tokens in split_args(iter)? {
+        let mut arg_iter = arg_tokens.clone().into_iter();
+        let span = arg_tokens
+            .clone()
+            .into_iter()
+            .next()
+            .map_or(str_span, |tt| tt.span());
+
+        match (arg_iter.next(), arg_iter.next()) {
+            (Some(TokenTree::Ident(name)), Some(TokenTree::Punct(eq)))
+                if eq.as_char() == '=' && eq.spacing() == Spacing::Alone =>
+            {
+                let name_str = name.to_string();
+                if named.iter().any(|n| n.name == name_str) {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("duplicate argument named `{name_str}`"),
+                    ));
+                }
+                named.push(NamedArg {
+                    name: name_str,
+                    arg: Arg {
+                        tokens: default_int_type(arg_iter.collect()),
+                        span: eq.span(),
+                    },
+                    used: false,
+                });
+            }
+            _ if !named.is_empty() => {
+                return Err(Error::new(
+                    span,
+                    "positional arguments cannot follow named arguments",
+                ));
+            }
+            _ => positional.push((
+                Arg {
+                    tokens: default_int_type(arg_tokens),
+                    span,
+                },
+                false,
+            )),
+        }
+    }
+
+    let positional_count = positional.len();
+    // inline captured identifiers, e.g. `{FOO}` without a `FOO = ...` argument
+    let mut captured = Vec::<NamedArg>::new();
+    let mut pieces = Vec::<Piece>::new();
+    let mut next_positional = 0;
+
+    for piece in parse_format_str(&format_str).map_err(|msg| Error::new(str_span, msg))? {
+        let (arg_ref, spec) = match piece {
+            ParsedPiece::Str(s) => {
+                pieces.push(Piece::Str(s));
+                continue;
+            }
+            ParsedPiece::Arg(arg_ref, spec) => (arg_ref, spec),
+        };
+
+        let index = match arg_ref {
+            ArgRef::Next | ArgRef::Index(_) => {
+                let index = if let ArgRef::Index(index) = arg_ref {
+                    index
+                } else {
+                    next_positional += 1;
+                    next_positional - 1
+                };
+
+                let Some((_, used)) = positional.get_mut(index) else {
+                    return Err(Error::new(
+                        str_span,
+                        format!(
+                            "invalid reference to positional argument {index} ({})",
+                            match positional_count {
+                                0 => "no arguments were given".to_string(),
+                                1 => "there is 1 argument".to_string(),
+                                n => format!("there are {n} arguments"),
+                            }
+                        ),
+                    ));
+                };
+                *used = true;
+                index
+            }
+            ArgRef::Name(name) => {
+                if let Some(i) = named.iter().position(|n| n.name == name) {
+                    named[i].used = true;
+                    positional_count + i
+                } else if let Some(i) = captured.iter().position(|n| n.name == name) {
+                    positional_count + named.len() + i
+                } else {
+                    let ident = Ident::new(name, str_span);
+                    captured.push(NamedArg {
+                        name: name.to_string(),
+                        arg: Arg {
+                            tokens: iter::once(TokenTree::from(ident)).collect(),
+                            span: str_span,
+                        },
+                        used: true,
+                    });
+                    positional_count + named.len() + captured.len() - 1
+                }
+            }
+        };
+
+        pieces.push(Piece::Arg { index, spec });
+    }
+
+    if let Some((arg, _)) = positional.iter().find(|(_, used)| !used) {
+        return Err(Error::new(arg.span, "argument never used"));
+    }
+    if let Some(named) = named.iter().find(|n| !n.used) {
+        return Err(Error::new(named.arg.span, "named argument never used"));
+    }
+
+    let args = positional
+        .into_iter()
+        .map(|(arg, _)| arg)
+        .chain(named.into_iter().chain(captured).map(|n| n.arg))
+        .collect();
+
+    Ok(Inputs { args, pieces })
+}
+
+// splits the arguments after the format string on the commas
+fn split_args(iter: &mut TSIterator) -> Result<Vec<TokenStream>, Error> {
+    let mut args = Vec::new();
+
+    match iter.next() {
+        Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+        Some(tt) => {
+            return Err(Error::new(
+                tt.span(),
+                format!("expected `,` after the format string, found `{tt}`"),
+            ));
+        }
+        None => return Ok(args),
+    }
+
+    let mut curr = TokenStream::new();
+    for tt in iter {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == ',' => {
+                if curr.is_empty() {
+                    return Err(Error::new(p.span(), "expected an argument before `,`"));
+                }
+                args.push(std::mem::replace(&mut curr, TokenStream::new()));
+            }
+            tt => curr.extend(iter::once(tt)),
+        }
+    }
+    if !curr.is_empty() {
+        args.push(curr);
+    }
+
+    Ok(args)
+}
+
+// Suffixes unsuffixed integer literals with `i32`, like `core::format_args` infers them,
+// because the integer type of arguments can't be inferred from how they're formatted.
+fn default_int_type(tokens: TokenStream) -> TokenStream {
+    let mut iter = tokens.clone().into_iter();
+
+    let (minus, lit) = match (iter.next(), iter.next(), iter.next()) {
+        (Some(TokenTree::Literal(lit)), None, None) => (None, lit),
+        (Some(TokenTree::Punct(p)), Some(TokenTree::Literal(lit)), None) if p.as_char() == '-' => {
+            (Some(p), lit)
+        }
+        _ => return tokens,
+    };
+
+    let lit_str = lit.to_string();
+    let is_unsuffixed_int = if let Some(hex) = lit_str.strip_prefix("0x") {
+        !hex.contains(['i', 'u'])
+    } else {
+        lit_str.starts_with(|c: char| c.is_ascii_digit())
+            && lit_str
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+            && !lit_str.contains(['i', 'u', 'e', 'E', 'f'])
+    };
+
+    let suffixed = match format!("{lit_str}i32").parse::<Literal>() {
+        Ok(mut suffixed) if is_unsuffixed_int => {
+            suffixed.set_span(lit.span());
+            suffixed
+        }
+        _ => return tokens,
+    };
+
+    minus
+        .map(TokenTree::from)
+        .into_iter()
+        .chain(iter::once(TokenTree::from(suffixed)))
+        .collect()
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(crate) enum ParsedPiece<'a> {
+    Str(String),
+    Arg(ArgRef<'a>, FmtSpec),
+}
+
+pub(crate) fn parse_format_str(format_str: &str) -> Result<Vec<ParsedPiece<'_>>, String> {
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut rem = format_str;
+
+    while let Some(c) = rem.chars().next() {
+        if rem.starts_with("{{") || rem.starts_with("}}") {
+            literal.push(c);
+            rem = &rem[2..];
+        } else if c == '}' {
+            return Err("unmatched `}` in format string, use `}}` to write a `}`".to_string());
+        } else if c == '{' {
+            let Some(end) = rem.find('}') else {
+                return Err("unmatched `{` in format string, use `{{` to write a `{`".to_string());
+            };
+
+            if !literal.is_empty() {
+                pieces.push(ParsedPiece::Str(std::mem::take(&mut literal)));
+            }
+
+            let (arg_ref, spec) = parse_argument(&rem[1..end])?;
+            pieces.push(ParsedPiece::Arg(arg_ref, spec));
+
+            rem = &rem[end + 1..];
+        } else {
+            literal.push(c);
+            rem = &rem[c.len_utf8()..];
+        }
+    }
+
+    if !literal.is_empty() {
+        pieces.push(ParsedPiece::Str(literal));
+    }
+
+    Ok(pieces)
+}
+
+// parses the contents of a `{...}` in the format string
+fn parse_argument(arg: &str) -> Result<(ArgRef<'_>, FmtSpec), String> {
+    let (arg_ref, spec) = match arg.split_once(':') {
+        Some((arg_ref, spec)) => (arg_ref, Some(spec)),
+        None => (arg, None),
+    };
+
+    let arg_ref = if arg_ref.is_empty() {
+        ArgRef::Next
+    } else if arg_ref.bytes().all(|b| b.is_ascii_digit()) {
+        ArgRef::Index(
+            arg_ref
+                .parse()
+                .map_err(|_| format!("invalid argument index: `{arg_ref}`"))?,
+        )
+    } else if is_identifier(arg_ref) {
+        ArgRef::Name(arg_ref)
+    } else {
+        return Err(format!(
+            "invalid argument: `{arg_ref}`, expected an index or an identifier"
+        ));
+    };
+
+    let spec = match spec {
+        Some(spec) => parse_spec(spec)?,
+        None => FmtSpec::default(),
+    };
+
+    Ok((arg_ref, spec))
+}
+
+// parses `[[fill]align][+][#][0][width][type]`
+fn parse_spec(spec_str: &str) -> Result<FmtSpec, String> {
+    let mut spec = FmtSpec::default();
+    let mut rem = spec_str;
+
+    let align_of = |c: char| match c {
+        '<' => Some("Left"),
+        '^' => Some("Center"),
+        '>' => Some("Right"),
+        _ => None,
+    };
+
+    let mut chars = rem.chars();
+    if let Some(fill) = chars.next()
+        && let Some(align) = chars.next().and_then(align_of)
+    {
+        spec.fill = fill;
+        spec.align = align;
+        rem = chars.as_str();
+    } else if let Some(align) = rem.chars().next().and_then(align_of) {
+        spec.align = align;
+        rem = &rem[1..];
+    }
+
+    if let Some(x) = rem.strip_prefix('+') {
+        spec.sign_plus = true;
+        rem = x;
+    } else if rem.starts_with('-') {
+        return Err("the `-` formatting flag is not supported".to_string());
+    }
+
+    if let Some(x) = rem.strip_prefix('#') {
+        spec.alternate = true;
+        rem = x;
+    }
+
+    if let Some(x) = rem.strip_prefix('0') {
+        spec.zero_pad = true;
+        rem = x;
+    }
+
+    let digits_end = rem
+        .bytes()
+        .position(|b| !b.is_ascii_digit())
+        .unwrap_or(rem.len());
+    if digits_end != 0 {
+        spec.width = rem[..digits_end]
+            .parse()
+            .map_err(|_| format!("invalid width: `{}`", &rem[..digits_end]))?;
+        rem = &rem[digits_end..];
+    }
+
+    if rem.starts_with('$') || rem.starts_with('*') {
+        return Err("width arguments are not supported, only literal widths".to_string());
+    } else if rem.starts_with('.') {
+        return Err("precision is not supported".to_string());
+    }
+
+    spec.kind = match rem {
+        "" => "Display",
+        "?" => "Debug",
+        "x" => "LowerHex",
+        "X" => "UpperHex",
+        "b" => "Binary",
+        "o" => "Octal",
+        _ => {
+            return Err(format!(
+                "unsupported format spec: `{spec_str}`, \
+                 expected `[[fill]align][+][#][0][width][type]`, \
+                 where the type is one of ``, `?`, `x`, `X`, `b`, or `o`"
+            ));
+        }
+    };
+
+    Ok(spec)
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+        && s != "_"
+}
@@ -0,0 +1,77 @@
+use super::{ArgRef, FmtSpec, ParsedPiece, parse_format_str};
+
+fn spec(f: impl FnOnce(&mut FmtSpec)) -> FmtSpec {
+    let mut spec = FmtSpec::default();
+    f(&mut spec);
+    spec
+}
+
+#[test]
+fn test_parse_format_str() {
+    assert_eq!(
+        parse_format_str("a{{b}}c{}d{0}{name}").unwrap(),
+        vec![
+            ParsedPiece::Str("a{b}c".to_string()),
+            ParsedPiece::Arg(ArgRef::Next, FmtSpec::default()),
+            ParsedPiece::Str("d".to_string()),
+            ParsedPiece::Arg(ArgRef::Index(0), FmtSpec::default()),
+            ParsedPiece::Arg(ArgRef::Name("name"), FmtSpec::default()),
+        ]
+    );
+
+    assert_eq!(parse_format_str("").unwrap(), vec![]);
+
+    assert_eq!(
+        parse_format_str("ñ{{").unwrap(),
+        vec![ParsedPiece::Str("ñ{".to_string())]
+    );
+}
+
+#[test]
+fn test_parse_spec() {
+    let arg = |s| match parse_format_str(s).unwrap().pop().unwrap() {
+        ParsedPiece::Arg(_, spec) => spec,
+        x => panic!("{x:?}"),
+    };
+
+    assert_eq!(arg("{:?}"), spec(|s| s.kind = "Debug"));
+    assert_eq!(arg("{:5}"), spec(|s| s.width = 5));
+    assert_eq!(arg("{:<5}"), spec(|s| (s.align, s.width) = ("Left", 5)));
+    assert_eq!(
+        arg("{:*^10x}"),
+        spec(|s| (s.fill, s.align, s.width, s.kind) = ('*', "Center", 10, "LowerHex"))
+    );
+    assert_eq!(
+        arg("{:<<3}"),
+        spec(|s| (s.fill, s.align, s.width) = ('<', "Left", 3))
+    );
+    assert_eq!(
+        arg("{:+#010b}"),
+        spec(|s| {
+            (s.sign_plus, s.alternate, s.zero_pad) = (true, true, true);
+            (s.width, s.kind) = (10, "Binary");
+        })
+    );
+    assert_eq!(
+        arg("{:0>4o}"),
+        spec(|s| { (s.fill, s.align, s.width, s.kind) = ('0', "Right", 4, "Octal") })
+    );
+    assert_eq!(arg("{0:X}"), spec(|s| s.kind = "UpperHex"));
+}
+
+#[test]
+fn test_parse_errors() {
+    for (input, err) in [
+        ("{", "unmatched `{`"),
+        ("}", "unmatched `}`"),
+        ("{:.3}", "precision"),
+        ("{:5$}", "width arguments"),
+        ("{:e}", "unsupported format spec"),
+        ("{:x?}", "unsupported format spec"),
+        ("{:-}", "`-`"),
+        ("{a b}", "invalid argument"),
+    ] {
+        let msg = parse_format_str(input).unwrap_err();
+        assert!(msg.contains(err), "{input:?}: {msg}");
+    }
+}
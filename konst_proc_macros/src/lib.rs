@@ -22,6 +22,8 @@ use used_proc_macro::{
 
 mod destructuring;
 
+mod formatting;
+
 mod parsing;
 
 mod parsing_bstr;
@@ -52,6 +54,16 @@ pub fn __destructure__unwrap_pats(
     .into()
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __priv_string_format(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match crate::formatting::macro_impl(input_tokens.into()) {
+        Ok(x) => x,
+        Err((e, krate)) => e.to_compile_error(Some(krate)),
+    }
+    .into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __priv_bstr_start(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -100,6 +112,35 @@ fn bstr_pattern(input_tokens: TokenStream, str_at: StrAt) -> TokenStream {
     }
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __priv_bstr_list(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    bstr_list(input_tokens.into()).into()
+}
+
+// outputs an array of string literals, with the strings that the patterns match
+fn bstr_list(input_tokens: TokenStream) -> TokenStream {
+    use crate::utils::punct_token;
+
+    match parsing_bstr::parse_patterns(&mut input_tokens.into_iter()) {
+        Ok(strings) => {
+            let tt = crate::utils::bracket(Span::call_site(), |out| {
+                for patt in &strings {
+                    let Pattern::String { string, span } = patt;
+
+                    let mut lit = Literal::string(string);
+                    lit.set_span(*span);
+                    out.extend(iter::once(TokenTree::from(lit)));
+                    out.extend(punct_token(',', *span));
+                }
+            });
+
+            iter::once(tt).collect()
+        }
+        Err(e) => e.to_compile_error(None),
+    }
+}
+
 fn output_patt(patt: &Pattern, out: &mut TokenStream) {
     use crate::utils::punct_token;
 
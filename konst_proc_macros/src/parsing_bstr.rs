@@ -22,6 +22,12 @@ pub(crate) fn parse_inputs(ts: TokenStream) -> Result<Inputs, Error> {
 
     assert_punct(iter.next(), ',')?;
 
+    let strings = parse_patterns(iter)?;
+
+    Ok(Inputs { rem_ident, strings })
+}
+
+pub(crate) fn parse_patterns(iter: &mut TSIterator) -> Result<Vec<Pattern>, Error> {
     let mut strings = Vec::<Pattern>::with_capacity(1);
 
     while let Some(x) = parse_lstr(iter)? {
@@ -39,12 +45,12 @@ pub(crate) fn parse_inputs(ts: TokenStream) -> Result<Inputs, Error> {
         }
     }
 
-    Ok(Inputs { rem_ident, strings })
+    Ok(strings)
 }
 
 const IN_MSG: &str = "Expected one of: string literal, concat!(...) , stringify!(...)";
 
-fn parse_lstr(iter: &mut TSIterator) -> Result<Option<Pattern>, Error> {
+pub(crate) fn parse_lstr(iter: &mut TSIterator) -> Result<Option<Pattern>, Error> {
     match iter.next() {
         Some(TokenTree::Ident(ident)) => {
             let string = ident.to_string();